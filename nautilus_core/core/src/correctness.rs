@@ -22,12 +22,30 @@ const FAILED: &str = "condition check failed:";
 /// - If `s` is all whitespace.
 /// - If `s` contains a Non-ASCII character.
 pub fn valid_string(s: &str, desc: &str) {
+    if let Err(e) = check_valid_string(s, desc) {
+        panic!("{e}");
+    }
+}
+
+/// Check string `s` is valid, returning an error rather than panicking.
+///
+/// # Errors
+/// - If `s` is empty.
+/// - If `s` is all whitespace.
+/// - If `s` contains a Non-ASCII character.
+pub fn check_valid_string(s: &str, desc: &str) -> Result<(), String> {
     if s.is_empty() {
-        panic!("{FAILED} invalid string for {desc}, was empty");
+        Err(format!("{FAILED} invalid string for {desc}, was empty"))
     } else if s.as_bytes().iter().all(u8::is_ascii_whitespace) {
-        panic!("{FAILED} invalid string for {desc}, was all whitespace");
+        Err(format!(
+            "{FAILED} invalid string for {desc}, was all whitespace"
+        ))
     } else if !s.is_ascii() {
-        panic!("{FAILED} invalid string for {desc} contained a Non-ASCII char, was '{s}'");
+        Err(format!(
+            "{FAILED} invalid string for {desc} contained a Non-ASCII char, was '{s}'"
+        ))
+    } else {
+        Ok(())
     }
 }
 
@@ -36,8 +54,23 @@ pub fn valid_string(s: &str, desc: &str) {
 /// # Panics
 /// - If `s` does not contain `pat`.
 pub fn string_contains(s: &str, pat: &str, desc: &str) {
-    if !s.contains(pat) {
-        panic!("{FAILED} invalid string for {desc} did not contain '{pat}', was '{s}'");
+    if let Err(e) = check_string_contains(s, pat, desc) {
+        panic!("{e}");
+    }
+}
+
+/// Check string `s` contains pattern `pat`, returning an error rather than
+/// panicking.
+///
+/// # Errors
+/// - If `s` does not contain `pat`.
+pub fn check_string_contains(s: &str, pat: &str, desc: &str) -> Result<(), String> {
+    if s.contains(pat) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{FAILED} invalid string for {desc} did not contain '{pat}', was '{s}'"
+        ))
     }
 }
 
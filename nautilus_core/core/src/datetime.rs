@@ -18,6 +18,8 @@ use std::time::{Duration, UNIX_EPOCH};
 use chrono::prelude::{DateTime, Utc};
 use chrono::SecondsFormat;
 
+use crate::time::UnixNanos;
+
 const MILLISECONDS_IN_SECOND: u64 = 1_000;
 const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
 const NANOSECONDS_IN_MILLISECOND: u64 = 1_000_000;
@@ -72,13 +74,29 @@ pub extern "C" fn nanos_to_micros(nanos: u64) -> u64 {
     nanos / NANOSECONDS_IN_MICROSECOND
 }
 
+/// Returns the given UNIX nanoseconds as an ISO 8601 (RFC 3339) UTC string
+/// with full nanosecond precision, e.g. `1970-01-01T00:00:00.000000001Z`.
 #[inline]
 #[must_use]
-pub fn unix_nanos_to_iso8601(timestamp_ns: u64) -> String {
+pub fn unix_nanos_to_iso8601(timestamp_ns: UnixNanos) -> String {
     let dt = DateTime::<Utc>::from(UNIX_EPOCH + Duration::from_nanos(timestamp_ns));
     dt.to_rfc3339_opts(SecondsFormat::Nanos, true)
 }
 
+/// Parses an ISO 8601 (RFC 3339) string into UNIX nanoseconds.
+///
+/// Any UTC offset is normalized and sub-second digits are kept down to the
+/// nanosecond. Returns an error if the string is malformed or the instant is
+/// not representable as [`UnixNanos`] (i.e. before the UNIX epoch).
+pub fn iso8601_to_unix_nanos(s: &str) -> Result<UnixNanos, String> {
+    let dt = DateTime::parse_from_rfc3339(s)
+        .map_err(|e| format!("invalid ISO 8601 timestamp '{s}', {e}"))?;
+    let nanos = i128::from(dt.timestamp()) * i128::from(NANOSECONDS_IN_SECOND)
+        + i128::from(dt.timestamp_subsec_nanos());
+    UnixNanos::try_from(nanos)
+        .map_err(|_| format!("ISO 8601 timestamp '{s}' not representable as UNIX nanoseconds"))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -171,4 +189,34 @@ mod tests {
         let result = nanos_to_micros(value);
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case(0, "1970-01-01T00:00:00.000000000Z")]
+    #[case(1, "1970-01-01T00:00:00.000000001Z")]
+    #[case(1_000_000_000, "1970-01-01T00:00:01.000000000Z")]
+    #[case(1_650_000_000_123_456_789, "2022-04-15T05:20:00.123456789Z")]
+    #[case(1_577_836_800_500_000_000, "2020-01-01T00:00:00.500000000Z")]
+    fn test_unix_nanos_to_iso8601_round_trip(#[case] value: u64, #[case] expected: &str) {
+        let result = unix_nanos_to_iso8601(value);
+        assert_eq!(result, expected);
+        assert_eq!(iso8601_to_unix_nanos(&result).unwrap(), value);
+    }
+
+    #[rstest]
+    #[case("2020-01-01T00:00:00Z", 1_577_836_800_000_000_000)]
+    #[case("2020-01-01T00:00:00.1Z", 1_577_836_800_100_000_000)]
+    #[case("2020-01-01T00:00:00.000001Z", 1_577_836_800_000_001_000)]
+    #[case("2020-01-01T10:00:00.000000001+10:00", 1_577_836_800_000_000_001)]
+    fn test_iso8601_to_unix_nanos(#[case] value: &str, #[case] expected: u64) {
+        assert_eq!(iso8601_to_unix_nanos(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("not a timestamp")]
+    #[case("2020-01-01")]
+    #[case("1969-12-31T23:59:59Z")]
+    fn test_iso8601_to_unix_nanos_with_invalid_values(#[case] value: &str) {
+        assert!(iso8601_to_unix_nanos(value).is_err());
+    }
 }
@@ -46,16 +46,32 @@ pub unsafe fn optional_bytes_to_json(ptr: *const c_char) -> Option<HashMap<Strin
 }
 
 /// Return the decimal precision inferred from the given string.
+///
+/// # Panics
+/// - If the exponent of scientific notation is not a `u8`.
 pub fn precision_from_str(s: &str) -> u8 {
+    try_precision_from_str(s).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Returns the decimal precision inferred from the given string, as
+/// [`precision_from_str`] but returning an error rather than panicking.
+///
+/// # Errors
+/// - If the exponent of scientific notation is not a `u8`.
+pub fn try_precision_from_str(s: &str) -> std::result::Result<u8, String> {
     let lower_s = s.to_lowercase();
     // Handle scientific notation
     if lower_s.contains("e-") {
-        return lower_s.split("e-").last().unwrap().parse::<u8>().unwrap();
+        let exponent = lower_s.split("e-").last().unwrap_or_default();
+        return exponent.parse::<u8>().map_err(|e| {
+            format!("Cannot parse precision of '{s}' from exponent '{exponent}', {e}")
+        });
     }
     if !lower_s.contains('.') {
-        return 0;
+        return Ok(0);
     }
-    return lower_s.split('.').last().unwrap().len() as u8;
+    u8::try_from(lower_s.split('.').last().unwrap_or_default().len())
+        .map_err(|e| format!("Cannot parse precision of '{s}', {e}"))
 }
 
 /// Return the decimal precision inferred from the given C string.
@@ -137,6 +153,11 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest(s, case("1e-x"), case("1e-300"))]
+    fn test_try_precision_from_str_with_invalid_exponent_errors(s: &str) {
+        assert!(try_precision_from_str(s).is_err());
+    }
+
     #[rstest(
         input,
         expected,
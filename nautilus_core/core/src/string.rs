@@ -15,7 +15,9 @@
 
 use std::ffi::{c_char, CStr, CString};
 
+#[cfg(feature = "python")]
 use pyo3::types::PyString;
+#[cfg(feature = "python")]
 use pyo3::{ffi, FromPyPointer, Python};
 
 /// Returns an owned string from a valid Python object pointer.
@@ -24,6 +26,7 @@ use pyo3::{ffi, FromPyPointer, Python};
 /// - Assumes `ptr` is borrowed from a valid Python UTF-8 `str`.
 /// # Panics
 /// - If `ptr` is null.
+#[cfg(feature = "python")]
 #[must_use]
 pub unsafe fn pystr_to_string(ptr: *mut ffi::PyObject) -> String {
     assert!(!ptr.is_null(), "`ptr` was NULL");
@@ -82,10 +85,12 @@ pub unsafe extern "C" fn cstr_free(ptr: *const c_char) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "python")]
     use pyo3::AsPyPointer;
 
     use super::*;
 
+    #[cfg(feature = "python")]
     #[test]
     fn test_pystr_to_string() {
         pyo3::prepare_freethreaded_python();
@@ -95,6 +100,7 @@ mod tests {
         assert_eq!(result, "test string1");
     }
 
+    #[cfg(feature = "python")]
     #[test]
     #[should_panic]
     fn test_pystr_to_string_with_null_ptr() {
@@ -0,0 +1,191 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::data::bar::{Bar, BarType};
+use crate::data::tick::QuoteTick;
+use crate::enums::PriceType;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Aggregates `ticks` into time bars of `interval_ns` nanoseconds.
+///
+/// Ticks are bucketed by `ts_init` into intervals aligned to the UNIX epoch,
+/// each bar is timestamped at the close of its interval. OHLC values are
+/// taken from the mid price and the volume is the sum of the bid and ask
+/// sizes. Intervals containing no ticks produce no bar. Unsorted `ticks` are
+/// stably sorted by `ts_init` first.
+///
+/// Every `Bar` needs a `BarType`, which cannot be derived from the ticks and
+/// interval alone, as there is no bar aggregation for intervals which are not
+/// a whole number of milliseconds, so it is given as `bar_type`.
+///
+/// # Panics
+/// - If `interval_ns` is zero.
+#[must_use]
+pub fn aggregate_time_bars(bar_type: &BarType, ticks: &[QuoteTick], interval_ns: u64) -> Vec<Bar> {
+    assert!(interval_ns > 0, "`interval_ns` must be greater than zero");
+    let mut sorted: Vec<&QuoteTick> = ticks.iter().collect();
+    sorted.sort_by_key(|tick| tick.ts_init);
+    let mut bars = Vec::new();
+    let mut builder: Option<(u64, Bar)> = None;
+
+    for tick in sorted {
+        let bucket = tick.ts_init / interval_ns;
+        let price = tick.extract_price(PriceType::Mid);
+        let volume = tick.bid_size.clone() + tick.ask_size.clone();
+
+        match &mut builder {
+            Some((current, bar)) if *current == bucket => {
+                bar.high = bar.high.clone().max(price.clone());
+                bar.low = bar.low.clone().min(price.clone());
+                bar.close = price;
+                bar.volume += volume;
+            }
+            _ => {
+                if let Some((_, bar)) = builder.take() {
+                    bars.push(bar);
+                }
+                builder = Some((
+                    bucket,
+                    new_bar(bar_type, price, volume, bucket, interval_ns),
+                ));
+            }
+        }
+    }
+
+    if let Some((_, bar)) = builder {
+        bars.push(bar);
+    }
+    bars
+}
+
+fn new_bar(
+    bar_type: &BarType,
+    price: Price,
+    volume: Quantity,
+    bucket: u64,
+    interval_ns: u64,
+) -> Bar {
+    let ts_close = (bucket + 1) * interval_ns;
+    Bar {
+        bar_type: bar_type.clone(),
+        open: price.clone(),
+        high: price.clone(),
+        low: price.clone(),
+        close: price,
+        volume,
+        ts_event: ts_close,
+        ts_init: ts_close,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::aggregate_time_bars;
+    use crate::data::bar::{BarSpecification, BarType};
+    use crate::data::tick::QuoteTick;
+    use crate::enums::{AggregationSource, BarAggregation, PriceType};
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn bar_type() -> BarType {
+        BarType {
+            instrument_id: InstrumentId::from("EUR/USD.SIM"),
+            spec: BarSpecification {
+                step: 1,
+                aggregation: BarAggregation::Minute,
+                price_type: PriceType::Mid,
+            },
+            aggregation_source: AggregationSource::Internal,
+        }
+    }
+
+    fn quote(bid: f64, ask: f64, size: f64, ts_init: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from("EUR/USD.SIM"),
+            bid: Price::new(bid, 4),
+            ask: Price::new(ask, 4),
+            bid_size: Quantity::new(size, 0),
+            ask_size: Quantity::new(size, 0),
+            ts_event: ts_init,
+            ts_init,
+        }
+    }
+
+    #[test]
+    fn test_aggregate_time_bars() {
+        let ticks = [
+            quote(1.0000, 1.0002, 10.0, 0),
+            quote(1.0004, 1.0006, 20.0, 10 * SECOND),
+            quote(0.9996, 0.9998, 30.0, 30 * SECOND),
+            quote(1.0002, 1.0004, 40.0, 60 * SECOND - 1),
+            quote(1.0010, 1.0012, 50.0, 60 * SECOND),
+            // No ticks in the third minute
+            quote(1.0020, 1.0022, 60.0, 185 * SECOND),
+            quote(1.0030, 1.0032, 70.0, 186 * SECOND),
+        ];
+
+        let bars = aggregate_time_bars(&bar_type(), &ticks, 60 * SECOND);
+
+        assert_eq!(bars.len(), 3);
+        assert_eq!(bars[0].open, Price::new(1.0001, 5));
+        assert_eq!(bars[0].high, Price::new(1.0005, 5));
+        assert_eq!(bars[0].low, Price::new(0.9997, 5));
+        assert_eq!(bars[0].close, Price::new(1.0003, 5));
+        assert_eq!(bars[0].volume, Quantity::new(200.0, 0));
+        assert_eq!(bars[0].ts_init, 60 * SECOND);
+        assert_eq!(bars[1].open, Price::new(1.0011, 5));
+        assert_eq!(bars[1].close, Price::new(1.0011, 5));
+        assert_eq!(bars[1].volume, Quantity::new(100.0, 0));
+        assert_eq!(bars[1].ts_init, 120 * SECOND);
+        assert_eq!(bars[2].open, Price::new(1.0021, 5));
+        assert_eq!(bars[2].high, Price::new(1.0031, 5));
+        assert_eq!(bars[2].low, Price::new(1.0021, 5));
+        assert_eq!(bars[2].close, Price::new(1.0031, 5));
+        assert_eq!(bars[2].volume, Quantity::new(260.0, 0));
+        assert_eq!(bars[2].ts_init, 240 * SECOND);
+        assert!(bars.iter().all(|bar| bar.bar_type == bar_type()));
+    }
+
+    #[test]
+    fn test_aggregate_time_bars_with_unsorted_ticks() {
+        let ticks = [
+            quote(1.0000, 1.0002, 10.0, 0),
+            quote(1.0004, 1.0006, 20.0, 10 * SECOND),
+            quote(1.0010, 1.0012, 50.0, 60 * SECOND),
+            quote(0.9996, 0.9998, 30.0, 30 * SECOND),
+        ];
+        let mut sorted = ticks.clone();
+        sorted.sort_by_key(|tick| tick.ts_init);
+
+        let bars = aggregate_time_bars(&bar_type(), &ticks, 60 * SECOND);
+
+        assert_eq!(bars, aggregate_time_bars(&bar_type(), &sorted, 60 * SECOND));
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].close, Price::new(0.9997, 5));
+        assert_eq!(bars[0].volume, Quantity::new(120.0, 0));
+    }
+
+    #[test]
+    fn test_aggregate_time_bars_with_no_ticks() {
+        assert!(aggregate_time_bars(&bar_type(), &[], 60 * SECOND).is_empty());
+    }
+}
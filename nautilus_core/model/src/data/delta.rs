@@ -0,0 +1,103 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Display, Formatter, Result};
+
+use nautilus_core::time::UnixNanos;
+
+use crate::enums::BookAction;
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::orderbook::order::BookOrder;
+
+/// Represents a single change/delta in an order book.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderBookDelta {
+    pub instrument_id: InstrumentId,
+    pub action: BookAction,
+    pub order: BookOrder,
+    pub sequence: u64,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderBookDelta {
+    #[must_use]
+    pub fn new(
+        instrument_id: InstrumentId,
+        action: BookAction,
+        order: BookOrder,
+        sequence: u64,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        OrderBookDelta {
+            instrument_id,
+            action,
+            order,
+            sequence,
+            ts_event,
+            ts_init,
+        }
+    }
+}
+
+impl Display for OrderBookDelta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            self.instrument_id,
+            self.action,
+            self.order.side,
+            self.order.price,
+            self.order.size,
+            self.order.order_id,
+            self.sequence,
+            self.ts_event,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::data::delta::OrderBookDelta;
+    use crate::enums::{BookAction, OrderSide};
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::orderbook::order::BookOrder;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    #[test]
+    fn test_order_book_delta_to_string() {
+        let delta = OrderBookDelta::new(
+            InstrumentId::from("AAPL.NASDAQ"),
+            BookAction::Add,
+            BookOrder::new(
+                Price::new(100.0, 2),
+                Quantity::new(10.0, 0),
+                OrderSide::Buy,
+                123,
+            ),
+            1,
+            2,
+            3,
+        );
+        assert_eq!(delta.to_string(), "AAPL.NASDAQ,ADD,BUY,100.00,10,123,1,2");
+    }
+}
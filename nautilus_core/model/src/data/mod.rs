@@ -14,4 +14,49 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod bar;
+pub mod bar_aggregator;
+pub mod delta;
+pub mod quote_cache;
+pub mod reorder_buffer;
 pub mod tick;
+
+use nautilus_core::time::UnixNanos;
+
+use crate::data::bar::Bar;
+use crate::data::delta::OrderBookDelta;
+use crate::data::tick::{PartialQuoteTick, QuoteTick, TradeTick};
+
+/// Provides the UNIX timestamp (nanoseconds) when a data object was initialized.
+pub trait GetTsInit {
+    fn get_ts_init(&self) -> UnixNanos;
+}
+
+impl GetTsInit for QuoteTick {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl GetTsInit for PartialQuoteTick {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl GetTsInit for TradeTick {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl GetTsInit for OrderBookDelta {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl GetTsInit for Bar {
+    fn get_ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
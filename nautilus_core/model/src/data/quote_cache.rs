@@ -0,0 +1,145 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::data::tick::QuoteTick;
+use crate::identifiers::instrument_id::InstrumentId;
+
+/// Holds the most recent quote ticks of each instrument, up to a fixed
+/// capacity per instrument, such as for rolling window indicators.
+///
+/// Once an instrument's buffer is full, pushing a tick evicts its oldest.
+pub struct QuoteCache {
+    capacity: usize,
+    quotes: HashMap<InstrumentId, VecDeque<QuoteTick>>,
+}
+
+impl QuoteCache {
+    /// Creates a new cache holding up to `capacity` ticks per instrument.
+    ///
+    /// # Panics
+    /// - If `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "`capacity` must be greater than zero");
+        QuoteCache {
+            capacity,
+            quotes: HashMap::new(),
+        }
+    }
+
+    /// Returns the maximum number of ticks held per instrument.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of ticks held for `instrument_id`.
+    #[must_use]
+    pub fn len(&self, instrument_id: &InstrumentId) -> usize {
+        self.quotes.get(instrument_id).map_or(0, VecDeque::len)
+    }
+
+    /// Pushes `tick` into the buffer of its instrument, evicting the oldest
+    /// tick of the instrument if its buffer is full.
+    pub fn push(&mut self, tick: QuoteTick) {
+        let capacity = self.capacity;
+        let quotes = self
+            .quotes
+            .entry(tick.instrument_id.clone())
+            .or_insert_with(|| VecDeque::with_capacity(capacity));
+        if quotes.len() == capacity {
+            quotes.pop_front();
+        }
+        quotes.push_back(tick);
+    }
+
+    /// Returns up to the `n` most recent ticks of `instrument_id`, oldest
+    /// first.
+    #[must_use]
+    pub fn latest(&self, instrument_id: &InstrumentId, n: usize) -> Vec<&QuoteTick> {
+        let Some(quotes) = self.quotes.get(instrument_id) else {
+            return Vec::new();
+        };
+        quotes.iter().skip(quotes.len().saturating_sub(n)).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::QuoteCache;
+    use crate::data::tick::QuoteTick;
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    fn quote(instrument_id: &str, ts_init: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            bid: Price::new(1.0, 4),
+            ask: Price::new(1.0001, 4),
+            bid_size: Quantity::new(100.0, 0),
+            ask_size: Quantity::new(100.0, 0),
+            ts_event: ts_init,
+            ts_init,
+        }
+    }
+
+    fn ts_inits(ticks: &[&QuoteTick]) -> Vec<u64> {
+        ticks.iter().map(|tick| tick.ts_init).collect()
+    }
+
+    #[test]
+    fn test_quote_cache_evicts_oldest_at_capacity() {
+        let instrument_id = InstrumentId::from("EUR/USD.SIM");
+        let mut cache = QuoteCache::new(3);
+        for ts_init in 1..=5 {
+            cache.push(quote("EUR/USD.SIM", ts_init));
+        }
+
+        assert_eq!(cache.len(&instrument_id), 3);
+        assert_eq!(ts_inits(&cache.latest(&instrument_id, 3)), [3, 4, 5]);
+        assert_eq!(ts_inits(&cache.latest(&instrument_id, 2)), [4, 5]);
+        assert_eq!(ts_inits(&cache.latest(&instrument_id, 10)), [3, 4, 5]);
+        assert!(cache.latest(&instrument_id, 0).is_empty());
+    }
+
+    #[test]
+    fn test_quote_cache_isolates_instruments() {
+        let eurusd = InstrumentId::from("EUR/USD.SIM");
+        let audusd = InstrumentId::from("AUD/USD.SIM");
+        let mut cache = QuoteCache::new(2);
+        cache.push(quote("EUR/USD.SIM", 1));
+        cache.push(quote("AUD/USD.SIM", 2));
+        cache.push(quote("EUR/USD.SIM", 3));
+        cache.push(quote("EUR/USD.SIM", 4));
+
+        assert_eq!(ts_inits(&cache.latest(&eurusd, 2)), [3, 4]);
+        assert_eq!(ts_inits(&cache.latest(&audusd, 2)), [2]);
+        assert!(cache
+            .latest(&InstrumentId::from("GBP/USD.SIM"), 2)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "`capacity` must be greater than zero")]
+    fn test_quote_cache_with_zero_capacity_panics() {
+        let _ = QuoteCache::new(0);
+    }
+}
@@ -0,0 +1,162 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use nautilus_core::time::UnixNanos;
+
+use crate::data::GetTsInit;
+
+/// Buffers data which may arrive slightly out of order, releasing it in
+/// `ts_init` order once it is older than `max_lateness_ns`.
+///
+/// Data is older than the lateness window once its `ts_init` plus
+/// `max_lateness_ns` is at or before the greatest `ts_init` pushed so far.
+/// Data with equal `ts_init` is released in the order it was pushed. Data
+/// arriving after a later `ts_init` has already been released cannot be
+/// placed in order, so it is dropped and counted by `num_dropped`.
+pub struct ReorderBuffer<T: GetTsInit> {
+    max_lateness_ns: u64,
+    buffer: BTreeMap<(UnixNanos, u64), T>,
+    next_seq: u64,
+    watermark: Option<UnixNanos>,
+    last_released: Option<UnixNanos>,
+    num_dropped: usize,
+}
+
+impl<T: GetTsInit> ReorderBuffer<T> {
+    #[must_use]
+    pub fn new(max_lateness_ns: u64) -> Self {
+        ReorderBuffer {
+            max_lateness_ns,
+            buffer: BTreeMap::new(),
+            next_seq: 0,
+            watermark: None,
+            last_released: None,
+            num_dropped: 0,
+        }
+    }
+
+    /// Returns the number of data currently buffered.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns the number of data dropped for arriving too late.
+    #[must_use]
+    pub fn num_dropped(&self) -> usize {
+        self.num_dropped
+    }
+
+    /// Pushes `data` into the buffer, returning any data now older than the
+    /// lateness window in `ts_init` order.
+    pub fn push(&mut self, data: T) -> Vec<T> {
+        let ts_init = data.get_ts_init();
+        if matches!(self.last_released, Some(last) if ts_init < last) {
+            self.num_dropped += 1;
+            return Vec::new();
+        }
+        self.buffer.insert((ts_init, self.next_seq), data);
+        self.next_seq += 1;
+        let watermark = self.watermark.map_or(ts_init, |w| w.max(ts_init));
+        self.watermark = Some(watermark);
+
+        let mut released = Vec::new();
+        while let Some(entry) = self.buffer.first_entry() {
+            if entry.key().0.saturating_add(self.max_lateness_ns) > watermark {
+                break;
+            }
+            self.last_released = Some(entry.key().0);
+            released.push(entry.remove());
+        }
+        released
+    }
+
+    /// Closes the buffer, returning all remaining data in `ts_init` order.
+    #[must_use]
+    pub fn close(self) -> Vec<T> {
+        self.buffer.into_values().collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::ReorderBuffer;
+    use crate::data::tick::QuoteTick;
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    fn quote(ts_init: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from("EUR/USD.SIM"),
+            bid: Price::new(1.0, 4),
+            ask: Price::new(1.0001, 4),
+            bid_size: Quantity::new(100.0, 0),
+            ask_size: Quantity::new(100.0, 0),
+            ts_event: ts_init,
+            ts_init,
+        }
+    }
+
+    #[test]
+    fn test_reorder_buffer_with_jittered_sequence_is_monotonic() {
+        let jittered = [1, 3, 2, 4, 6, 5, 7, 10, 8, 9, 11, 12];
+        let mut buffer = ReorderBuffer::new(3);
+        let mut output = Vec::new();
+
+        for ts_init in jittered {
+            output.extend(buffer.push(quote(ts_init)));
+        }
+        assert!(!buffer.is_empty());
+        output.extend(buffer.close());
+
+        let result: Vec<u64> = output.iter().map(|quote| quote.ts_init).collect();
+        assert_eq!(result, (1..=12).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_reorder_buffer_holds_data_within_lateness_window() {
+        let mut buffer = ReorderBuffer::new(10);
+
+        assert!(buffer.push(quote(100)).is_empty());
+        assert!(buffer.push(quote(105)).is_empty());
+        let released = buffer.push(quote(110));
+
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].ts_init, 100);
+        assert_eq!(buffer.len(), 2);
+    }
+
+    #[test]
+    fn test_reorder_buffer_drops_data_later_than_released() {
+        let mut buffer = ReorderBuffer::new(0);
+
+        assert_eq!(buffer.push(quote(2)).len(), 1);
+        assert!(buffer.push(quote(1)).is_empty());
+
+        assert_eq!(buffer.num_dropped(), 1);
+        assert!(buffer.close().is_empty());
+    }
+}
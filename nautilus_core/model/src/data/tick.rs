@@ -17,14 +17,13 @@ use std::cmp;
 use std::ffi::c_char;
 use std::fmt::{Display, Formatter, Result};
 
-use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
 use nautilus_core::time::UnixNanos;
 
-use crate::enums::{AggressorSide, PriceType};
+use crate::enums::{AggressorSide, OrderSide, PriceType};
 use crate::identifiers::instrument_id::InstrumentId;
 use crate::identifiers::trade_id::TradeId;
-use crate::types::fixed::FIXED_PRECISION;
+use crate::types::fixed::{round_fixed_i64, RoundingMode, FIXED_PRECISION};
 use crate::types::price::Price;
 use crate::types::quantity::Quantity;
 
@@ -42,7 +41,11 @@ pub struct QuoteTick {
 }
 
 impl QuoteTick {
-    #[must_use]
+    /// Creates a new [`QuoteTick`] instance.
+    ///
+    /// # Errors
+    /// - If `bid` and `ask` precisions are not equal.
+    /// - If `bid_size` and `ask_size` precisions are not equal.
     pub fn new(
         instrument_id: InstrumentId,
         bid: Price,
@@ -51,20 +54,20 @@ impl QuoteTick {
         ask_size: Quantity,
         ts_event: UnixNanos,
         ts_init: UnixNanos,
-    ) -> Self {
-        correctness::u8_equal(
-            bid.precision,
-            ask.precision,
-            "bid.precision",
-            "ask.precision",
-        );
-        correctness::u8_equal(
-            bid_size.precision,
-            ask_size.precision,
-            "bid_size.precision",
-            "ask_size.precision",
-        );
-        QuoteTick {
+    ) -> std::result::Result<Self, String> {
+        if bid.precision != ask.precision {
+            return Err(format!(
+                "'bid.precision' of {} was not equal to 'ask.precision' of {}",
+                bid.precision, ask.precision
+            ));
+        }
+        if bid_size.precision != ask_size.precision {
+            return Err(format!(
+                "'bid_size.precision' of {} was not equal to 'ask_size.precision' of {}",
+                bid_size.precision, ask_size.precision
+            ));
+        }
+        Ok(QuoteTick {
             instrument_id,
             bid,
             ask,
@@ -72,7 +75,7 @@ impl QuoteTick {
             ask_size,
             ts_event,
             ts_init,
-        }
+        })
     }
 
     pub fn extract_price(&self, price_type: PriceType) -> Price {
@@ -86,6 +89,128 @@ impl QuoteTick {
             _ => panic!("Cannot extract with price type {price_type}"),
         }
     }
+
+    /// Returns the size weighted mid price
+    /// `(bid * ask_size + ask * bid_size) / (bid_size + ask_size)`, which
+    /// moves towards the ask as the bid size grows, at the precision of the
+    /// mid of [`QuoteTick::extract_price`].
+    ///
+    /// Computed on the raw values in `i128` so the products cannot overflow,
+    /// with the result rounded half to even. Falls back to the simple mid
+    /// when both sizes are zero.
+    #[must_use]
+    pub fn weighted_mid(&self) -> Price {
+        let total_size = i128::from(self.bid_size.raw) + i128::from(self.ask_size.raw);
+        if total_size == 0 {
+            return self.extract_price(PriceType::Mid);
+        }
+        let numerator = i128::from(self.bid.raw) * i128::from(self.ask_size.raw)
+            + i128::from(self.ask.raw) * i128::from(self.bid_size.raw);
+        let raw = i64::try_from(numerator / total_size)
+            .expect("weighted mid between `bid` and `ask` fits `i64`");
+        let precision = cmp::min(self.bid.precision + 1, FIXED_PRECISION);
+        Price::from_raw(
+            round_fixed_i64(raw, precision, RoundingMode::HalfEven),
+            precision,
+        )
+    }
+
+    /// Returns whether the quote is crossed, with the bid above the ask, which
+    /// usually indicates bad data. A locked quote (bid equal to ask) is not
+    /// crossed.
+    #[must_use]
+    pub fn is_crossed(&self) -> bool {
+        self.bid.raw > self.ask.raw
+    }
+
+    /// Returns a flat view of this tick of primitive values, such as for CSV
+    /// export or logging.
+    #[must_use]
+    pub fn to_record(&self) -> QuoteRecord {
+        QuoteRecord {
+            instrument_id: self.instrument_id.to_string(),
+            bid: self.bid.as_f64(),
+            ask: self.ask.as_f64(),
+            bid_size: self.bid_size.as_f64(),
+            ask_size: self.ask_size.as_f64(),
+            ts_event: self.ts_event,
+            ts_init: self.ts_init,
+        }
+    }
+
+    /// Splits this tick into its bid side, as a `Buy`, and its ask side, as
+    /// a `Sell`, such as for building each side of a book separately.
+    #[must_use]
+    pub fn split_sides(&self) -> (SideTick, SideTick) {
+        let bid = SideTick {
+            side: OrderSide::Buy,
+            price: self.bid.clone(),
+            size: self.bid_size.clone(),
+            ts_event: self.ts_event,
+            ts_init: self.ts_init,
+        };
+        let ask = SideTick {
+            side: OrderSide::Sell,
+            price: self.ask.clone(),
+            size: self.ask_size.clone(),
+            ts_event: self.ts_event,
+            ts_init: self.ts_init,
+        };
+        (bid, ask)
+    }
+
+    /// Creates a [`QuoteTick`] from a flat `record`, rounding the prices and
+    /// sizes half-even to the given precisions.
+    ///
+    /// Prices follow [`Price::from_f64`] so saturate at the price range.
+    ///
+    /// # Errors
+    /// - If the instrument ID of `record` is not valid.
+    /// - If `price_precision` or `size_precision` exceeds `FIXED_PRECISION`.
+    /// - If a size is not a valid [`Quantity`] value.
+    pub fn from_record(
+        record: &QuoteRecord,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> std::result::Result<Self, String> {
+        if price_precision > FIXED_PRECISION {
+            return Err(format!(
+                "`price_precision` of {price_precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        Ok(QuoteTick {
+            instrument_id: record.instrument_id.parse()?,
+            bid: Price::from_f64(record.bid, price_precision),
+            ask: Price::from_f64(record.ask, price_precision),
+            bid_size: Quantity::from_f64(record.bid_size, size_precision)?,
+            ask_size: Quantity::from_f64(record.ask_size, size_precision)?,
+            ts_event: record.ts_event,
+            ts_init: record.ts_init,
+        })
+    }
+}
+
+/// A flat view of a [`QuoteTick`] of primitive values, see
+/// [`QuoteTick::to_record`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuoteRecord {
+    pub instrument_id: String,
+    pub bid: f64,
+    pub ask: f64,
+    pub bid_size: f64,
+    pub ask_size: f64,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+/// One side of a [`QuoteTick`], see [`QuoteTick::split_sides`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SideTick {
+    pub side: OrderSide,
+    pub price: Price,
+    pub size: Quantity,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
 }
 
 impl Display for QuoteTick {
@@ -98,6 +223,56 @@ impl Display for QuoteTick {
     }
 }
 
+/// Returns the `(prev_ts, next_ts)` pairs of consecutive `ticks` whose
+/// `ts_init` values are more than `threshold_ns` apart, for data quality
+/// reports of missing data.
+///
+/// Assumes `ticks` are sorted by `ts_init`.
+#[must_use]
+pub fn find_time_gaps(ticks: &[QuoteTick], threshold_ns: u64) -> Vec<(UnixNanos, UnixNanos)> {
+    ticks
+        .windows(2)
+        .map(|pair| (pair[0].ts_init, pair[1].ts_init))
+        .filter(|(prev_ts, next_ts)| next_ts.saturating_sub(*prev_ts) > threshold_ns)
+        .collect()
+}
+
+/// Merges the quote ticks `a` and `b`, each sorted by `ts_init`, into a
+/// single vector sorted by `ts_init`.
+///
+/// The merge is stable, ticks of `a` come before ticks of `b` with an equal
+/// `ts_init` and the order of ticks within each input is kept.
+#[must_use]
+pub fn merge_quotes(a: Vec<QuoteTick>, b: Vec<QuoteTick>) -> Vec<QuoteTick> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    loop {
+        let take_a = match (a.peek(), b.peek()) {
+            (Some(next_a), Some(next_b)) => next_a.ts_init <= next_b.ts_init,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return merged,
+        };
+        merged.extend(if take_a { a.next() } else { b.next() });
+    }
+}
+
+/// Represents a single quote tick where the bid and ask sizes may be unknown.
+///
+/// Some feeds omit sizes, a `None` size distinguishes "size unknown" from a
+/// size of zero.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartialQuoteTick {
+    pub instrument_id: InstrumentId,
+    pub bid: Price,
+    pub ask: Price,
+    pub bid_size: Option<Quantity>,
+    pub ask_size: Option<Quantity>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
 /// Represents a single trade tick in a financial market.
 #[repr(C)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -181,6 +356,7 @@ pub extern "C" fn quote_tick_new(
         ts_event,
         ts_init,
     )
+    .unwrap()
 }
 
 #[no_mangle]
@@ -206,6 +382,7 @@ pub extern "C" fn quote_tick_from_raw(
         ts_event,
         ts_init,
     )
+    .unwrap()
 }
 
 /// Returns a [`QuoteTick`] as a C string pointer.
@@ -258,10 +435,11 @@ pub extern "C" fn trade_tick_to_cstr(tick: &TradeTick) -> *const c_char {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::time::UnixNanos;
     use rstest::rstest;
 
-    use crate::data::tick::{QuoteTick, TradeTick};
-    use crate::enums::{AggressorSide, PriceType};
+    use crate::data::tick::{find_time_gaps, merge_quotes, QuoteTick, TradeTick};
+    use crate::enums::{AggressorSide, OrderSide, PriceType};
     use crate::identifiers::instrument_id::InstrumentId;
     use crate::identifiers::trade_id::TradeId;
     use crate::types::price::Price;
@@ -284,6 +462,96 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(10000.0, 10001.0, false)]
+    #[case(10000.0, 10000.0, false)]
+    #[case(10001.0, 10000.0, true)]
+    fn test_quote_tick_is_crossed(#[case] bid: f64, #[case] ask: f64, #[case] expected: bool) {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            bid: Price::new(bid, 4),
+            ask: Price::new(ask, 4),
+            bid_size: Quantity::new(1.0, 8),
+            ask_size: Quantity::new(1.0, 8),
+            ts_event: 0,
+            ts_init: 0,
+        };
+        assert_eq!(tick.is_crossed(), expected);
+    }
+
+    #[test]
+    fn test_quote_tick_record_round_trip() {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            bid: Price::new(10000.1234, 4),
+            ask: Price::new(10001.5678, 4),
+            bid_size: Quantity::new(1.5, 8),
+            ask_size: Quantity::new(0.00000001, 8),
+            ts_event: 1_650_000_000_123_456_789,
+            ts_init: 1_650_000_000_987_654_321,
+        };
+
+        let record = tick.to_record();
+
+        assert_eq!(record.instrument_id, "ETHUSDT-PERP.BINANCE");
+        assert!((record.bid - 10000.1234).abs() < 1e-9);
+        assert!((record.ask - 10001.5678).abs() < 1e-9);
+        assert!((record.bid_size - 1.5).abs() < 1e-12);
+        assert!((record.ask_size - 0.00000001).abs() < 1e-12);
+        assert_eq!(record.ts_event, 1_650_000_000_123_456_789);
+        assert_eq!(record.ts_init, 1_650_000_000_987_654_321);
+        assert_eq!(QuoteTick::from_record(&record, 4, 8).unwrap(), tick);
+    }
+
+    #[test]
+    fn test_quote_tick_split_sides() {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            bid: Price::new(10000.1234, 4),
+            ask: Price::new(10001.5678, 4),
+            bid_size: Quantity::new(1.5, 8),
+            ask_size: Quantity::new(0.25, 8),
+            ts_event: 1_650_000_000_123_456_789,
+            ts_init: 1_650_000_000_987_654_321,
+        };
+
+        let (bid, ask) = tick.split_sides();
+
+        assert_eq!(bid.side, OrderSide::Buy);
+        assert_eq!(bid.price, tick.bid);
+        assert_eq!(bid.size, tick.bid_size);
+        assert_eq!(ask.side, OrderSide::Sell);
+        assert_eq!(ask.price, tick.ask);
+        assert_eq!(ask.size, tick.ask_size);
+        for side in [&bid, &ask] {
+            assert_eq!(side.ts_event, tick.ts_event);
+            assert_eq!(side.ts_init, tick.ts_init);
+        }
+    }
+
+    #[test]
+    fn test_quote_tick_from_record_with_invalid_values_errors() {
+        let record = QuoteTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            bid: Price::new(10000.0, 4),
+            ask: Price::new(10001.0, 4),
+            bid_size: Quantity::new(1.0, 8),
+            ask_size: Quantity::new(1.0, 8),
+            ts_event: 0,
+            ts_init: 0,
+        }
+        .to_record();
+
+        assert!(QuoteTick::from_record(&record, 10, 8).is_err());
+        assert!(QuoteTick::from_record(&record, 4, 10).is_err());
+        let mut invalid = record.clone();
+        invalid.instrument_id = "ETHUSDT-PERP".to_string();
+        assert!(QuoteTick::from_record(&invalid, 4, 8).is_err());
+        let mut invalid = record;
+        invalid.bid_size = -1.0;
+        assert!(QuoteTick::from_record(&invalid, 4, 8).is_err());
+    }
+
     #[rstest(
         input,
         expected,
@@ -306,6 +574,91 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case(1.0, 1.0, 10_000_500_000_000)]
+    #[case(3.0, 1.0, 10_000_750_000_000)]
+    #[case(1.0, 3.0, 10_000_250_000_000)]
+    #[case(0.0, 2.0, 10_000_000_000_000)]
+    #[case(0.0, 0.0, 10_000_500_000_000)]
+    fn test_quote_tick_weighted_mid(
+        #[case] bid_size: f64,
+        #[case] ask_size: f64,
+        #[case] expected: i64,
+    ) {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            bid: Price::new(10000.0, 4),
+            ask: Price::new(10001.0, 4),
+            bid_size: Quantity::new(bid_size, 8),
+            ask_size: Quantity::new(ask_size, 8),
+            ts_event: 0,
+            ts_init: 0,
+        };
+
+        let result = tick.weighted_mid();
+        assert_eq!(result.raw, expected);
+        assert_eq!(result.precision, 5);
+    }
+
+    #[test]
+    fn test_quote_tick_weighted_mid_rounds_to_precision() {
+        let tick = QuoteTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            bid: Price::new(1.0, 0),
+            ask: Price::new(2.0, 0),
+            bid_size: Quantity::new(1.0, 0),
+            ask_size: Quantity::new(2.0, 0),
+            ts_event: 0,
+            ts_init: 0,
+        };
+
+        // (1 * 2 + 2 * 1) / 3 = 1.333.. at precision 1
+        assert_eq!(tick.weighted_mid(), Price::new(1.3, 1));
+    }
+
+    #[test]
+    fn test_quote_tick_new() {
+        let tick = QuoteTick::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Price::new(10000.0, 4),
+            Price::new(10001.0, 4),
+            Quantity::new(1.0, 8),
+            Quantity::new(2.0, 8),
+            1,
+            2,
+        )
+        .unwrap();
+        assert_eq!(tick.bid, Price::new(10000.0, 4));
+        assert_eq!(tick.ask_size, Quantity::new(2.0, 8));
+        assert_eq!(tick.ts_init, 2);
+    }
+
+    #[rstest(
+        bid_prec,
+        ask_prec,
+        bid_size_prec,
+        ask_size_prec,
+        case(4, 5, 8, 8),
+        case(4, 4, 8, 7)
+    )]
+    fn test_quote_tick_new_with_precision_mismatch_errors(
+        bid_prec: u8,
+        ask_prec: u8,
+        bid_size_prec: u8,
+        ask_size_prec: u8,
+    ) {
+        let result = QuoteTick::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Price::new(10000.0, bid_prec),
+            Price::new(10001.0, ask_prec),
+            Quantity::new(1.0, bid_size_prec),
+            Quantity::new(1.0, ask_size_prec),
+            0,
+            0,
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_trade_tick_to_string() {
         let tick = TradeTick {
@@ -322,4 +675,83 @@ mod tests {
             "ETHUSDT-PERP.BINANCE,10000.0000,1.00000000,BUYER,123456789,0"
         );
     }
+
+    #[test]
+    fn test_find_time_gaps() {
+        let ts_inits = [0, 10, 20, 100, 110, 120, 500, 505, 515];
+        let ticks: Vec<QuoteTick> = ts_inits
+            .iter()
+            .map(|ts_init| QuoteTick {
+                instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                bid: Price::new(10000.0, 4),
+                ask: Price::new(10001.0, 4),
+                bid_size: Quantity::new(1.0, 8),
+                ask_size: Quantity::new(1.0, 8),
+                ts_event: *ts_init,
+                ts_init: *ts_init,
+            })
+            .collect();
+
+        assert_eq!(find_time_gaps(&ticks, 10), vec![(20, 100), (120, 500)]);
+        assert!(find_time_gaps(&ticks, 400).is_empty());
+        assert!(find_time_gaps(&ticks[..1], 0).is_empty());
+    }
+
+    fn quote_ticks(bid: f64, ts_inits: &[UnixNanos]) -> Vec<QuoteTick> {
+        ts_inits
+            .iter()
+            .map(|ts_init| QuoteTick {
+                instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                bid: Price::new(bid, 4),
+                ask: Price::new(10001.0, 4),
+                bid_size: Quantity::new(1.0, 8),
+                ask_size: Quantity::new(1.0, 8),
+                ts_event: *ts_init,
+                ts_init: *ts_init,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_quotes_interleaved() {
+        let merged = merge_quotes(
+            quote_ticks(10000.0, &[1, 4, 5, 9]),
+            quote_ticks(9999.0, &[2, 3, 6, 10, 11]),
+        );
+
+        let ts_inits: Vec<UnixNanos> = merged.iter().map(|tick| tick.ts_init).collect();
+        assert_eq!(ts_inits, [1, 2, 3, 4, 5, 6, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_merge_quotes_duplicate_timestamps_a_first() {
+        let merged = merge_quotes(
+            quote_ticks(10000.0, &[1, 2, 2]),
+            quote_ticks(9999.0, &[2, 2, 3]),
+        );
+
+        let result: Vec<(UnixNanos, f64)> = merged
+            .iter()
+            .map(|tick| (tick.ts_init, tick.bid.as_f64()))
+            .collect();
+        assert_eq!(
+            result,
+            [
+                (1, 10000.0),
+                (2, 10000.0),
+                (2, 10000.0),
+                (2, 9999.0),
+                (2, 9999.0),
+                (3, 9999.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge_quotes_empty_input() {
+        let a = quote_ticks(10000.0, &[1, 2]);
+        assert_eq!(merge_quotes(a.clone(), vec![]), a);
+        assert_eq!(merge_quotes(vec![], a.clone()), a);
+        assert!(merge_quotes(vec![], vec![]).is_empty());
+    }
 }
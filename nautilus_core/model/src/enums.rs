@@ -18,10 +18,11 @@ use std::fmt::Debug;
 use std::str::FromStr;
 
 use nautilus_core::string::{cstr_to_string, string_to_cstr};
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 use strum::{Display, EnumString, FromRepr};
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -32,7 +33,7 @@ pub enum AccountType {
     Betting = 3,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -42,7 +43,7 @@ pub enum AggregationSource {
     Internal = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -53,7 +54,22 @@ pub enum AggressorSide {
     Seller = 2,
 }
 
-#[pyclass]
+impl From<AggressorSide> for u8 {
+    fn from(value: AggressorSide) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for AggressorSide {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_repr(value as usize)
+            .ok_or_else(|| format!("invalid `AggressorSide` enum value, was {value}"))
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -71,7 +87,7 @@ pub enum AssetClass {
     SportsBetting = 9,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -86,7 +102,7 @@ pub enum AssetType {
     Warrant = 7,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -110,7 +126,7 @@ pub enum BarAggregation {
     Month = 16,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -122,7 +138,7 @@ pub enum BookAction {
     Clear = 4,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -137,7 +153,7 @@ pub enum BookType {
     L3_MBO = 3,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -149,7 +165,7 @@ pub enum ContingencyType {
     Ouo = 3,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -159,7 +175,7 @@ pub enum CurrencyType {
     Fiat = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -169,7 +185,7 @@ pub enum DepthType {
     Exposure = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -179,7 +195,7 @@ pub enum InstrumentCloseType {
     ContractExpired = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -191,7 +207,7 @@ pub enum LiquiditySide {
     Taker = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -204,7 +220,7 @@ pub enum MarketStatus {
     PreClose = 5,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -215,7 +231,7 @@ pub enum OmsType {
     Hedging = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -225,7 +241,7 @@ pub enum OptionKind {
     Put = 2,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -237,7 +253,22 @@ pub enum OrderSide {
     Sell = 2,
 }
 
-#[pyclass]
+impl From<OrderSide> for u8 {
+    fn from(value: OrderSide) -> Self {
+        value as u8
+    }
+}
+
+impl TryFrom<u8> for OrderSide {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_repr(value as usize)
+            .ok_or_else(|| format!("invalid `OrderSide` enum value, was {value}"))
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -257,7 +288,7 @@ pub enum OrderStatus {
     Filled = 12,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -274,7 +305,7 @@ pub enum OrderType {
     TrailingStopLimit = 9,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -287,7 +318,7 @@ pub enum PositionSide {
     Short = 3,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -299,7 +330,7 @@ pub enum PriceType {
     Last = 4,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -314,7 +345,7 @@ pub enum TimeInForce {
     AtTheClose = 7,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -325,7 +356,7 @@ pub enum TradingState {
     Reducing = 3,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -338,7 +369,7 @@ pub enum TrailingOffsetType {
     PriceTier = 4,
 }
 
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
 #[strum(ascii_case_insensitive)]
@@ -404,6 +435,22 @@ pub unsafe extern "C" fn aggressor_side_from_cstr(ptr: *const c_char) -> Aggress
         .unwrap_or_else(|_| panic!("invalid `AggressorSide` enum string value, was '{value}'"))
 }
 
+/// Returns the integer representation of the enum, as used by the parquet
+/// encoders.
+#[no_mangle]
+pub extern "C" fn aggressor_side_to_u8(value: AggressorSide) -> u8 {
+    u8::from(value)
+}
+
+/// Returns an enum from its integer representation.
+///
+/// # Panics
+/// - If `value` is not a valid `AggressorSide`.
+#[no_mangle]
+pub extern "C" fn aggressor_side_from_u8(value: u8) -> AggressorSide {
+    AggressorSide::try_from(value).unwrap_or_else(|e| panic!("{e}"))
+}
+
 #[no_mangle]
 pub extern "C" fn asset_class_to_cstr(value: AssetClass) -> *const c_char {
     string_to_cstr(&value.to_string())
@@ -631,6 +678,22 @@ pub unsafe extern "C" fn order_side_from_cstr(ptr: *const c_char) -> OrderSide {
         .unwrap_or_else(|_| panic!("invalid `OrderSide` enum string value, was '{value}'"))
 }
 
+/// Returns the integer representation of the enum, as used by the parquet
+/// encoders.
+#[no_mangle]
+pub extern "C" fn order_side_to_u8(value: OrderSide) -> u8 {
+    u8::from(value)
+}
+
+/// Returns an enum from its integer representation.
+///
+/// # Panics
+/// - If `value` is not a valid `OrderSide`.
+#[no_mangle]
+pub extern "C" fn order_side_from_u8(value: u8) -> OrderSide {
+    OrderSide::try_from(value).unwrap_or_else(|e| panic!("{e}"))
+}
+
 #[no_mangle]
 pub extern "C" fn order_status_to_cstr(value: OrderStatus) -> *const c_char {
     string_to_cstr(&value.to_string())
@@ -758,3 +821,41 @@ pub unsafe extern "C" fn trigger_type_from_cstr(ptr: *const c_char) -> TriggerTy
     TriggerType::from_str(&value)
         .unwrap_or_else(|_| panic!("invalid `TriggerType` enum string value, was '{value}'"))
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(OrderSide::NoOrderSide, 0)]
+    #[case(OrderSide::Buy, 1)]
+    #[case(OrderSide::Sell, 2)]
+    fn test_order_side_u8_round_trip(#[case] side: OrderSide, #[case] value: u8) {
+        assert_eq!(order_side_to_u8(side), value);
+        assert_eq!(order_side_from_u8(value), side);
+        assert_eq!(OrderSide::try_from(u8::from(side)), Ok(side));
+    }
+
+    #[rstest]
+    #[case(AggressorSide::NoAggressor, 0)]
+    #[case(AggressorSide::Buyer, 1)]
+    #[case(AggressorSide::Seller, 2)]
+    fn test_aggressor_side_u8_round_trip(#[case] side: AggressorSide, #[case] value: u8) {
+        assert_eq!(aggressor_side_to_u8(side), value);
+        assert_eq!(aggressor_side_from_u8(value), side);
+        assert_eq!(AggressorSide::try_from(u8::from(side)), Ok(side));
+    }
+
+    #[rstest]
+    #[case(3)]
+    #[case(u8::MAX)]
+    fn test_side_try_from_out_of_range_u8_errors(#[case] value: u8) {
+        assert!(OrderSide::try_from(value).is_err());
+        assert!(AggressorSide::try_from(value).is_err());
+    }
+}
@@ -18,6 +18,7 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,15 +37,68 @@ impl Display for AccountId {
     }
 }
 
+impl PartialEq<str> for AccountId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for AccountId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        AccountId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for AccountId {
+    /// Creates an [`AccountId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`AccountId::new`].
+    fn from(value: String) -> Self {
+        AccountId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl AccountId {
+    /// Creates an [`AccountId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`AccountId` value")?;
+        correctness::check_string_contains(&value, "-", "`AccountId` value")?;
+
+        Ok(AccountId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`AccountId`] from `s`, which must contain '-' between
+    /// the issuer and the account number.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`AccountId` value");
-        correctness::string_contains(s, "-", "`TraderId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
 
-        AccountId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -60,6 +114,11 @@ pub unsafe extern "C" fn account_id_new(ptr: *const c_char) -> AccountId {
     AccountId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `account_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `account_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn account_id_clone(account_id: &AccountId) -> AccountId {
     account_id.clone()
@@ -77,6 +136,12 @@ pub extern "C" fn account_id_to_cstr(account_id: &AccountId) -> *const c_char {
     string_to_cstr(&account_id.value)
 }
 
+/// Returns the UTF-8 byte length of an [`AccountId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn account_id_len(account_id: &AccountId) -> usize {
+    account_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn account_id_eq(lhs: &AccountId, rhs: &AccountId) -> u8 {
     u8::from(lhs == rhs)
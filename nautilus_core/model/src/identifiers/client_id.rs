@@ -18,10 +18,13 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
 
+use crate::identifiers::{is_valid_value, IdentifierFfiError};
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
 #[allow(clippy::box_collection)] // C ABI compatibility
@@ -36,17 +39,103 @@ impl Display for ClientId {
     }
 }
 
+impl PartialEq<str> for ClientId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ClientId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for ClientId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        ClientId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for ClientId {
+    /// Creates a [`ClientId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`ClientId::new`].
+    fn from(value: String) -> Self {
+        ClientId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl ClientId {
+    /// Creates a [`ClientId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`ClientId` value")?;
+
+        Ok(ClientId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`ClientId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`ClientId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
 
-        ClientId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the values are equal ignoring ASCII case, such as for
+    /// venue codes, without changing either stored value.
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &ClientId) -> bool {
+        self.value.eq_ignore_ascii_case(other.value.as_str())
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
+/// Checks `s` is a valid [`ClientId`] value in a const context, backing the
+/// compile time check of the [`client_id!`](crate::client_id) macro.
+///
+/// # Panics
+/// - If `s` is not a valid value.
+pub const fn check_client_id(s: &str) {
+    assert!(
+        is_valid_value(s.as_bytes(), 0, s.len()),
+        "invalid `ClientId` value, must be non-empty, ASCII only and not all whitespace"
+    );
+}
+
+/// Creates a [`ClientId`] from a string literal, failing compilation if the
+/// literal is not a valid value.
+///
+/// ```
+/// let id = nautilus_model::client_id!("BINANCE");
+/// assert_eq!(id.to_string(), "BINANCE");
+/// ```
+#[macro_export]
+macro_rules! client_id {
+    ($s:literal) => {{
+        const _: () = $crate::identifiers::client_id::check_client_id($s);
+        $crate::identifiers::client_id::ClientId::new($s)
+    }};
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -59,6 +148,38 @@ pub unsafe extern "C" fn client_id_new(ptr: *const c_char) -> ClientId {
     ClientId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Writes a [`ClientId`] from a C string pointer to `out`, returning zero on
+/// success or an [`IdentifierFfiError`] code, in which case `out` is left
+/// untouched.
+///
+/// Unlike `client_id_new` this never panics on the input.
+///
+/// # Safety
+/// - Assumes `ptr` is null or a valid C string pointer.
+/// - Assumes `out` is null or valid for writes, any value it holds is
+///   overwritten without being freed.
+#[no_mangle]
+pub unsafe extern "C" fn client_id_new_checked(ptr: *const c_char, out: *mut ClientId) -> i32 {
+    if ptr.is_null() || out.is_null() {
+        return IdentifierFfiError::NullPointer as i32;
+    }
+    let Ok(s) = CStr::from_ptr(ptr).to_str() else {
+        return IdentifierFfiError::InvalidUtf8 as i32;
+    };
+    match s.parse::<ClientId>() {
+        Ok(client_id) => {
+            out.write(client_id);
+            0
+        }
+        Err(_) => IdentifierFfiError::InvalidValue as i32,
+    }
+}
+
+/// Returns an owned clone of the given `client_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `client_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn client_id_clone(client_id: &ClientId) -> ClientId {
     client_id.clone()
@@ -76,11 +197,22 @@ pub extern "C" fn client_id_to_cstr(client_id: &ClientId) -> *const c_char {
     string_to_cstr(&client_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`ClientId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn client_id_len(client_id: &ClientId) -> usize {
+    client_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn client_id_eq(lhs: &ClientId, rhs: &ClientId) -> u8 {
     u8::from(lhs == rhs)
 }
 
+#[no_mangle]
+pub extern "C" fn client_id_eq_ignore_case(lhs: &ClientId, rhs: &ClientId) -> u8 {
+    u8::from(lhs.eq_ignore_case(rhs))
+}
+
 #[no_mangle]
 pub extern "C" fn client_id_hash(client_id: &ClientId) -> u64 {
     let mut h = DefaultHasher::new();
@@ -93,6 +225,12 @@ pub extern "C" fn client_id_hash(client_id: &ClientId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+    use std::ptr;
+
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -110,12 +248,67 @@ mod tests {
         assert_eq!(format!("{id}"), "BINANCE");
     }
 
+    #[test]
+    fn test_equality_with_str() {
+        let id = ClientId::new("BINANCE");
+        assert!(id == "BINANCE");
+        assert!(id == *"BINANCE");
+        assert!(id != "DYDX");
+        assert!(id != *"DYDX");
+    }
+
     #[test]
     fn test_client_id_new() {
         let id = ClientId::new("BINANCE");
         assert_eq!(id.value.as_str(), "BINANCE");
     }
 
+    #[test]
+    fn test_client_id_macro() {
+        assert_eq!(crate::client_id!("BINANCE"), ClientId::new("BINANCE"));
+    }
+
+    #[test]
+    fn test_client_id_new_checked_c() {
+        let c_str = CString::new("BINANCE").unwrap();
+        let mut out = MaybeUninit::<ClientId>::uninit();
+
+        let status = unsafe { client_id_new_checked(c_str.as_ptr(), out.as_mut_ptr()) };
+
+        assert_eq!(status, 0);
+        assert_eq!(unsafe { out.assume_init() }, ClientId::new("BINANCE"));
+    }
+
+    #[rstest]
+    #[case(b"\xff\xfe".as_slice(), IdentifierFfiError::InvalidUtf8)]
+    #[case(b" ".as_slice(), IdentifierFfiError::InvalidValue)]
+    #[case(b"B\xc3\x96RSE".as_slice(), IdentifierFfiError::InvalidValue)]
+    fn test_client_id_new_checked_c_with_invalid_string(
+        #[case] bytes: &[u8],
+        #[case] expected: IdentifierFfiError,
+    ) {
+        let c_str = CString::new(bytes).unwrap();
+        let mut out = ClientId::new("UNCHANGED");
+
+        let status = unsafe { client_id_new_checked(c_str.as_ptr(), &mut out) };
+
+        assert_eq!(status, expected as i32);
+        assert_eq!(out, ClientId::new("UNCHANGED"));
+    }
+
+    #[test]
+    fn test_client_id_new_checked_c_with_null_pointers() {
+        let c_str = CString::new("BINANCE").unwrap();
+        let mut out = ClientId::new("UNCHANGED");
+
+        let status = unsafe { client_id_new_checked(ptr::null(), &mut out) };
+        assert_eq!(status, IdentifierFfiError::NullPointer as i32);
+        assert_eq!(out, ClientId::new("UNCHANGED"));
+
+        let status = unsafe { client_id_new_checked(c_str.as_ptr(), ptr::null_mut()) };
+        assert_eq!(status, IdentifierFfiError::NullPointer as i32);
+    }
+
     #[test]
     fn test_client_id_clone_c() {
         let id = ClientId::new("BINANCE");
@@ -123,6 +316,18 @@ mod tests {
         assert_eq!(id, id_clone);
     }
 
+    #[test]
+    fn test_client_id_clone_c_is_independent() {
+        let id = ClientId::new("BINANCE");
+        let id_clone = client_id_clone(&id);
+        client_id_free(id);
+        assert_eq!(id_clone.to_string(), "BINANCE");
+
+        let id = client_id_clone(&id_clone);
+        client_id_free(id_clone);
+        assert_eq!(id, ClientId::new("BINANCE"));
+    }
+
     #[test]
     fn test_client_id_free_c() {
         let id = ClientId::new("BINANCE");
@@ -137,6 +342,21 @@ mod tests {
         assert_eq!(rust_string, "BINANCE");
     }
 
+    #[test]
+    fn test_client_id_len_c() {
+        let id = ClientId::new("BINANCE");
+        assert_eq!(client_id_len(&id), 7);
+    }
+
+    #[test]
+    fn test_client_id_len_c_with_multi_byte_utf8() {
+        // `new` only accepts ASCII, so build the value directly
+        let id = ClientId {
+            value: Box::new(Rc::new("BÖRSE-€".to_string())),
+        };
+        assert_eq!(client_id_len(&id), 10);
+    }
+
     #[test]
     fn test_client_id_eq_c() {
         let id1 = ClientId::new("BINANCE");
@@ -146,6 +366,26 @@ mod tests {
         assert_eq!(client_id_eq(&id1, &id3), 0);
     }
 
+    #[test]
+    fn test_client_id_eq_ignore_case() {
+        let id1 = ClientId::new("Binance");
+        let id2 = ClientId::new("BINANCE");
+        assert!(id1.eq_ignore_case(&id2));
+        assert_ne!(id1, id2);
+        assert!(!id1.eq_ignore_case(&ClientId::new("BINANCE-SPOT")));
+        assert_eq!(id1.to_string(), "Binance");
+    }
+
+    #[test]
+    fn test_client_id_eq_ignore_case_c() {
+        let id1 = ClientId::new("Binance");
+        let id2 = ClientId::new("BINANCE");
+        let id3 = ClientId::new("DYDX");
+        assert_eq!(client_id_eq_ignore_case(&id1, &id2), 1);
+        assert_eq!(client_id_eq(&id1, &id2), 0);
+        assert_eq!(client_id_eq_ignore_case(&id1, &id3), 0);
+    }
+
     #[test]
     fn test_client_id_hash_c() {
         let id1 = ClientId::new("BINANCE");
@@ -18,9 +18,20 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
+#[cfg(feature = "identifier-stats")]
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
+#[cfg(feature = "python")]
+use pyo3::types::{PyList, PyString};
+#[cfg(feature = "python")]
+use pyo3::{ffi, FromPyPointer, Python};
+
+/// The number of [`ClientOrderId`]s constructed in this process.
+#[cfg(feature = "identifier-stats")]
+static CONSTRUCTED_COUNT: AtomicU64 = AtomicU64::new(0);
 
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
@@ -36,14 +47,92 @@ impl Display for ClientOrderId {
     }
 }
 
+impl PartialEq<str> for ClientOrderId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ClientOrderId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for ClientOrderId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        ClientOrderId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for ClientOrderId {
+    /// Creates a [`ClientOrderId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`ClientOrderId::new`].
+    fn from(value: String) -> Self {
+        ClientOrderId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl ClientOrderId {
+    /// Creates a [`ClientOrderId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`ClientOrderId` value")?;
+
+        #[cfg(feature = "identifier-stats")]
+        CONSTRUCTED_COUNT.fetch_add(1, Ordering::Relaxed);
+        Ok(ClientOrderId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`ClientOrderId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`ClientOrderId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
 
-        ClientOrderId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns the number of [`ClientOrderId`]s constructed in this process,
+    /// such as for detecting leaks in long running backtests.
+    ///
+    /// Every construction is counted, including of equal values, but clones
+    /// are not as they share the value of the original.
+    #[cfg(feature = "identifier-stats")]
+    #[must_use]
+    pub fn constructed_count() -> u64 {
+        CONSTRUCTED_COUNT.load(Ordering::Relaxed)
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
+    }
+
+    /// Returns whether the value matches the regular expression `pattern`,
+    /// such as `^O-\d{8}-` for a venue specific format.
+    ///
+    /// The pattern is compiled on every call, so callers validating many ids
+    /// against the same format should compile a `Regex` once instead.
+    ///
+    /// # Errors
+    /// - If `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn matches(&self, pattern: &str) -> std::result::Result<bool, regex::Error> {
+        Ok(regex::Regex::new(pattern)?.is_match(&self.value))
     }
 }
 
@@ -59,6 +148,48 @@ pub unsafe extern "C" fn client_order_id_new(ptr: *const c_char) -> ClientOrderI
     ClientOrderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Fills the caller allocated `out` array with a [`ClientOrderId`] for each
+/// string in the given Python `list`, in order, in a single call.
+///
+/// The caller owns each identifier written to `out` and is responsible for
+/// freeing each one with `client_order_id_free`. The array itself remains
+/// owned by the caller.
+///
+/// # Safety
+/// - Assumes `ptr` is borrowed from a valid Python `list` of `str`.
+/// - Assumes `out` points to an array with space for at least `len` identifiers.
+/// # Panics
+/// - If `ptr` or `out` is null.
+/// - If the length of the list is not equal to `len`.
+/// - If any list item is not a valid identifier string.
+#[cfg(feature = "python")]
+#[no_mangle]
+pub unsafe extern "C" fn client_order_ids_from_pystr_list(
+    ptr: *mut ffi::PyObject,
+    out: *mut ClientOrderId,
+    len: usize,
+) {
+    assert!(!ptr.is_null(), "`ptr` was NULL");
+    assert!(!out.is_null(), "`out` was NULL");
+    Python::with_gil(|py| {
+        let list = PyList::from_borrowed_ptr(py, ptr);
+        assert_eq!(list.len(), len, "list length was not equal to `len`");
+        for (i, item) in list.iter().enumerate() {
+            let value = item
+                .downcast::<PyString>()
+                .expect("list item was not a `str`")
+                .to_str()
+                .expect("invalid UTF-8 in list item");
+            out.add(i).write(ClientOrderId::new(value));
+        }
+    });
+}
+
+/// Returns an owned clone of the given `client_order_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `client_order_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn client_order_id_clone(client_order_id: &ClientOrderId) -> ClientOrderId {
     client_order_id.clone()
@@ -76,6 +207,12 @@ pub extern "C" fn client_order_id_to_cstr(client_order_id: &ClientOrderId) -> *c
     string_to_cstr(&client_order_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`ClientOrderId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn client_order_id_len(client_order_id: &ClientOrderId) -> usize {
+    client_order_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn client_order_id_eq(lhs: &ClientOrderId, rhs: &ClientOrderId) -> u8 {
     u8::from(lhs == rhs)
@@ -117,4 +254,61 @@ mod tests {
 
         client_order_id_free(id); // No panic
     }
+
+    #[test]
+    fn test_starts_with_and_ends_with() {
+        let id = ClientOrderId::new("O-20200814-102234-001-001-1");
+        assert!(id.starts_with("O-20200814"));
+        assert!(!id.starts_with("o-20200814"));
+        assert!(id.ends_with("001-1"));
+        assert!(id.ends_with(""));
+        assert!(!id.ends_with("001-2"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_matches_pattern() {
+        let id = ClientOrderId::new("O-20230404-001");
+        assert!(id.matches(r"^O-\d{8}-").unwrap());
+        assert!(!ClientOrderId::new("O-2023-001")
+            .matches(r"^O-\d{8}-")
+            .unwrap());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_matches_invalid_pattern_errors() {
+        let id = ClientOrderId::new("O-20230404-001");
+        assert!(matches!(
+            id.matches(r"^O-(\d{8}"),
+            Err(regex::Error::Syntax(_))
+        ));
+    }
+
+    #[cfg(feature = "python")]
+    #[test]
+    fn test_client_order_ids_from_pystr_list() {
+        use pyo3::types::PyList;
+        use pyo3::{AsPyPointer, Python};
+
+        use crate::identifiers::client_order_id::client_order_ids_from_pystr_list;
+
+        pyo3::prepare_freethreaded_python();
+        let values = ["O-001", "O-002", "O-003"];
+        let mut out: Vec<ClientOrderId> = Vec::with_capacity(values.len());
+
+        Python::with_gil(|py| {
+            let list = PyList::new(py, values);
+            unsafe {
+                client_order_ids_from_pystr_list(list.as_ptr(), out.as_mut_ptr(), values.len());
+                out.set_len(values.len());
+            }
+        });
+
+        let result: Vec<String> = out.iter().map(ToString::to_string).collect();
+        assert_eq!(result, values);
+        for id in out {
+            client_order_id_free(id);
+        }
+    }
 }
@@ -18,10 +18,13 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
 
+use crate::identifiers::trader_id::TraderId;
+
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
 #[allow(clippy::box_collection)] // C ABI compatibility
@@ -36,14 +39,73 @@ impl Display for ComponentId {
     }
 }
 
+impl PartialEq<str> for ComponentId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ComponentId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for ComponentId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        ComponentId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for ComponentId {
+    /// Creates a [`ComponentId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`ComponentId::new`].
+    fn from(value: String) -> Self {
+        ComponentId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl ComponentId {
+    /// Creates a [`ComponentId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`ComponentId` value")?;
+
+        Ok(ComponentId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`ComponentId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`ComponentId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
+    }
 
-        ComponentId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Creates a new [`ComponentId`] for the given `component` of a trader,
+    /// joined with a `-` separator e.g. `TRADER-001-RiskEngine`.
+    #[must_use]
+    pub fn from_parts(trader_id: &TraderId, component: &str) -> Self {
+        ComponentId::new(&format!("{trader_id}-{component}"))
     }
 }
 
@@ -59,6 +121,11 @@ pub unsafe extern "C" fn component_id_new(ptr: *const c_char) -> ComponentId {
     ComponentId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `component_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `component_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn component_id_clone(component_id: &ComponentId) -> ComponentId {
     component_id.clone()
@@ -76,6 +143,12 @@ pub extern "C" fn component_id_to_cstr(component_id: &ComponentId) -> *const c_c
     string_to_cstr(&component_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`ComponentId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn component_id_len(component_id: &ComponentId) -> usize {
+    component_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn component_id_eq(lhs: &ComponentId, rhs: &ComponentId) -> u8 {
     u8::from(lhs == rhs)
@@ -95,6 +168,7 @@ pub extern "C" fn component_id_hash(component_id: &ComponentId) -> u64 {
 mod tests {
     use super::ComponentId;
     use crate::identifiers::component_id::component_id_free;
+    use crate::identifiers::trader_id::TraderId;
 
     #[test]
     fn test_equality() {
@@ -111,6 +185,13 @@ mod tests {
         assert_eq!(format!("{id}"), "RiskEngine");
     }
 
+    #[test]
+    fn test_from_parts() {
+        let trader_id = TraderId::new("TRADER-001");
+        let id = ComponentId::from_parts(&trader_id, "RiskEngine");
+        assert_eq!(id, ComponentId::new("TRADER-001-RiskEngine"));
+    }
+
     #[test]
     fn test_component_id_free() {
         let id = ComponentId::new("001");
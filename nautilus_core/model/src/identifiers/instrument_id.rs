@@ -17,9 +17,11 @@ use std::collections::hash_map::DefaultHasher;
 use std::ffi::c_char;
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 use nautilus_core::string::{cstr_to_string, string_to_cstr};
 
+use crate::identifiers::is_valid_value;
 use crate::identifiers::symbol::Symbol;
 use crate::identifiers::venue::Venue;
 
@@ -31,13 +33,25 @@ pub struct InstrumentId {
     pub venue: Venue,
 }
 
+impl FromStr for InstrumentId {
+    type Err = String;
+
+    /// Parses an [`InstrumentId`] of the form `{symbol}.{venue}`, split at
+    /// the last '.', where the symbol and venue must each be valid.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (symbol, venue) = s.rsplit_once('.').ok_or_else(|| {
+            format!("invalid string for `InstrumentId` value did not contain '.', was '{s}'")
+        })?;
+        Ok(InstrumentId {
+            symbol: symbol.parse()?,
+            venue: venue.parse()?,
+        })
+    }
+}
+
 impl From<&str> for InstrumentId {
     fn from(s: &str) -> Self {
-        let pieces = s.rsplit_once('.').expect("rsplit_once failed");
-        InstrumentId {
-            symbol: Symbol::new(pieces.0),
-            venue: Venue::new(pieces.1),
-        }
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
     }
 }
 
@@ -54,6 +68,47 @@ impl InstrumentId {
     }
 }
 
+/// Checks `s` is a valid [`InstrumentId`] value in a const context, backing
+/// the compile time check of the [`instrument_id!`](crate::instrument_id)
+/// macro.
+///
+/// # Panics
+/// - If `s` is not of the form `{symbol}.{venue}` with a valid symbol and venue.
+pub const fn check_instrument_id(s: &str) {
+    let bytes = s.as_bytes();
+    let mut dot = bytes.len();
+    while dot > 0 && bytes[dot - 1] != b'.' {
+        dot -= 1;
+    }
+    assert!(
+        dot > 0,
+        "invalid `InstrumentId` value, must be of the form `{{symbol}}.{{venue}}`"
+    );
+    assert!(
+        is_valid_value(bytes, 0, dot - 1),
+        "invalid `InstrumentId` symbol, must be non-empty, ASCII only and not all whitespace"
+    );
+    assert!(
+        is_valid_value(bytes, dot, bytes.len()),
+        "invalid `InstrumentId` venue, must be non-empty, ASCII only and not all whitespace"
+    );
+}
+
+/// Creates an [`InstrumentId`] from a string literal, failing compilation if
+/// the literal is not a valid value.
+///
+/// ```
+/// let id = nautilus_model::instrument_id!("EUR/USD.SIM");
+/// assert_eq!(id.venue.to_string(), "SIM");
+/// ```
+#[macro_export]
+macro_rules! instrument_id {
+    ($s:literal) => {{
+        const _: () = $crate::identifiers::instrument_id::check_instrument_id($s);
+        $crate::identifiers::instrument_id::InstrumentId::from($s)
+    }};
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -73,6 +128,30 @@ pub unsafe extern "C" fn instrument_id_new_from_cstr(ptr: *const c_char) -> Inst
     InstrumentId::from(cstr_to_string(ptr).as_str())
 }
 
+/// Returns an [`InstrumentId`] from C string pointers to its symbol and venue,
+/// joined as `{symbol}.{venue}`.
+///
+/// # Safety
+/// - Assumes `symbol_ptr` and `venue_ptr` are valid C string pointers.
+///
+/// # Panics
+/// - If the symbol or venue is not a valid value.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_from_parts(
+    symbol_ptr: *const c_char,
+    venue_ptr: *const c_char,
+) -> InstrumentId {
+    InstrumentId::new(
+        Symbol::new(&cstr_to_string(symbol_ptr)),
+        Venue::new(&cstr_to_string(venue_ptr)),
+    )
+}
+
+/// Returns an owned clone of the given `instrument_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `instrument_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn instrument_id_clone(instrument_id: &InstrumentId) -> InstrumentId {
     instrument_id.clone()
@@ -90,6 +169,12 @@ pub extern "C" fn instrument_id_to_cstr(instrument_id: &InstrumentId) -> *const
     string_to_cstr(&instrument_id.to_string())
 }
 
+/// Returns the UTF-8 byte length of an [`InstrumentId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn instrument_id_len(instrument_id: &InstrumentId) -> usize {
+    instrument_id.symbol.value.len() + 1 + instrument_id.venue.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn instrument_id_eq(lhs: &InstrumentId, rhs: &InstrumentId) -> u8 {
     u8::from(lhs == rhs)
@@ -107,10 +192,13 @@ pub extern "C" fn instrument_id_hash(instrument_id: &InstrumentId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::ffi::CStr;
+    use std::ffi::{CStr, CString};
 
     use super::InstrumentId;
-    use crate::identifiers::instrument_id::{instrument_id_free, instrument_id_to_cstr};
+    use crate::identifiers::instrument_id::{
+        instrument_id_eq, instrument_id_free, instrument_id_from_parts, instrument_id_hash,
+        instrument_id_len, instrument_id_to_cstr,
+    };
 
     #[test]
     fn test_equality() {
@@ -127,6 +215,18 @@ mod tests {
         assert_eq!(format!("{id}"), "ETH/USDT.BINANCE");
     }
 
+    #[test]
+    fn test_instrument_id_macro() {
+        assert_eq!(
+            crate::instrument_id!("ETH/USDT.BINANCE"),
+            InstrumentId::from("ETH/USDT.BINANCE")
+        );
+        assert_eq!(
+            crate::instrument_id!("BRK.B.XNYS"),
+            InstrumentId::from("BRK.B.XNYS")
+        );
+    }
+
     #[test]
     fn test_to_cstr() {
         unsafe {
@@ -136,10 +236,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_instrument_id_from_parts_c() {
+        let symbol = CString::new("EUR/USD").unwrap();
+        let venue = CString::new("SIM").unwrap();
+
+        let id = unsafe { instrument_id_from_parts(symbol.as_ptr(), venue.as_ptr()) };
+
+        assert_eq!(id.to_string(), "EUR/USD.SIM");
+        assert_eq!(id, InstrumentId::from("EUR/USD.SIM"));
+    }
+
+    #[test]
+    fn test_instrument_id_len_c() {
+        let id = InstrumentId::from("ETH/USDT.BINANCE");
+        assert_eq!(instrument_id_len(&id), id.to_string().len());
+    }
+
     #[test]
     fn test_instrument_id_free() {
         let id = InstrumentId::from("ETH/USDT.BINANCE");
 
         instrument_id_free(id); // No panic
     }
+
+    #[test]
+    fn test_instrument_id_eq_c() {
+        let id1 = InstrumentId::from("ETH/USDT.BINANCE");
+        let id2 = InstrumentId::from("ETH/USDT.BINANCE");
+        let id3 = InstrumentId::from("XBT/USD.BITMEX");
+        assert_eq!(instrument_id_eq(&id1, &id2), 1);
+        assert_eq!(instrument_id_eq(&id1, &id3), 0);
+    }
+
+    #[test]
+    fn test_instrument_id_hash_c() {
+        let id1 = InstrumentId::from("ETH/USDT.BINANCE");
+        let id2 = InstrumentId::from("ETH/USDT.BINANCE");
+        let id3 = InstrumentId::from("XBT/USD.BITMEX");
+        assert_eq!(instrument_id_hash(&id1), instrument_id_hash(&id2));
+        assert_ne!(instrument_id_hash(&id1), instrument_id_hash(&id3));
+    }
 }
@@ -13,17 +13,198 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+//! Identifiers for the domain model.
+//!
+//! Each identifier is created with `new`, which panics on an invalid value, or
+//! parsed with `FromStr`, which returns the error instead. Valid values are
+//! non-empty, ASCII only and not all whitespace, on top of any rule of the
+//! identifier itself. Values are stored verbatim, leading and trailing
+//! whitespace included, so every identifier round trips through `to_string`
+//! and `parse`.
+
 pub mod account_id;
 pub mod client_id;
 pub mod client_order_id;
 pub mod component_id;
 pub mod exec_algorithm_id;
 pub mod instrument_id;
+pub mod order_key;
 pub mod order_list_id;
+pub mod owned;
 pub mod position_id;
+/// Re-exports all identifier types, so they can be imported together with
+/// `use nautilus_model::identifiers::prelude::*`, along with `FromStr` so
+/// they can be parsed with `from_str`.
+pub mod prelude;
 pub mod strategy_id;
 pub mod symbol;
 pub mod trade_id;
 pub mod trader_id;
 pub mod venue;
 pub mod venue_order_id;
+
+/// The reasons a checked identifier constructor of the C API fails, returned
+/// as nonzero status codes, zero is success.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IdentifierFfiError {
+    /// The string or out pointer was null.
+    NullPointer = 1,
+    /// The string was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The string was not a valid identifier value.
+    InvalidValue = 3,
+}
+
+/// Returns whether `bytes[start..end]` is a valid identifier value, by the
+/// same rules as `correctness::check_valid_string` but usable in const
+/// contexts: non-empty, ASCII only and not all whitespace.
+pub(crate) const fn is_valid_value(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut all_whitespace = true;
+    let mut i = start;
+    while i < end {
+        if !bytes[i].is_ascii() {
+            return false;
+        }
+        if !bytes[i].is_ascii_whitespace() {
+            all_whitespace = false;
+        }
+        i += 1;
+    }
+    start < end && !all_whitespace
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fmt::{Debug, Display};
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::account_id::AccountId;
+    use super::client_id::ClientId;
+    use super::client_order_id::ClientOrderId;
+    use super::component_id::ComponentId;
+    use super::exec_algorithm_id::ExecAlgorithmId;
+    use super::instrument_id::InstrumentId;
+    use super::order_list_id::OrderListId;
+    use super::position_id::PositionId;
+    use super::strategy_id::StrategyId;
+    use super::symbol::Symbol;
+    use super::trade_id::TradeId;
+    use super::trader_id::TraderId;
+    use super::venue::Venue;
+    use super::venue_order_id::VenueOrderId;
+
+    /// Asserts `s` parses as a `T` which displays as `s` again.
+    fn assert_round_trip<T>(s: &str)
+    where
+        T: FromStr + Display,
+        T::Err: Debug,
+    {
+        let id = s.parse::<T>().unwrap();
+        assert_eq!(id.to_string(), s);
+    }
+
+    #[rstest]
+    #[case("SIM-001")]
+    #[case(" SIM-001")]
+    #[case("SIM-001 ")]
+    #[case("SIM -\t001")]
+    #[case("-")]
+    #[case("--")]
+    #[case("SIM-001.XNAS")]
+    #[case("O-20200814-102234-001-001-1")]
+    fn test_identifiers_round_trip(#[case] s: &str) {
+        assert_round_trip::<AccountId>(s);
+        assert_round_trip::<ClientId>(s);
+        assert_round_trip::<ClientOrderId>(s);
+        assert_round_trip::<ComponentId>(s);
+        assert_round_trip::<ExecAlgorithmId>(s);
+        assert_round_trip::<OrderListId>(s);
+        assert_round_trip::<PositionId>(s);
+        assert_round_trip::<StrategyId>(s);
+        assert_round_trip::<Symbol>(s);
+        assert_round_trip::<TradeId>(s);
+        assert_round_trip::<TraderId>(s);
+        assert_round_trip::<Venue>(s);
+        assert_round_trip::<VenueOrderId>(s);
+    }
+
+    /// Asserts a `T` from an owned `String` keeps its value and allocation,
+    /// given the pointer to the value of an id.
+    fn assert_from_string_reuses_allocation<T>(value_ptr: impl Fn(&T) -> *const u8)
+    where
+        T: From<String> + Display,
+    {
+        let value = "SIM-001".to_string();
+        let ptr = value.as_ptr();
+        let id = T::from(value);
+        assert_eq!(id.to_string(), "SIM-001");
+        assert_eq!(value_ptr(&id), ptr);
+    }
+
+    #[test]
+    fn test_identifiers_from_string_reuse_allocation() {
+        assert_from_string_reuses_allocation::<AccountId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<ClientId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<ClientOrderId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<ComponentId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<ExecAlgorithmId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<OrderListId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<PositionId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<StrategyId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<Symbol>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<TradeId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<TraderId>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<Venue>(|id| id.value.as_ptr());
+        assert_from_string_reuses_allocation::<VenueOrderId>(|id| id.value.as_ptr());
+    }
+
+    #[test]
+    #[should_panic(expected = "`TraderId` value")]
+    fn test_identifier_from_invalid_string_panics() {
+        let _ = TraderId::from("TRADER".to_string());
+    }
+
+    #[rstest]
+    #[case("ETH/USDT.BINANCE")]
+    #[case("ES.FUT.GLBX")]
+    #[case(" AUD/USD .SIM ")]
+    fn test_instrument_id_round_trip(#[case] s: &str) {
+        assert_round_trip::<InstrumentId>(s);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case(" ")]
+    #[case("\t\n")]
+    #[case("BÖRSE-1")]
+    #[case("€-")]
+    fn test_identifiers_from_invalid_str_errors(#[case] s: &str) {
+        assert!(s.parse::<AccountId>().is_err());
+        assert!(s.parse::<ClientId>().is_err());
+        assert!(s.parse::<ClientOrderId>().is_err());
+        assert!(s.parse::<ComponentId>().is_err());
+        assert!(s.parse::<ExecAlgorithmId>().is_err());
+        assert!(s.parse::<OrderListId>().is_err());
+        assert!(s.parse::<PositionId>().is_err());
+        assert!(s.parse::<StrategyId>().is_err());
+        assert!(s.parse::<Symbol>().is_err());
+        assert!(s.parse::<TradeId>().is_err());
+        assert!(s.parse::<TraderId>().is_err());
+        assert!(s.parse::<Venue>().is_err());
+        assert!(s.parse::<VenueOrderId>().is_err());
+    }
+
+    #[rstest]
+    #[case("NOSEPARATOR")]
+    #[case(".SIM")]
+    #[case("AUD/USD.")]
+    fn test_instrument_id_from_invalid_str_errors(#[case] s: &str) {
+        assert!(s.parse::<InstrumentId>().is_err());
+    }
+}
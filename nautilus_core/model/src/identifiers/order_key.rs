@@ -0,0 +1,136 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+
+use crate::identifiers::client_order_id::ClientOrderId;
+use crate::identifiers::venue_order_id::VenueOrderId;
+
+/// Represents a composite key identifying an order by both its client and
+/// venue assigned identifiers.
+#[repr(C)]
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct OrderKey {
+    pub client_order_id: ClientOrderId,
+    pub venue_order_id: VenueOrderId,
+}
+
+impl Display for OrderKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}-{}", self.client_order_id, self.venue_order_id)
+    }
+}
+
+impl OrderKey {
+    #[must_use]
+    pub fn new(client_order_id: ClientOrderId, venue_order_id: VenueOrderId) -> Self {
+        OrderKey {
+            client_order_id,
+            venue_order_id,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn order_key_new(
+    client_order_id: &ClientOrderId,
+    venue_order_id: &VenueOrderId,
+) -> OrderKey {
+    OrderKey::new(client_order_id.clone(), venue_order_id.clone())
+}
+
+/// Returns an owned clone of the given `order_key`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `order_key_free`,
+/// freeing either the clone or the original does not affect the other.
+#[no_mangle]
+pub extern "C" fn order_key_clone(order_key: &OrderKey) -> OrderKey {
+    order_key.clone()
+}
+
+/// Frees the memory for the given `order_key` by dropping.
+#[no_mangle]
+pub extern "C" fn order_key_free(order_key: OrderKey) {
+    drop(order_key); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_key_eq(lhs: &OrderKey, rhs: &OrderKey) -> u8 {
+    u8::from(lhs == rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn order_key_hash(order_key: &OrderKey) -> u64 {
+    let mut h = DefaultHasher::new();
+    order_key.hash(&mut h);
+    h.finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::OrderKey;
+    use crate::identifiers::client_order_id::ClientOrderId;
+    use crate::identifiers::order_key::{order_key_eq, order_key_free, order_key_hash};
+    use crate::identifiers::venue_order_id::VenueOrderId;
+
+    fn order_key(client_order_id: &str, venue_order_id: &str) -> OrderKey {
+        OrderKey::new(
+            ClientOrderId::new(client_order_id),
+            VenueOrderId::new(venue_order_id),
+        )
+    }
+
+    #[test]
+    fn test_string_reprs() {
+        let key = order_key("O-123456789", "001");
+        assert_eq!(key.to_string(), "O-123456789-001");
+    }
+
+    #[test]
+    fn test_order_key_eq() {
+        let key1 = order_key("O-123456789", "001");
+        let key2 = order_key("O-123456789", "001");
+        let key3 = order_key("O-123456789", "002");
+        let key4 = order_key("O-987654321", "001");
+        assert_eq!(order_key_eq(&key1, &key2), 1);
+        assert_eq!(order_key_eq(&key1, &key3), 0);
+        assert_eq!(order_key_eq(&key1, &key4), 0);
+    }
+
+    #[test]
+    fn test_order_key_hash() {
+        let key1 = order_key("O-123456789", "001");
+        let key2 = order_key("O-123456789", "001");
+        let key3 = order_key("O-123456789", "002");
+        assert_eq!(order_key_hash(&key1), order_key_hash(&key2));
+        assert_eq!(order_key_hash(&key1), order_key_hash(&key1));
+        assert_ne!(order_key_hash(&key1), order_key_hash(&key3));
+    }
+
+    #[test]
+    fn test_order_key_free() {
+        let key = order_key("O-123456789", "001");
+        order_key_free(key); // No panic
+    }
+}
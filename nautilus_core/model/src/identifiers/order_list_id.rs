@@ -18,6 +18,7 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,14 +37,66 @@ impl Display for OrderListId {
     }
 }
 
+impl PartialEq<str> for OrderListId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for OrderListId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for OrderListId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        OrderListId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for OrderListId {
+    /// Creates an [`OrderListId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`OrderListId::new`].
+    fn from(value: String) -> Self {
+        OrderListId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl OrderListId {
+    /// Creates an [`OrderListId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`OrderListId` value")?;
+
+        Ok(OrderListId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`OrderListId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`OrderListId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
 
-        OrderListId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -59,6 +112,11 @@ pub unsafe extern "C" fn order_list_id_new(ptr: *const c_char) -> OrderListId {
     OrderListId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `order_list_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `order_list_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn order_list_id_clone(order_list_id: &OrderListId) -> OrderListId {
     order_list_id.clone()
@@ -76,6 +134,12 @@ pub extern "C" fn order_list_id_to_cstr(order_list_id: &OrderListId) -> *const c
     string_to_cstr(&order_list_id.value)
 }
 
+/// Returns the UTF-8 byte length of an [`OrderListId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn order_list_id_len(order_list_id: &OrderListId) -> usize {
+    order_list_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn order_list_id_eq(lhs: &OrderListId, rhs: &OrderListId) -> u8 {
     u8::from(lhs == rhs)
@@ -93,8 +157,13 @@ pub extern "C" fn order_list_id_hash(order_list_id: &OrderListId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::ffi::CStr;
+
     use super::OrderListId;
-    use crate::identifiers::order_list_id::order_list_id_free;
+    use crate::identifiers::order_list_id::{
+        order_list_id_clone, order_list_id_eq, order_list_id_free, order_list_id_hash,
+        order_list_id_len, order_list_id_to_cstr,
+    };
 
     #[test]
     fn test_equality() {
@@ -117,4 +186,44 @@ mod tests {
 
         order_list_id_free(id); // No panic
     }
+
+    #[test]
+    fn test_order_list_id_clone_c() {
+        let id = OrderListId::new("OL-19700101-000-001-1");
+        let id_clone = order_list_id_clone(&id);
+        order_list_id_free(id);
+        assert_eq!(id_clone, "OL-19700101-000-001-1");
+    }
+
+    #[test]
+    fn test_order_list_id_to_cstr_c() {
+        let id = OrderListId::new("OL-19700101-000-001-1");
+        let c_string = order_list_id_to_cstr(&id);
+        let rust_string = unsafe { CStr::from_ptr(c_string) }.to_str().unwrap();
+        assert_eq!(rust_string, "OL-19700101-000-001-1");
+    }
+
+    #[test]
+    fn test_order_list_id_len_c() {
+        let id = OrderListId::new("OL-19700101-000-001-1");
+        assert_eq!(order_list_id_len(&id), 21);
+    }
+
+    #[test]
+    fn test_order_list_id_eq_c() {
+        let id1 = OrderListId::new("OL-19700101-000-001-1");
+        let id2 = OrderListId::new("OL-19700101-000-001-1");
+        let id3 = OrderListId::new("OL-19700101-000-001-2");
+        assert_eq!(order_list_id_eq(&id1, &id2), 1);
+        assert_eq!(order_list_id_eq(&id1, &id3), 0);
+    }
+
+    #[test]
+    fn test_order_list_id_hash_c() {
+        let id1 = OrderListId::new("OL-19700101-000-001-1");
+        let id2 = OrderListId::new("OL-19700101-000-001-1");
+        let id3 = OrderListId::new("OL-19700101-000-001-2");
+        assert_eq!(order_list_id_hash(&id1), order_list_id_hash(&id2));
+        assert_ne!(order_list_id_hash(&id1), order_list_id_hash(&id3));
+    }
 }
@@ -0,0 +1,171 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::Deref;
+
+use crate::identifiers::account_id::{account_id_free, AccountId};
+use crate::identifiers::client_id::{client_id_free, ClientId};
+use crate::identifiers::client_order_id::{client_order_id_free, ClientOrderId};
+use crate::identifiers::component_id::{component_id_free, ComponentId};
+use crate::identifiers::exec_algorithm_id::{exec_algorithm_id_free, ExecAlgorithmId};
+use crate::identifiers::instrument_id::{instrument_id_free, InstrumentId};
+use crate::identifiers::order_key::{order_key_free, OrderKey};
+use crate::identifiers::order_list_id::{order_list_id_free, OrderListId};
+use crate::identifiers::position_id::{position_id_free, PositionId};
+use crate::identifiers::strategy_id::{strategy_id_free, StrategyId};
+use crate::identifiers::symbol::{symbol_free, Symbol};
+use crate::identifiers::trade_id::{trade_id_free, TradeId};
+use crate::identifiers::trader_id::{trader_id_free, TraderId};
+use crate::identifiers::venue::{venue_free, Venue};
+use crate::identifiers::venue_order_id::{venue_order_id_free, VenueOrderId};
+
+/// An identifier which the C API hands out as owned, to be released with its
+/// `*_free` function.
+pub trait FreeCId {
+    /// Releases the identifier with its C API `*_free` function.
+    fn free_c(self);
+}
+
+macro_rules! impl_free_c_id {
+    ($($ty:ty => $free:ident),* $(,)?) => {
+        $(
+            impl FreeCId for $ty {
+                fn free_c(self) {
+                    $free(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_free_c_id!(
+    AccountId => account_id_free,
+    ClientId => client_id_free,
+    ClientOrderId => client_order_id_free,
+    ComponentId => component_id_free,
+    ExecAlgorithmId => exec_algorithm_id_free,
+    InstrumentId => instrument_id_free,
+    OrderKey => order_key_free,
+    OrderListId => order_list_id_free,
+    PositionId => position_id_free,
+    StrategyId => strategy_id_free,
+    Symbol => symbol_free,
+    TradeId => trade_id_free,
+    TraderId => trader_id_free,
+    Venue => venue_free,
+    VenueOrderId => venue_order_id_free,
+);
+
+/// Owns an identifier returned by the C API, such as from `client_id_new`,
+/// and releases it with its `*_free` function on drop.
+///
+/// This models the ownership contract of the C API for Rust callers, the
+/// identifier is freed exactly once, including on early returns and panics,
+/// unless ownership is taken back with [`OwnedCId::into_inner`].
+#[derive(Debug)]
+pub struct OwnedCId<T: FreeCId> {
+    id: Option<T>,
+}
+
+impl<T: FreeCId> OwnedCId<T> {
+    #[must_use]
+    pub fn new(id: T) -> Self {
+        OwnedCId { id: Some(id) }
+    }
+
+    /// Returns the identifier without freeing it, the caller becomes
+    /// responsible for freeing it.
+    #[must_use]
+    pub fn into_inner(mut self) -> T {
+        self.id.take().expect("identifier already taken")
+    }
+}
+
+impl<T: FreeCId> Deref for OwnedCId<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.id.as_ref().expect("identifier already taken")
+    }
+}
+
+impl<T: FreeCId> Drop for OwnedCId<T> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            id.free_c();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::identifiers::client_id::{client_id_clone, client_id_new};
+
+    #[test]
+    fn test_owned_c_id_frees_on_drop() {
+        let c_str = CString::new("BINANCE").unwrap();
+        let id = unsafe { client_id_new(c_str.as_ptr()) };
+        let owned = OwnedCId::new(client_id_clone(&id));
+        assert_eq!(*owned, id);
+        assert_eq!(Rc::strong_count(&id.value), 2);
+
+        drop(owned);
+
+        assert_eq!(Rc::strong_count(&id.value), 1);
+        assert_eq!(id.to_string(), "BINANCE");
+    }
+
+    #[test]
+    fn test_owned_c_id_frees_once_on_panic() {
+        let id = ClientId::new("BINANCE");
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _owned = OwnedCId::new(client_id_clone(&id));
+            panic!("error mid-flow");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&id.value), 1);
+    }
+
+    #[test]
+    fn test_owned_c_id_into_inner_does_not_free() {
+        let id = ClientId::new("BINANCE");
+        let inner = OwnedCId::new(client_id_clone(&id)).into_inner();
+        assert_eq!(Rc::strong_count(&id.value), 2);
+
+        client_id_free(inner);
+
+        assert_eq!(Rc::strong_count(&id.value), 1);
+    }
+
+    #[test]
+    fn test_owned_c_id_instrument_id() {
+        let id = InstrumentId::from("EUR/USD.SIM");
+        let owned = OwnedCId::new(id.clone());
+        assert_eq!(owned.venue, Venue::new("SIM"));
+        assert_eq!(Rc::strong_count(&id.symbol.value), 2);
+
+        drop(owned);
+
+        assert_eq!(Rc::strong_count(&id.symbol.value), 1);
+    }
+}
@@ -18,6 +18,7 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,14 +37,73 @@ impl Display for PositionId {
     }
 }
 
+impl PartialEq<str> for PositionId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for PositionId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for PositionId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        PositionId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for PositionId {
+    /// Creates a [`PositionId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`PositionId::new`].
+    fn from(value: String) -> Self {
+        PositionId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl PositionId {
+    /// Creates a [`PositionId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`PositionId` value")?;
+
+        Ok(PositionId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`PositionId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`PositionId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
 
-        PositionId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
+    }
+
+    /// Returns whether the position ID is virtual, i.e. generated by the
+    /// platform (prefixed with `P-`) rather than assigned by the venue.
+    #[must_use]
+    pub fn is_virtual(&self) -> bool {
+        self.value.starts_with("P-")
     }
 }
 
@@ -59,6 +119,11 @@ pub unsafe extern "C" fn position_id_new(ptr: *const c_char) -> PositionId {
     PositionId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `position_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `position_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn position_id_clone(position_id: &PositionId) -> PositionId {
     position_id.clone()
@@ -76,6 +141,17 @@ pub extern "C" fn position_id_to_cstr(position_id: &PositionId) -> *const c_char
     string_to_cstr(&position_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`PositionId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn position_id_len(position_id: &PositionId) -> usize {
+    position_id.value.len()
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_is_virtual(position_id: &PositionId) -> u8 {
+    u8::from(position_id.is_virtual())
+}
+
 #[no_mangle]
 pub extern "C" fn position_id_eq(lhs: &PositionId, rhs: &PositionId) -> u8 {
     u8::from(lhs == rhs)
@@ -93,8 +169,10 @@ pub extern "C" fn position_id_hash(position_id: &PositionId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::PositionId;
-    use crate::identifiers::position_id::position_id_free;
+    use crate::identifiers::position_id::{position_id_free, position_id_is_virtual};
 
     #[test]
     fn test_equality() {
@@ -116,4 +194,16 @@ mod tests {
         let id = PositionId::new("001");
         position_id_free(id); // No panic
     }
+
+    #[rstest]
+    #[case("P-123456789", true)]
+    #[case("P-20230101-000-001-1", true)]
+    #[case("ETHUSDT-PERP.BINANCE-EMACross-001", false)]
+    #[case("123456789", false)]
+    #[case("p-123", false)]
+    fn test_is_virtual(#[case] value: &str, #[case] expected: bool) {
+        let id = PositionId::new(value);
+        assert_eq!(id.is_virtual(), expected);
+        assert_eq!(position_id_is_virtual(&id), u8::from(expected));
+    }
 }
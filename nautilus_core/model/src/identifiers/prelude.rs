@@ -0,0 +1,74 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub use std::str::FromStr;
+
+pub use super::account_id::AccountId;
+pub use super::client_id::ClientId;
+pub use super::client_order_id::ClientOrderId;
+pub use super::component_id::ComponentId;
+pub use super::exec_algorithm_id::ExecAlgorithmId;
+pub use super::instrument_id::InstrumentId;
+pub use super::order_key::OrderKey;
+pub use super::order_list_id::OrderListId;
+pub use super::position_id::PositionId;
+pub use super::strategy_id::StrategyId;
+pub use super::symbol::Symbol;
+pub use super::trade_id::TradeId;
+pub use super::trader_id::TraderId;
+pub use super::venue::Venue;
+pub use super::venue_order_id::VenueOrderId;
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::identifiers::prelude::*;
+
+    #[test]
+    fn test_prelude_constructs_each_identifier() {
+        let account_id = AccountId::new("SIM-001");
+        let client_id = ClientId::from_str("BINANCE").unwrap();
+        let client_order_id = ClientOrderId::new("O-20200814-102234-001-001-1");
+        let component_id = ComponentId::from_str("RiskEngine").unwrap();
+        let exec_algorithm_id = ExecAlgorithmId::new("TWAP");
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let order_list_id = OrderListId::new("OL-001");
+        let position_id = PositionId::from_str("P-001").unwrap();
+        let strategy_id = StrategyId::new("EMACross-001");
+        let symbol = Symbol::from_str("ETHUSDT-PERP").unwrap();
+        let trade_id = TradeId::new("T-001");
+        let trader_id = TraderId::from_str("TRADER-001").unwrap();
+        let venue = Venue::new("BINANCE");
+        let venue_order_id = VenueOrderId::from_str("V-001").unwrap();
+        let order_key = OrderKey::new(client_order_id.clone(), venue_order_id.clone());
+
+        assert_eq!(account_id.to_string(), "SIM-001");
+        assert_eq!(client_id.to_string(), "BINANCE");
+        assert_eq!(client_order_id.to_string(), "O-20200814-102234-001-001-1");
+        assert_eq!(component_id.to_string(), "RiskEngine");
+        assert_eq!(exec_algorithm_id.to_string(), "TWAP");
+        assert_eq!(instrument_id.symbol, symbol);
+        assert_eq!(instrument_id.venue, venue);
+        assert_eq!(order_list_id.to_string(), "OL-001");
+        assert_eq!(position_id.to_string(), "P-001");
+        assert_eq!(strategy_id.to_string(), "EMACross-001");
+        assert_eq!(trade_id.to_string(), "T-001");
+        assert_eq!(trader_id.to_string(), "TRADER-001");
+        assert_eq!(venue_order_id.to_string(), "V-001");
+        assert_eq!(order_key.to_string(), "O-20200814-102234-001-001-1-V-001");
+    }
+}
@@ -16,6 +16,7 @@
 use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -34,17 +35,70 @@ impl Display for StrategyId {
     }
 }
 
+impl PartialEq<str> for StrategyId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for StrategyId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for StrategyId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        StrategyId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for StrategyId {
+    /// Creates a [`StrategyId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`StrategyId::new`].
+    fn from(value: String) -> Self {
+        StrategyId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl StrategyId {
+    /// Creates a [`StrategyId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`StrategyId` value")?;
+        if value != "EXTERNAL" {
+            correctness::check_string_contains(&value, "-", "`StrategyId` value")?;
+        }
+
+        Ok(StrategyId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`StrategyId`] from `s`, which must contain '-' between
+    /// the strategy name and its tag, unless it is `EXTERNAL`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`StrategyId` value");
-        if s != "EXTERNAL" {
-            correctness::string_contains(s, "-", "`StrategyId` value");
-        }
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
 
-        StrategyId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -60,6 +114,11 @@ pub unsafe extern "C" fn strategy_id_new(ptr: *const c_char) -> StrategyId {
     StrategyId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `strategy_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `strategy_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn strategy_id_clone(strategy_id: &StrategyId) -> StrategyId {
     strategy_id.clone()
@@ -77,6 +136,12 @@ pub extern "C" fn strategy_id_to_cstr(strategy_id: &StrategyId) -> *const c_char
     string_to_cstr(&strategy_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`StrategyId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn strategy_id_len(strategy_id: &StrategyId) -> usize {
+    strategy_id.value.len()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -105,4 +170,15 @@ mod tests {
         let id = StrategyId::new("EMACross-001");
         strategy_id_free(id); // No panic
     }
+
+    #[test]
+    fn test_starts_with_and_ends_with() {
+        let id = StrategyId::new("EMACross-001");
+        assert!(id.starts_with("EMACross"));
+        assert!(id.starts_with(""));
+        assert!(!id.starts_with("MACross"));
+        assert!(!id.starts_with("EMACross-0011"));
+        assert!(id.ends_with("-001"));
+        assert!(!id.ends_with("-002"));
+    }
 }
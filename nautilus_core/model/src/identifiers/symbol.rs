@@ -18,6 +18,7 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,14 +37,66 @@ impl Display for Symbol {
     }
 }
 
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Symbol::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for Symbol {
+    /// Creates a [`Symbol`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`Symbol::new`].
+    fn from(value: String) -> Self {
+        Symbol::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl Symbol {
+    /// Creates a [`Symbol`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`Symbol` value")?;
+
+        Ok(Symbol {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`Symbol`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`Symbol` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
 
-        Symbol {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -59,6 +112,11 @@ pub unsafe extern "C" fn symbol_new(ptr: *const c_char) -> Symbol {
     Symbol::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `symbol`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `symbol_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn symbol_clone(symbol: &Symbol) -> Symbol {
     symbol.clone()
@@ -76,6 +134,12 @@ pub extern "C" fn symbol_to_cstr(symbol: &Symbol) -> *const c_char {
     string_to_cstr(&symbol.value)
 }
 
+/// Returns the UTF-8 byte length of a [`Symbol`], without copying the string.
+#[no_mangle]
+pub extern "C" fn symbol_len(symbol: &Symbol) -> usize {
+    symbol.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn symbol_eq(lhs: &Symbol, rhs: &Symbol) -> u8 {
     u8::from(lhs == rhs)
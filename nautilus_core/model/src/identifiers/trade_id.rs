@@ -18,6 +18,7 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,14 +37,83 @@ impl Display for TradeId {
     }
 }
 
+impl PartialEq<str> for TradeId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TradeId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for TradeId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        TradeId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for TradeId {
+    /// Creates a [`TradeId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`TradeId::new`].
+    fn from(value: String) -> Self {
+        TradeId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl TradeId {
+    /// Creates a [`TradeId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`TradeId` value")?;
+
+        Ok(TradeId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`TradeId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`TradeId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
 
-        TradeId {
-            value: Box::new(Rc::new(s.to_string())),
+    /// Parses a [`TradeId`] from `s` as [`TradeId::new`], additionally
+    /// checking it is at most `max_len` bytes, for venues whose trade IDs
+    /// must fit a fixed width field.
+    ///
+    /// # Errors
+    /// - If `s` is not a valid value.
+    /// - If `s` is longer than `max_len` bytes.
+    pub fn new_with_max_len(s: &str, max_len: usize) -> std::result::Result<Self, String> {
+        if s.len() > max_len {
+            return Err(format!(
+                "invalid string for `TradeId` value exceeded maximum length {max_len}, was {}",
+                s.len()
+            ));
         }
+        s.parse()
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -59,6 +129,11 @@ pub unsafe extern "C" fn trade_id_new(ptr: *const c_char) -> TradeId {
     TradeId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `trade_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `trade_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn trade_id_clone(trade_id: &TradeId) -> TradeId {
     trade_id.clone()
@@ -70,12 +145,18 @@ pub extern "C" fn trade_id_free(trade_id: TradeId) {
     drop(trade_id); // Memory freed here
 }
 
-/// Returns [TradeId] as a C string pointer.
+/// Returns a [`TradeId`] as a C string pointer.
 #[no_mangle]
 pub extern "C" fn trade_id_to_cstr(trade_id: &TradeId) -> *const c_char {
     string_to_cstr(&trade_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`TradeId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn trade_id_len(trade_id: &TradeId) -> usize {
+    trade_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn trade_id_eq(lhs: &TradeId, rhs: &TradeId) -> u8 {
     u8::from(lhs == rhs)
@@ -93,8 +174,14 @@ pub extern "C" fn trade_id_hash(trade_id: &TradeId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::ffi::CStr;
+
+    use rstest::rstest;
+
     use super::TradeId;
-    use crate::identifiers::trade_id::trade_id_free;
+    use crate::identifiers::trade_id::{
+        trade_id_clone, trade_id_eq, trade_id_free, trade_id_hash, trade_id_len, trade_id_to_cstr,
+    };
 
     #[test]
     fn test_equality() {
@@ -116,4 +203,34 @@ mod tests {
         let id = TradeId::new("123456789");
         trade_id_free(id); // No panic
     }
+
+    #[rstest]
+    #[case("1234567890")]
+    #[case("018a0b1c-2d3e-7f40-8a5b-6c7d8e9f0a1b")]
+    fn test_trade_id_c_round_trip(#[case] value: &str) {
+        let id = TradeId::new(value);
+        let c_string = trade_id_to_cstr(&id);
+        let rust_string = unsafe { CStr::from_ptr(c_string) }.to_str().unwrap();
+        assert_eq!(rust_string, value);
+        assert_eq!(trade_id_len(&id), value.len());
+        let id_clone = trade_id_clone(&id);
+        assert_eq!(trade_id_eq(&id, &id_clone), 1);
+        assert_eq!(trade_id_hash(&id), trade_id_hash(&id_clone));
+    }
+
+    #[test]
+    fn test_trade_id_eq_c() {
+        let id1 = TradeId::new("1234567890");
+        let id2 = TradeId::new("1234567891");
+        assert_eq!(trade_id_eq(&id1, &id2), 0);
+        assert_ne!(trade_id_hash(&id1), trade_id_hash(&id2));
+    }
+
+    #[test]
+    fn test_trade_id_new_with_max_len() {
+        let uuid = "018a0b1c-2d3e-7f40-8a5b-6c7d8e9f0a1b";
+        assert_eq!(TradeId::new_with_max_len(uuid, 36).unwrap(), uuid);
+        assert!(TradeId::new_with_max_len(uuid, 35).is_err());
+        assert!(TradeId::new_with_max_len("", 36).is_err());
+    }
 }
@@ -16,6 +16,7 @@
 use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -34,15 +35,68 @@ impl Display for TraderId {
     }
 }
 
+impl PartialEq<str> for TraderId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TraderId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for TraderId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        TraderId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for TraderId {
+    /// Creates a [`TraderId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`TraderId::new`].
+    fn from(value: String) -> Self {
+        TraderId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl TraderId {
+    /// Creates a [`TraderId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`TraderId` value")?;
+        correctness::check_string_contains(&value, "-", "`TraderId` value")?;
+
+        Ok(TraderId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`TraderId`] from `s`, which must contain '-' between
+    /// the trader name and its tag.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`TraderId` value");
-        correctness::string_contains(s, "-", "`TraderId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
 
-        TraderId {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -58,6 +112,11 @@ pub unsafe extern "C" fn trader_id_new(ptr: *const c_char) -> TraderId {
     TraderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `trader_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `trader_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn trader_id_clone(trader_id: &TraderId) -> TraderId {
     trader_id.clone()
@@ -75,6 +134,12 @@ pub extern "C" fn trader_id_to_cstr(trader_id: &TraderId) -> *const c_char {
     string_to_cstr(&trader_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`TraderId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn trader_id_len(trader_id: &TraderId) -> usize {
+    trader_id.value.len()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -18,6 +18,7 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,14 +37,66 @@ impl Display for Venue {
     }
 }
 
+impl PartialEq<str> for Venue {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Venue {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for Venue {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Venue::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for Venue {
+    /// Creates a [`Venue`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`Venue::new`].
+    fn from(value: String) -> Self {
+        Venue::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl Venue {
+    /// Creates a [`Venue`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`Venue` value")?;
+
+        Ok(Venue {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`Venue`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`Venue` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
 
-        Venue {
-            value: Box::new(Rc::new(s.to_string())),
-        }
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
     }
 }
 
@@ -59,6 +112,11 @@ pub unsafe extern "C" fn venue_new(ptr: *const c_char) -> Venue {
     Venue::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `venue`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `venue_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn venue_clone(venue: &Venue) -> Venue {
     venue.clone()
@@ -76,6 +134,12 @@ pub extern "C" fn venue_to_cstr(venue: &Venue) -> *const c_char {
     string_to_cstr(&venue.value)
 }
 
+/// Returns the UTF-8 byte length of a [`Venue`], without copying the string.
+#[no_mangle]
+pub extern "C" fn venue_len(venue: &Venue) -> usize {
+    venue.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn venue_eq(lhs: &Venue, rhs: &Venue) -> u8 {
     u8::from(lhs == rhs)
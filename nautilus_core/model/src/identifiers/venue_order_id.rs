@@ -18,6 +18,8 @@ use std::ffi::{c_char, CStr};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use nautilus_core::correctness;
 use nautilus_core::string::string_to_cstr;
@@ -36,15 +38,113 @@ impl Display for VenueOrderId {
     }
 }
 
+impl PartialEq<str> for VenueOrderId {
+    fn eq(&self, other: &str) -> bool {
+        self.value.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for VenueOrderId {
+    fn eq(&self, other: &&str) -> bool {
+        self.value.as_str() == *other
+    }
+}
+
+impl FromStr for VenueOrderId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        VenueOrderId::try_from_string(s.to_string())
+    }
+}
+
+impl From<String> for VenueOrderId {
+    /// Creates a [`VenueOrderId`] taking ownership of `value`, reusing its allocation
+    /// rather than copying it as parsing a `&str` does.
+    ///
+    /// # Panics
+    /// - If `value` is not a valid value, see [`VenueOrderId::new`].
+    fn from(value: String) -> Self {
+        VenueOrderId::try_from_string(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
 impl VenueOrderId {
+    /// Creates a [`VenueOrderId`] from `value` if it is valid, taking ownership of it.
+    fn try_from_string(value: String) -> std::result::Result<Self, String> {
+        correctness::check_valid_string(&value, "`VenueOrderId` value")?;
+
+        Ok(VenueOrderId {
+            value: Box::new(Rc::new(value)),
+        })
+    }
+
+    /// Creates a new [`VenueOrderId`] from `s`.
+    ///
+    /// # Panics
+    /// - If `s` is not a valid value.
     #[must_use]
     pub fn new(s: &str) -> Self {
-        correctness::valid_string(s, "`VenueOrderId` value");
+        s.parse().unwrap_or_else(|e: String| panic!("{e}"))
+    }
+
+    /// Returns whether the value starts with `prefix`, without allocating.
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.starts_with(prefix)
+    }
+
+    /// Returns whether the value ends with `suffix`, without allocating.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.value.ends_with(suffix)
+    }
+}
+
+/// Generates sequential [`VenueOrderId`]s of the form `{prefix}-{count}`,
+/// with the count zero-padded to `width` digits, such as `SIM-000001`.
+///
+/// The count is atomic, so a generator shared between threads never repeats
+/// an id and each thread sees increasing ids.
+#[derive(Debug)]
+pub struct VenueOrderIdGenerator {
+    prefix: String,
+    width: usize,
+    count: AtomicU64,
+}
 
-        VenueOrderId {
-            value: Box::new(Rc::new(s.to_string())),
+impl VenueOrderIdGenerator {
+    /// Creates a new [`VenueOrderIdGenerator`] whose first id has a count of 1.
+    ///
+    /// # Panics
+    /// - If `prefix` is not a valid [`VenueOrderId`] value.
+    #[must_use]
+    pub fn new(prefix: &str, width: usize) -> Self {
+        correctness::check_valid_string(prefix, "`VenueOrderIdGenerator` prefix")
+            .unwrap_or_else(|e| panic!("{e}"));
+        VenueOrderIdGenerator {
+            prefix: prefix.to_string(),
+            width,
+            count: AtomicU64::new(0),
         }
     }
+
+    /// Returns the number of ids generated.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Returns the next id, counts wider than `width` are not truncated.
+    #[must_use]
+    pub fn generate(&self) -> VenueOrderId {
+        let count = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+        VenueOrderId::new(&format!(
+            "{}-{count:0width$}",
+            self.prefix,
+            width = self.width
+        ))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -59,6 +159,11 @@ pub unsafe extern "C" fn venue_order_id_new(ptr: *const c_char) -> VenueOrderId
     VenueOrderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Returns an owned clone of the given `venue_order_id`, sharing its immutable string
+/// data.
+///
+/// The caller owns the result and must free it with `venue_order_id_free`,
+/// freeing either the clone or the original does not affect the other.
 #[no_mangle]
 pub extern "C" fn venue_order_id_clone(venue_order_id: &VenueOrderId) -> VenueOrderId {
     venue_order_id.clone()
@@ -75,6 +180,12 @@ pub extern "C" fn venue_order_id_to_cstr(venue_order_id: &VenueOrderId) -> *cons
     string_to_cstr(&venue_order_id.value)
 }
 
+/// Returns the UTF-8 byte length of a [`VenueOrderId`], without copying the string.
+#[no_mangle]
+pub extern "C" fn venue_order_id_len(venue_order_id: &VenueOrderId) -> usize {
+    venue_order_id.value.len()
+}
+
 #[no_mangle]
 pub extern "C" fn venue_order_id_eq(lhs: &VenueOrderId, rhs: &VenueOrderId) -> u8 {
     u8::from(lhs == rhs)
@@ -92,7 +203,10 @@ pub extern "C" fn venue_order_id_hash(venue_order_id: &VenueOrderId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::VenueOrderId;
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::{VenueOrderId, VenueOrderIdGenerator};
     use crate::identifiers::venue_order_id::venue_order_id_free;
 
     #[test]
@@ -116,4 +230,59 @@ mod tests {
         let id = VenueOrderId::new("001");
         venue_order_id_free(id); // No panic
     }
+
+    #[test]
+    fn test_venue_order_id_generator_sequential() {
+        let generator = VenueOrderIdGenerator::new("SIM", 6);
+        assert_eq!(generator.generate(), VenueOrderId::new("SIM-000001"));
+        assert_eq!(generator.generate(), VenueOrderId::new("SIM-000002"));
+        assert_eq!(generator.count(), 2);
+    }
+
+    #[test]
+    fn test_venue_order_id_generator_padding_width() {
+        let generator = VenueOrderIdGenerator::new("SIM", 3);
+        for _ in 0..999 {
+            let _ = generator.generate();
+        }
+        assert_eq!(generator.generate().to_string(), "SIM-1000");
+        assert_eq!(
+            VenueOrderIdGenerator::new("SIM", 0).generate().to_string(),
+            "SIM-1"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_venue_order_id_generator_with_invalid_prefix_panics() {
+        let _ = VenueOrderIdGenerator::new("", 6);
+    }
+
+    #[test]
+    fn test_venue_order_id_generator_concurrent_monotonic() {
+        let generator = Arc::new(VenueOrderIdGenerator::new("SIM", 6));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                thread::spawn(move || {
+                    (0..1000)
+                        .map(|_| {
+                            let id = generator.generate().to_string();
+                            id["SIM-".len()..].parse::<u64>().unwrap()
+                        })
+                        .collect::<Vec<u64>>()
+                })
+            })
+            .collect();
+
+        let mut counts = Vec::new();
+        for handle in handles {
+            let thread_counts = handle.join().unwrap();
+            assert!(thread_counts.windows(2).all(|pair| pair[0] < pair[1]));
+            counts.extend(thread_counts);
+        }
+        counts.sort_unstable();
+        assert_eq!(counts, (1..=4000).collect::<Vec<u64>>());
+        assert_eq!(generator.count(), 4000);
+    }
 }
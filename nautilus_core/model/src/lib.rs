@@ -17,8 +17,11 @@
 #[macro_use]
 extern crate lazy_static;
 
+#[cfg(feature = "python")]
 use crate::enums::PriceType;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "python")]
 use pyo3::{PyResult, Python};
 
 pub mod currencies;
@@ -31,6 +34,7 @@ pub mod orders;
 pub mod types;
 
 /// Loaded as nautilus_model
+#[cfg(feature = "python")]
 #[pymodule]
 pub fn model(_: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PriceType>()?;
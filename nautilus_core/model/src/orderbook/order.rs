@@ -19,7 +19,7 @@ use crate::types::price::Price;
 use crate::types::quantity::Quantity;
 
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BookOrder {
     pub price: Price,
     pub size: Quantity,
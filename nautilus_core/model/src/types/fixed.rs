@@ -32,6 +32,60 @@ pub fn f64_to_fixed_u64(value: f64, precision: u8) -> u64 {
     rounded * pow2
 }
 
+/// Scales `value` by `10^precision` and rounds the result to the nearest
+/// integer, with ties to the even neighbour.
+#[must_use]
+pub fn scale_round_half_even(value: f64, precision: u8) -> f64 {
+    let scaled = value * 10_f64.powi(i32::from(precision));
+    if (scaled - scaled.trunc()).abs() == 0.5 {
+        2.0 * (scaled / 2.0).round()
+    } else {
+        scaled.round()
+    }
+}
+
+/// The direction to round a fixed-point value which lies between two
+/// representable values at a target precision.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, with ties away from zero.
+    HalfUp,
+    /// Round to nearest, with ties to the even neighbour.
+    HalfEven,
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+/// Rounds a fixed-point `raw` value to a multiple of the given `precision`.
+///
+/// Rounding away from zero beyond the range of `i64` saturates to the
+/// multiple of the precision towards zero instead.
+///
+/// # Panics
+/// - If `precision` exceeds `FIXED_PRECISION`.
+#[must_use]
+pub fn round_fixed_i64(raw: i64, precision: u8, mode: RoundingMode) -> i64 {
+    assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+    let unit = 10_u64.pow((FIXED_PRECISION - precision) as u32);
+    let abs = raw.unsigned_abs();
+    let (quotient, remainder) = (abs / unit, abs % unit);
+    let round_up = match mode {
+        RoundingMode::HalfUp => remainder * 2 >= unit,
+        RoundingMode::HalfEven => {
+            remainder * 2 > unit || (remainder * 2 == unit && quotient % 2 == 1)
+        }
+        RoundingMode::Down => false,
+        RoundingMode::Up => remainder > 0,
+    };
+    let truncated = i128::from(quotient * unit);
+    let signed = |magnitude: i128| if raw < 0 { -magnitude } else { magnitude };
+    let rounded = signed(truncated + i128::from(round_up) * i128::from(unit));
+    // the truncated value is no further from zero than `raw`, so always fits
+    i64::try_from(rounded).unwrap_or(signed(truncated) as i64)
+}
+
 pub fn fixed_i64_to_f64(value: i64) -> f64 {
     (value as f64) * 0.000000001
 }
@@ -142,6 +196,30 @@ mod tests {
         assert_eq!(f64_to_fixed_u64(value, precision), expected);
     }
 
+    #[rstest(raw, precision, mode, expected,
+        case(i64::MAX, 0, RoundingMode::Up, i64::MAX / 1_000_000_000 * 1_000_000_000),
+        case(i64::MAX, 0, RoundingMode::HalfUp, i64::MAX / 1_000_000_000 * 1_000_000_000),
+        case(i64::MAX, 9, RoundingMode::Up, i64::MAX),
+        case(i64::MIN, 9, RoundingMode::HalfEven, i64::MIN),
+        case(i64::MIN, 0, RoundingMode::Up, i64::MIN / 1_000_000_000 * 1_000_000_000),
+        case(i64::MIN, 0, RoundingMode::Down, i64::MIN / 1_000_000_000 * 1_000_000_000),
+        case(-1_500_000_000, 0, RoundingMode::HalfUp, -2_000_000_000),
+    )]
+    fn test_round_fixed_i64_at_range_limits(
+        raw: i64,
+        precision: u8,
+        mode: RoundingMode,
+        expected: i64,
+    ) {
+        assert_eq!(round_fixed_i64(raw, precision, mode), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "precision exceeded maximum 9")]
+    fn test_round_fixed_i64_with_precision_above_maximum_panics() {
+        let _ = round_fixed_i64(1, 10, RoundingMode::HalfUp);
+    }
+
     #[rstest]
     fn test_fixed_i64_to_f64(
         #[values(1, -1, 2, -2, 10, -10, 100, -100, 1_000, -1_000)] value: i64,
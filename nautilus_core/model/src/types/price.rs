@@ -17,15 +17,33 @@ use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Deref, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 use nautilus_core::correctness;
-use nautilus_core::parsing::precision_from_str;
+use nautilus_core::parsing::try_precision_from_str;
 
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use crate::types::fixed::{
+    f64_to_fixed_i64, fixed_i64_to_f64, round_fixed_i64, scale_round_half_even, RoundingMode,
+    FIXED_PRECISION,
+};
+use crate::types::quantity::Quantity;
 
 pub const PRICE_MAX: f64 = 9_223_372_036.0;
 pub const PRICE_MIN: f64 = -9_223_372_036.0;
 
+/// What [`Price::from_f64_with_policy`] does with a value outside the price
+/// range, which would overflow the `i64` raw value.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum OnOverflow {
+    /// Returns an error, also for NaN.
+    #[default]
+    Error,
+    /// Saturates at `PRICE_MIN` or `PRICE_MAX`, as [`Price::from_f64`].
+    Saturate,
+    /// Clamps to the given maximum magnitude, keeping the sign of the value.
+    Clamp(f64),
+}
+
 #[repr(C)]
 #[derive(Eq, Clone, Default)]
 pub struct Price {
@@ -34,6 +52,12 @@ pub struct Price {
 }
 
 impl Price {
+    /// A zero price with a precision of 0.
+    pub const ZERO: Price = Price {
+        raw: 0,
+        precision: 0,
+    };
+
     #[must_use]
     pub fn new(value: f64, precision: u8) -> Self {
         correctness::f64_in_range_inclusive(value, PRICE_MIN, PRICE_MAX, "`Price` value");
@@ -48,23 +72,266 @@ impl Price {
         Price { raw, precision }
     }
 
+    /// Creates a new [`Price`] from a fixed-point `raw` value, checking the
+    /// `precision` is supported.
+    ///
+    /// # Errors
+    /// - If `precision` exceeds `FIXED_PRECISION`.
+    pub fn try_from_raw(raw: i64, precision: u8) -> std::result::Result<Self, String> {
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "`precision` of {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        Ok(Price { raw, precision })
+    }
+
+    /// Creates a new [`Price`] from a float `value`, rounding half-even to the
+    /// given `precision`.
+    ///
+    /// Unlike [`Price::new`] this never panics on the value, values outside
+    /// the price range saturate at `PRICE_MIN` or `PRICE_MAX` and NaN becomes
+    /// zero.
+    ///
+    /// Note rounding applies to the binary value of the float which may not
+    /// be the decimal value written, for example `2.675` is stored as
+    /// `2.67499999...` so rounds down to `2.67` at a precision of 2.
+    ///
+    /// # Panics
+    /// - If `precision` exceeds `FIXED_PRECISION`.
+    #[must_use]
+    pub fn from_f64(value: f64, precision: u8) -> Self {
+        assert!(precision <= FIXED_PRECISION, "precision exceeded maximum 9");
+        let value = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(PRICE_MIN, PRICE_MAX)
+        };
+        let rounded = scale_round_half_even(value, precision) as i64;
+        Price {
+            raw: rounded * 10_i64.pow(u32::from(FIXED_PRECISION - precision)),
+            precision,
+        }
+    }
+
+    /// Creates a new [`Price`] from a float `value` as [`Price::from_f64`],
+    /// handling values outside the price range by the `on_overflow` policy.
+    ///
+    /// NaN is an error under [`OnOverflow::Error`] and zero otherwise.
+    ///
+    /// # Errors
+    /// - If `precision` exceeds `FIXED_PRECISION`.
+    /// - If `value` is NaN or outside the price range under [`OnOverflow::Error`].
+    /// - If the maximum of [`OnOverflow::Clamp`] is negative or outside the price range.
+    pub fn from_f64_with_policy(
+        value: f64,
+        precision: u8,
+        on_overflow: OnOverflow,
+    ) -> std::result::Result<Self, String> {
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "`precision` of {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        if (PRICE_MIN..=PRICE_MAX).contains(&value) {
+            return Ok(Price::from_f64(value, precision));
+        }
+        match on_overflow {
+            OnOverflow::Error => Err(format!(
+                "`Price` value {value} was not in range [{PRICE_MIN}, {PRICE_MAX}]"
+            )),
+            OnOverflow::Saturate => Ok(Price::from_f64(value, precision)),
+            OnOverflow::Clamp(max) if (0.0..=PRICE_MAX).contains(&max) => {
+                let value = if value.is_nan() {
+                    0.0
+                } else {
+                    max.copysign(value)
+                };
+                Ok(Price::from_f64(value, precision))
+            }
+            OnOverflow::Clamp(max) => Err(format!(
+                "`OnOverflow::Clamp` maximum {max} was not in range [0, {PRICE_MAX}]"
+            )),
+        }
+    }
+
     pub fn is_zero(&self) -> bool {
         self.raw == 0
     }
 
+    pub fn is_positive(&self) -> bool {
+        self.raw > 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.raw < 0
+    }
+
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    /// Returns the notional value of `quantity` at this price as an `f64`.
+    #[must_use]
+    pub fn notional(&self, quantity: &Quantity) -> f64 {
+        self.as_f64() * quantity.as_f64()
+    }
+
+    /// Returns the notional value of `quantity` at this price computed in the
+    /// integer domain, or `None` if the result overflows.
+    ///
+    /// The precision of the result is the sum of the price and quantity
+    /// precisions capped at `FIXED_PRECISION`, the value is exact unless the
+    /// cap applies in which case it is rounded half away from zero.
+    #[must_use]
+    pub fn checked_notional(&self, quantity: &Quantity) -> Option<Price> {
+        let scalar = 10_i128.pow(FIXED_PRECISION as u32);
+        let product = i128::from(self.raw) * i128::from(quantity.raw);
+        let half = if product < 0 { -scalar / 2 } else { scalar / 2 };
+        let raw = i64::try_from((product + half) / scalar).ok()?;
+        let precision = (self.precision + quantity.precision).min(FIXED_PRECISION);
+        Some(Price {
+            raw: round_fixed_i64(raw, precision, RoundingMode::HalfUp),
+            precision,
+        })
+    }
+
+    /// Returns the sum of the prices, or `None` if their precisions differ
+    /// or the result overflows.
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Price) -> Option<Price> {
+        (self.precision == rhs.precision)
+            .then(|| self.raw.checked_add(rhs.raw))
+            .flatten()
+            .map(|raw| Price {
+                raw,
+                precision: self.precision,
+            })
+    }
+
+    /// Returns the difference of the prices, or `None` if their precisions
+    /// differ or the result overflows.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: &Price) -> Option<Price> {
+        (self.precision == rhs.precision)
+            .then(|| self.raw.checked_sub(rhs.raw))
+            .flatten()
+            .map(|raw| Price {
+                raw,
+                precision: self.precision,
+            })
+    }
+
+    /// Returns the number of `tick_size` ticks from this price to `other`,
+    /// positive if `other` is higher, computed on the raw values.
+    ///
+    /// # Errors
+    /// - If the precisions of the prices and `tick_size` differ.
+    /// - If `tick_size` is not positive.
+    /// - If the distance is not a whole number of ticks.
+    pub fn ticks_between(
+        &self,
+        other: &Price,
+        tick_size: &Price,
+    ) -> std::result::Result<i64, String> {
+        if self.precision != other.precision || self.precision != tick_size.precision {
+            return Err(format!(
+                "precisions of {self:?} ({}), {other:?} ({}) and tick size {tick_size:?} ({}) differ",
+                self.precision, other.precision, tick_size.precision
+            ));
+        }
+        if !tick_size.is_positive() {
+            return Err(format!("tick size {tick_size:?} was not positive"));
+        }
+        let distance = i128::from(other.raw) - i128::from(self.raw);
+        let tick = i128::from(tick_size.raw);
+        if distance % tick != 0 {
+            return Err(format!(
+                "distance from {self:?} to {other:?} is not a multiple of tick size {tick_size:?}"
+            ));
+        }
+        i64::try_from(distance / tick).map_err(|e| e.to_string())
+    }
+
+    /// Panics unless `rhs` has the same precision, as the operators combine
+    /// raw values which are only comparable at equal precisions.
+    fn assert_same_precision(&self, rhs: &Price, op: &str) {
+        assert!(
+            self.precision == rhs.precision,
+            "cannot {op} `Price` of precision {} and {}, use `round_to` to match precisions",
+            self.precision,
+            rhs.precision
+        );
+    }
+
+    /// Returns this price rounded to the given `precision` using `mode`.
+    ///
+    /// Increasing the precision never changes the value.
+    #[must_use]
+    pub fn round_to(&self, precision: u8, mode: RoundingMode) -> Price {
+        Price {
+            raw: round_fixed_i64(self.raw, precision, mode),
+            precision,
+        }
+    }
+
+    /// Returns the binary encoding of this price, which is the same on every
+    /// platform: the 8 byte little-endian `raw` value followed by the
+    /// `precision` byte.
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 9] {
+        let mut bytes = [0; 9];
+        bytes[..8].copy_from_slice(&self.raw.to_le_bytes());
+        bytes[8] = self.precision;
+        bytes
+    }
+
+    /// Decodes a [`Price`] from the encoding of [`Price::to_le_bytes`].
+    ///
+    /// # Errors
+    /// - If the precision byte exceeds `FIXED_PRECISION`.
+    pub fn from_le_bytes(bytes: [u8; 9]) -> std::result::Result<Self, String> {
+        let mut raw = [0; 8];
+        raw.copy_from_slice(&bytes[..8]);
+        Price::try_from_raw(i64::from_le_bytes(raw), bytes[8])
+    }
+}
+
+impl FromStr for Price {
+    type Err = String;
+
+    /// Parses a decimal string into a [`Price`], inferring the precision.
+    ///
+    /// Non-finite tokens such as `"nan"`, `"inf"` and `"-inf"`, values outside
+    /// the price range and precisions above `FIXED_PRECISION` are rejected
+    /// before any scaling is applied.
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let float_from_input = input
+            .parse::<f64>()
+            .map_err(|err| format!("Cannot parse `input` string '{input}' as f64, {err}"))?;
+        if !float_from_input.is_finite() {
+            return Err(format!(
+                "Cannot parse `input` string '{input}' as `Price`, value was not finite"
+            ));
+        }
+        if !(PRICE_MIN..=PRICE_MAX).contains(&float_from_input) {
+            return Err(format!(
+                "Cannot parse `input` string '{input}' as `Price`, value was not in range [{PRICE_MIN}, {PRICE_MAX}]"
+            ));
+        }
+        let precision = try_precision_from_str(input)?;
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "Cannot parse `input` string '{input}' as `Price`, precision of {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        Ok(Price::new(float_from_input, precision))
+    }
 }
 
 impl From<&str> for Price {
     fn from(input: &str) -> Self {
-        let float_from_input = input.parse::<f64>();
-        let float_res = match float_from_input {
-            Ok(number) => number,
-            Err(err) => panic!("Cannot parse `input` string '{input}' as f64, {err}"),
-        };
-        Price::new(float_res, precision_from_str(input))
+        input.parse().unwrap_or_else(|err: String| panic!("{err}"))
     }
 }
 
@@ -138,9 +405,12 @@ impl Neg for Price {
     }
 }
 
+/// # Panics
+/// - If the precisions differ, see [`Price::checked_add`].
 impl Add for Price {
     type Output = Self;
     fn add(self, rhs: Price) -> Self::Output {
+        self.assert_same_precision(&rhs, "add");
         Price {
             raw: self.raw + rhs.raw,
             precision: self.precision,
@@ -148,9 +418,12 @@ impl Add for Price {
     }
 }
 
+/// # Panics
+/// - If the precisions differ, see [`Price::checked_sub`].
 impl Sub for Price {
     type Output = Self;
     fn sub(self, rhs: Price) -> Self::Output {
+        self.assert_same_precision(&rhs, "subtract");
         Price {
             raw: self.raw - rhs.raw,
             precision: self.precision,
@@ -158,6 +431,10 @@ impl Sub for Price {
     }
 }
 
+/// Multiplies the raw values keeping the precision of `self`, so unlike [`Add`]
+/// and [`Sub`] the precision of `rhs` is not checked. The result is not scaled
+/// back to fixed-point, use [`Price::checked_notional`] for the value of a
+/// quantity at a price.
 impl Mul for Price {
     type Output = Self;
     fn mul(self, rhs: Price) -> Self {
@@ -170,16 +447,20 @@ impl Mul for Price {
 
 impl AddAssign for Price {
     fn add_assign(&mut self, other: Self) {
+        self.assert_same_precision(&other, "add");
         self.raw += other.raw;
     }
 }
 
 impl SubAssign for Price {
     fn sub_assign(&mut self, other: Self) {
+        self.assert_same_precision(&other, "subtract");
         self.raw -= other.raw;
     }
 }
 
+/// Multiplies the raw values in place as [`Mul`], ignoring the precision of
+/// `multiplier`.
 impl MulAssign for Price {
     fn mul_assign(&mut self, multiplier: Self) {
         self.raw *= multiplier.raw;
@@ -237,14 +518,32 @@ pub extern "C" fn price_as_f64(price: &Price) -> f64 {
     price.as_f64()
 }
 
+/// Adds `b` to `a` in place, returning 1 on success, or 0 leaving `a`
+/// unchanged if the precisions differ or the sum overflows, see
+/// [`Price::checked_add`].
 #[no_mangle]
-pub extern "C" fn price_add_assign(mut a: Price, b: Price) {
-    a.add_assign(b);
+pub extern "C" fn price_add_assign(a: &mut Price, b: Price) -> u8 {
+    match a.checked_add(&b) {
+        Some(sum) => {
+            *a = sum;
+            1
+        }
+        None => 0,
+    }
 }
 
+/// Subtracts `b` from `a` in place, returning 1 on success, or 0 leaving `a`
+/// unchanged if the precisions differ or the difference overflows, see
+/// [`Price::checked_sub`].
 #[no_mangle]
-pub extern "C" fn price_sub_assign(mut a: Price, b: Price) {
-    a.sub_assign(b);
+pub extern "C" fn price_sub_assign(a: &mut Price, b: Price) -> u8 {
+    match a.checked_sub(&b) {
+        Some(difference) => {
+            *a = difference;
+            1
+        }
+        None => 0,
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -252,7 +551,41 @@ pub extern "C" fn price_sub_assign(mut a: Price, b: Price) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::Price;
+    use rstest::rstest;
+
+    use super::{price_add_assign, price_sub_assign, OnOverflow, Price, PRICE_MAX, PRICE_MIN};
+    use crate::types::fixed::RoundingMode;
+    use crate::types::quantity::Quantity;
+
+    #[rstest]
+    #[case(Price::ZERO)]
+    #[case(Price::new(1.00001, 5))]
+    #[case(Price::new(-123.456, 3))]
+    #[case(Price::from_raw(i64::MIN, 9))]
+    #[case(Price::from_raw(i64::MAX, 0))]
+    fn test_price_le_bytes_round_trip(#[case] price: Price) {
+        let decoded = Price::from_le_bytes(price.to_le_bytes()).unwrap();
+        assert_eq!(decoded.raw, price.raw);
+        assert_eq!(decoded.precision, price.precision);
+    }
+
+    #[test]
+    fn test_price_to_le_bytes_layout() {
+        // the expected bytes are spelled out so the encoding is checked to be
+        // little-endian whatever the byte order of the platform
+        let price = Price::from_raw(0x0102_0304_0506_0708, 5);
+        assert_eq!(price.to_le_bytes(), [8, 7, 6, 5, 4, 3, 2, 1, 5]);
+        let price = Price::from_raw(-2, 3);
+        assert_eq!(
+            price.to_le_bytes(),
+            [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 3]
+        );
+    }
+
+    #[test]
+    fn test_price_from_le_bytes_with_invalid_precision_errors() {
+        assert!(Price::from_le_bytes([0, 0, 0, 0, 0, 0, 0, 0, 10]).is_err());
+    }
 
     #[test]
     fn test_price_new() {
@@ -264,6 +597,116 @@ mod tests {
         assert_eq!(price.to_string(), "0.00812000");
     }
 
+    #[test]
+    fn test_price_zero() {
+        assert!(Price::ZERO.is_zero());
+        assert_eq!(Price::ZERO, Price::new(0.0, 0));
+    }
+
+    #[rstest]
+    #[case(0.5, 0, 0.0)]
+    #[case(1.5, 0, 2.0)]
+    #[case(2.5, 0, 2.0)]
+    #[case(-0.5, 0, 0.0)]
+    #[case(-1.5, 0, -2.0)]
+    #[case(-2.5, 0, -2.0)]
+    #[case(0.125, 2, 0.12)]
+    #[case(0.375, 2, 0.38)]
+    #[case(1.00001, 5, 1.00001)]
+    fn test_price_from_f64_rounds_half_even(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] expected: f64,
+    ) {
+        let price = Price::from_f64(value, precision);
+        assert_eq!(price, Price::new(expected, precision));
+        assert_eq!(price.precision, precision);
+    }
+
+    #[rstest]
+    #[case(f64::INFINITY, PRICE_MAX)]
+    #[case(f64::NEG_INFINITY, PRICE_MIN)]
+    #[case(1e20, PRICE_MAX)]
+    #[case(f64::NAN, 0.0)]
+    fn test_price_from_f64_saturates(#[case] value: f64, #[case] expected: f64) {
+        assert_eq!(Price::from_f64(value, 0), Price::new(expected, 0));
+    }
+
+    #[test]
+    fn test_price_from_f64_with_policy_defaults_to_error() {
+        assert_eq!(OnOverflow::default(), OnOverflow::Error);
+        assert!(Price::from_f64_with_policy(1e10, 0, OnOverflow::default()).is_err());
+        assert!(Price::from_f64_with_policy(f64::NAN, 0, OnOverflow::Error).is_err());
+        assert_eq!(
+            Price::from_f64_with_policy(1.5, 0, OnOverflow::Error),
+            Ok(Price::from_f64(1.5, 0))
+        );
+    }
+
+    #[rstest]
+    #[case(1e10, PRICE_MAX)]
+    #[case(-1e10, PRICE_MIN)]
+    #[case(f64::NAN, 0.0)]
+    fn test_price_from_f64_with_policy_saturate(#[case] value: f64, #[case] expected: f64) {
+        assert_eq!(
+            Price::from_f64_with_policy(value, 0, OnOverflow::Saturate),
+            Ok(Price::new(expected, 0))
+        );
+    }
+
+    #[rstest]
+    #[case(1e10, 1_000_000.0)]
+    #[case(-1e10, -1_000_000.0)]
+    #[case(f64::INFINITY, 1_000_000.0)]
+    fn test_price_from_f64_with_policy_clamp(#[case] value: f64, #[case] expected: f64) {
+        assert_eq!(
+            Price::from_f64_with_policy(value, 0, OnOverflow::Clamp(1_000_000.0)),
+            Ok(Price::new(expected, 0))
+        );
+    }
+
+    #[rstest]
+    #[case(OnOverflow::Clamp(-1.0))]
+    #[case(OnOverflow::Clamp(1e10))]
+    #[case(OnOverflow::Clamp(f64::NAN))]
+    fn test_price_from_f64_with_policy_invalid_clamp_errors(#[case] on_overflow: OnOverflow) {
+        assert!(Price::from_f64_with_policy(1e10, 0, on_overflow).is_err());
+    }
+
+    #[rstest]
+    #[case(1.0, true, false, false)]
+    #[case(-1.0, false, true, false)]
+    #[case(0.0, false, false, true)]
+    fn test_price_sign_predicates(
+        #[case] value: f64,
+        #[case] positive: bool,
+        #[case] negative: bool,
+        #[case] zero: bool,
+    ) {
+        let price = Price::new(value, 1);
+        assert_eq!(price.is_positive(), positive);
+        assert_eq!(price.is_negative(), negative);
+        assert_eq!(price.is_zero(), zero);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(8)]
+    #[case(9)]
+    fn test_price_try_from_raw(#[case] precision: u8) {
+        let price = Price::try_from_raw(1_000_000_000, precision).unwrap();
+        assert_eq!(price.raw, 1_000_000_000);
+        assert_eq!(price.precision, precision);
+    }
+
+    #[rstest]
+    #[case(10)]
+    #[case(16)]
+    #[case(u8::MAX)]
+    fn test_price_try_from_raw_with_invalid_precision(#[case] precision: u8) {
+        assert!(Price::try_from_raw(1_000_000_000, precision).is_err());
+    }
+
     #[test]
     fn test_price_minimum() {
         let price = Price::new(0.000000001, 9);
@@ -299,6 +742,39 @@ mod tests {
         assert_eq!(price.to_string(), "0.00812000");
     }
 
+    #[test]
+    fn test_price_from_str() {
+        let price: Price = "1.2345".parse().unwrap();
+        assert_eq!(price.raw, 1234500000);
+        assert_eq!(price.precision, 4);
+    }
+
+    #[rstest]
+    #[case("nan")]
+    #[case("NaN")]
+    #[case("inf")]
+    #[case("-inf")]
+    #[case("infinity")]
+    #[case("")]
+    #[case("1e20")]
+    #[case("-1e20")]
+    #[case("0.0000000001")]
+    #[case("1e-20")]
+    #[case("1e-300")]
+    fn test_price_from_str_with_invalid_values(#[case] input: &str) {
+        assert!(input.parse::<Price>().is_err());
+    }
+
+    #[rstest]
+    #[case("nan")]
+    #[case("inf")]
+    #[case("-inf")]
+    #[case("")]
+    #[should_panic]
+    fn test_price_from_with_invalid_values(#[case] input: &str) {
+        let _ = Price::from(input);
+    }
+
     #[test]
     fn test_price_equality() {
         assert_eq!(Price::new(1.0, 1), Price::new(1.0, 1));
@@ -314,6 +790,66 @@ mod tests {
         assert!(Price::new(0.9, 1) <= Price::new(1.0, 1));
     }
 
+    #[rstest]
+    #[case(1.25, RoundingMode::HalfUp, 1.3)]
+    #[case(1.25, RoundingMode::HalfEven, 1.2)]
+    #[case(1.35, RoundingMode::HalfEven, 1.4)]
+    #[case(1.25, RoundingMode::Down, 1.2)]
+    #[case(1.25, RoundingMode::Up, 1.3)]
+    #[case(1.24, RoundingMode::HalfUp, 1.2)]
+    #[case(1.21, RoundingMode::Up, 1.3)]
+    #[case(1.29, RoundingMode::Down, 1.2)]
+    #[case(-1.25, RoundingMode::HalfUp, -1.3)]
+    #[case(-1.25, RoundingMode::HalfEven, -1.2)]
+    #[case(-1.25, RoundingMode::Down, -1.2)]
+    #[case(-1.25, RoundingMode::Up, -1.3)]
+    fn test_price_round_to_decreasing_precision(
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: f64,
+    ) {
+        let price = Price::new(value, 2).round_to(1, mode);
+        assert_eq!(price, Price::new(expected, 1));
+        assert_eq!(price.precision, 1);
+    }
+
+    #[rstest]
+    #[case(RoundingMode::HalfUp)]
+    #[case(RoundingMode::HalfEven)]
+    #[case(RoundingMode::Down)]
+    #[case(RoundingMode::Up)]
+    fn test_price_round_to_increasing_precision(#[case] mode: RoundingMode) {
+        let price = Price::new(1.25, 2).round_to(4, mode);
+        assert_eq!(price.raw, 1_250_000_000);
+        assert_eq!(price.to_string(), "1.2500");
+    }
+
+    #[rstest]
+    #[case(Price::new(1.5, 1), Quantity::new(2.0, 0), 3.0, 1)]
+    #[case(Price::new(100.25, 2), Quantity::new(0.5, 1), 50.125, 3)]
+    #[case(Price::new(1.23456, 5), Quantity::new(1000.0, 0), 1234.56, 5)]
+    #[case(Price::new(-2.5, 1), Quantity::new(4.25, 2), -10.625, 3)]
+    #[case(Price::new(0.00001, 5), Quantity::new(0.00001, 5), 0.0000000001, 9)]
+    fn test_price_notional(
+        #[case] price: Price,
+        #[case] quantity: Quantity,
+        #[case] expected: f64,
+        #[case] expected_precision: u8,
+    ) {
+        let checked = price.checked_notional(&quantity).unwrap();
+
+        assert!((price.notional(&quantity) - expected).abs() < 1e-12);
+        assert_eq!(checked, Price::new(expected, expected_precision));
+        assert_eq!(checked.precision, expected_precision);
+    }
+
+    #[test]
+    fn test_price_checked_notional_overflow() {
+        let price = Price::new(9_000_000_000.0, 0);
+        let quantity = Quantity::new(10.0, 0);
+        assert!(price.checked_notional(&quantity).is_none());
+    }
+
     #[test]
     fn test_add() {
         let price1 = Price::new(1.000, 3);
@@ -336,6 +872,89 @@ mod tests {
         assert_eq!(price.raw, 989000000)
     }
 
+    #[test]
+    fn test_sub() {
+        let price = Price::new(1.011, 3) - Price::new(1.000, 3);
+        assert_eq!(price, Price::new(0.011, 3));
+        assert_eq!(price.precision, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add `Price` of precision 3 and 2")]
+    fn test_add_with_precision_mismatch_panics() {
+        let _ = Price::new(1.000, 3) + Price::new(1.01, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract `Price` of precision 3 and 2")]
+    fn test_sub_assign_with_precision_mismatch_panics() {
+        let mut price = Price::new(1.000, 3);
+        price -= Price::new(0.01, 2);
+    }
+
+    #[test]
+    fn test_price_add_assign_c() {
+        let mut price = Price::new(1.000, 3);
+
+        assert_eq!(price_add_assign(&mut price, Price::new(1.011, 3)), 1);
+        assert_eq!(price, Price::new(2.011, 3));
+        assert_eq!(price_sub_assign(&mut price, Price::new(0.011, 3)), 1);
+        assert_eq!(price, Price::new(2.000, 3));
+    }
+
+    #[test]
+    fn test_price_add_assign_c_with_precision_mismatch_returns_zero() {
+        let mut price = Price::new(1.000, 3);
+
+        assert_eq!(price_add_assign(&mut price, Price::new(1.01, 2)), 0);
+        assert_eq!(price_sub_assign(&mut price, Price::new(0.01, 2)), 0);
+        assert_eq!(price, Price::new(1.000, 3));
+    }
+
+    #[rstest]
+    #[case(
+        Price::new(1.00000, 5),
+        Price::new(1.00025, 5),
+        Price::new(0.00005, 5),
+        5
+    )]
+    #[case(Price::new(1.00025, 5), Price::new(1.00000, 5), Price::new(0.00005, 5), -5)]
+    #[case(Price::new(100.50, 2), Price::new(100.50, 2), Price::new(0.25, 2), 0)]
+    #[case(Price::new(-1.0, 1), Price::new(1.0, 1), Price::new(0.5, 1), 4)]
+    fn test_price_ticks_between(
+        #[case] price: Price,
+        #[case] other: Price,
+        #[case] tick_size: Price,
+        #[case] expected: i64,
+    ) {
+        assert_eq!(price.ticks_between(&other, &tick_size), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(Price::new(1.0, 2), Price::new(1.1, 1), Price::new(0.01, 2))]
+    #[case(Price::new(1.0, 2), Price::new(1.1, 2), Price::new(0.1, 1))]
+    #[case(Price::new(1.0, 2), Price::new(1.1, 2), Price::new(0.0, 2))]
+    #[case(Price::new(1.0, 2), Price::new(1.1, 2), Price::new(-0.01, 2))]
+    #[case(Price::new(1.0, 2), Price::new(1.1, 2), Price::new(0.03, 2))]
+    fn test_price_ticks_between_errors(
+        #[case] price: Price,
+        #[case] other: Price,
+        #[case] tick_size: Price,
+    ) {
+        assert!(price.ticks_between(&other, &tick_size).is_err());
+    }
+
+    #[test]
+    fn test_checked_add_and_sub() {
+        let price1 = Price::new(1.000, 3);
+        let price2 = Price::new(1.011, 3);
+        assert_eq!(price1.checked_add(&price2), Some(Price::new(2.011, 3)));
+        assert_eq!(price2.checked_sub(&price1), Some(Price::new(0.011, 3)));
+        assert_eq!(price1.checked_add(&Price::new(1.01, 2)), None);
+        assert_eq!(price1.checked_sub(&Price::new(1.01, 2)), None);
+        assert_eq!(Price::from_raw(i64::MAX, 3).checked_add(&price1), None);
+    }
+
     #[test]
     fn test_price_display_works() {
         use std::fmt::Write as FmtWrite;
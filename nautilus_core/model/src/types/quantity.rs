@@ -21,7 +21,9 @@ use std::ops::{Add, AddAssign, Deref, Mul, MulAssign, Sub, SubAssign};
 use nautilus_core::correctness;
 use nautilus_core::parsing::precision_from_str;
 
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
+use crate::types::fixed::{
+    f64_to_fixed_u64, fixed_u64_to_f64, scale_round_half_even, FIXED_PRECISION,
+};
 
 pub const QUANTITY_MAX: f64 = 18_446_744_073.0;
 pub const QUANTITY_MIN: f64 = 0.0;
@@ -48,6 +50,117 @@ impl Quantity {
         Quantity { raw, precision }
     }
 
+    /// Creates a new [`Quantity`] from a float `value`, rounding half-even to
+    /// the given `precision`.
+    ///
+    /// As with [`Price::from_f64`](crate::types::price::Price::from_f64)
+    /// rounding applies to the binary value of the float, which may not be
+    /// the decimal value written.
+    ///
+    /// # Errors
+    /// - If `value` is negative, NaN or greater than `QUANTITY_MAX`.
+    /// - If `precision` exceeds `FIXED_PRECISION`.
+    pub fn from_f64(value: f64, precision: u8) -> std::result::Result<Self, String> {
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "`precision` of {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        if !(QUANTITY_MIN..=QUANTITY_MAX).contains(&value) {
+            return Err(format!(
+                "`Quantity` value {value} not in range [{QUANTITY_MIN}, {QUANTITY_MAX}]"
+            ));
+        }
+        let rounded = scale_round_half_even(value, precision) as u64;
+        Ok(Quantity {
+            raw: rounded * 10_u64.pow(u32::from(FIXED_PRECISION - precision)),
+            precision,
+        })
+    }
+
+    /// Returns this quantity with the given `precision`, without losing any
+    /// digits.
+    ///
+    /// The raw value is always scaled to `FIXED_PRECISION` so it is unchanged,
+    /// a downscale is lossless when the digits beyond `new_precision` are
+    /// all zero.
+    ///
+    /// # Errors
+    /// - If `new_precision` exceeds `FIXED_PRECISION`.
+    /// - If downscaling would drop non-zero digits.
+    pub fn rescale(&self, new_precision: u8) -> std::result::Result<Quantity, String> {
+        if new_precision > FIXED_PRECISION {
+            return Err(format!(
+                "`precision` of {new_precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        let unit = 10_u64.pow(u32::from(FIXED_PRECISION - new_precision));
+        let lost_digits = self.raw % unit;
+        if lost_digits > 0 {
+            return Err(format!(
+                "cannot rescale {self} to precision {new_precision} without losing digits"
+            ));
+        }
+        Ok(Quantity {
+            raw: self.raw,
+            precision: new_precision,
+        })
+    }
+
+    /// Returns the binary encoding of this quantity, which is the same on
+    /// every platform: the 8 byte little-endian `raw` value followed by the
+    /// `precision` byte.
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 9] {
+        let mut bytes = [0; 9];
+        bytes[..8].copy_from_slice(&self.raw.to_le_bytes());
+        bytes[8] = self.precision;
+        bytes
+    }
+
+    /// Decodes a [`Quantity`] from the encoding of [`Quantity::to_le_bytes`].
+    ///
+    /// # Errors
+    /// - If the precision byte exceeds `FIXED_PRECISION`.
+    pub fn from_le_bytes(bytes: [u8; 9]) -> std::result::Result<Self, String> {
+        let precision = bytes[8];
+        if precision > FIXED_PRECISION {
+            return Err(format!(
+                "`precision` of {precision} exceeded maximum {FIXED_PRECISION}"
+            ));
+        }
+        let mut raw = [0; 8];
+        raw.copy_from_slice(&bytes[..8]);
+        Ok(Quantity {
+            raw: u64::from_le_bytes(raw),
+            precision,
+        })
+    }
+
+    /// Returns the value as formatted by `Display`, with a `,` between each
+    /// group of three integer digits such as `1,000,000.50`, for human
+    /// facing logs.
+    #[must_use]
+    pub fn to_formatted_string(&self) -> String {
+        let value = self.to_string();
+        let (units, fraction) = match value.split_once('.') {
+            Some((units, fraction)) => (units, Some(fraction)),
+            None => (value.as_str(), None),
+        };
+        let mut formatted = String::with_capacity(value.len() + units.len() / 3);
+        for (i, digit) in units.chars().enumerate() {
+            if i > 0 && (units.len() - i) % 3 == 0 {
+                formatted.push(',');
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push('.');
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
     pub fn is_zero(&self) -> bool {
         self.raw == 0
     }
@@ -243,12 +356,206 @@ pub extern "C" fn quantity_sub_assign_u64(mut a: Quantity, b: u64) {
     a.sub_assign(b);
 }
 
+/// Sums quantities at a fixed precision in the integer domain, such as for
+/// the total volume of a column of sizes.
+///
+/// Once an addition would overflow the total is left at the last sum which
+/// fit and all further additions are rejected.
+#[derive(Clone, Debug)]
+pub struct QuantityAccumulator {
+    raw: u64,
+    precision: u8,
+    overflowed: bool,
+}
+
+impl QuantityAccumulator {
+    /// Creates a new accumulator with a zero total at the given `precision`.
+    ///
+    /// # Panics
+    /// - If `precision` exceeds `FIXED_PRECISION`.
+    #[must_use]
+    pub fn new(precision: u8) -> Self {
+        assert!(
+            precision <= FIXED_PRECISION,
+            "precision exceeded maximum {FIXED_PRECISION}"
+        );
+        QuantityAccumulator {
+            raw: 0,
+            precision,
+            overflowed: false,
+        }
+    }
+
+    /// Adds `quantity` to the total.
+    ///
+    /// # Errors
+    /// - If the precision of `quantity` exceeds that of the accumulator.
+    /// - If the total would overflow, or has previously overflowed.
+    pub fn add(&mut self, quantity: &Quantity) -> std::result::Result<(), String> {
+        if self.overflowed {
+            return Err("accumulator has overflowed".to_string());
+        }
+        if quantity.precision > self.precision {
+            return Err(format!(
+                "`precision` of {} exceeded accumulator precision {}",
+                quantity.precision, self.precision
+            ));
+        }
+        match self.raw.checked_add(quantity.raw) {
+            Some(raw) => {
+                self.raw = raw;
+                Ok(())
+            }
+            None => {
+                self.overflowed = true;
+                Err(format!(
+                    "adding {quantity} overflowed the accumulated total"
+                ))
+            }
+        }
+    }
+
+    /// Returns whether an addition has overflowed.
+    #[must_use]
+    pub fn has_overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    /// Returns the total at the precision of the accumulator.
+    #[must_use]
+    pub fn total(&self) -> Quantity {
+        Quantity::from_raw(self.raw, self.precision)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::Quantity;
+    use rstest::rstest;
+
+    use super::{Quantity, QuantityAccumulator};
+
+    #[test]
+    fn test_qty_accumulator_sum() {
+        let mut acc = QuantityAccumulator::new(2);
+        for qty in ["1.25", "100", "0.5"] {
+            acc.add(&Quantity::from(qty)).unwrap();
+        }
+
+        assert_eq!(acc.total(), Quantity::from("101.75"));
+        assert_eq!(acc.total().precision, 2);
+        assert_eq!(acc.total().to_string(), "101.75");
+        assert!(!acc.has_overflowed());
+    }
+
+    #[test]
+    fn test_qty_accumulator_with_higher_precision_errors() {
+        let mut acc = QuantityAccumulator::new(1);
+        assert!(acc.add(&Quantity::from("0.01")).is_err());
+        assert_eq!(acc.total().raw, 0);
+    }
+
+    #[test]
+    fn test_qty_accumulator_overflow() {
+        let mut acc = QuantityAccumulator::new(0);
+        let qty = Quantity::new(10_000_000_000.0, 0);
+        acc.add(&qty).unwrap();
+
+        assert!(acc.add(&qty).is_err());
+        assert!(acc.has_overflowed());
+        assert_eq!(acc.total(), qty);
+        assert!(acc.add(&Quantity::new(1.0, 0)).is_err());
+    }
+
+    #[rstest]
+    #[case(Quantity::from_raw(0, 0))]
+    #[case(Quantity::new(100.0, 0))]
+    #[case(Quantity::new(0.00812, 5))]
+    #[case(Quantity::from_raw(u64::MAX, 9))]
+    fn test_qty_le_bytes_round_trip(#[case] qty: Quantity) {
+        let decoded = Quantity::from_le_bytes(qty.to_le_bytes()).unwrap();
+        assert_eq!(decoded.raw, qty.raw);
+        assert_eq!(decoded.precision, qty.precision);
+    }
+
+    #[test]
+    fn test_qty_to_le_bytes_layout() {
+        // the expected bytes are spelled out so the encoding is checked to be
+        // little-endian whatever the byte order of the platform
+        let qty = Quantity::from_raw(0x0102_0304_0506_0708, 2);
+        assert_eq!(qty.to_le_bytes(), [8, 7, 6, 5, 4, 3, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_qty_from_le_bytes_with_invalid_precision_errors() {
+        assert!(Quantity::from_le_bytes([1, 0, 0, 0, 0, 0, 0, 0, 255]).is_err());
+    }
+
+    #[rstest]
+    #[case(-1.0, 0)]
+    #[case(-0.000_000_001, 9)]
+    #[case(f64::NAN, 0)]
+    #[case(f64::INFINITY, 0)]
+    #[case(1.0, 10)]
+    fn test_qty_from_f64_with_invalid_input_errors(#[case] value: f64, #[case] precision: u8) {
+        assert!(Quantity::from_f64(value, precision).is_err());
+    }
+
+    #[rstest]
+    #[case(0.5, 0, 0)]
+    #[case(1.5, 0, 2_000_000_000)]
+    #[case(2.5, 0, 2_000_000_000)]
+    #[case(0.125, 2, 120_000_000)]
+    #[case(0.375, 2, 380_000_000)]
+    fn test_qty_from_f64_rounds_half_even(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] expected_raw: u64,
+    ) {
+        let qty = Quantity::from_f64(value, precision).unwrap();
+        assert_eq!(qty.raw, expected_raw);
+    }
+
+    #[rstest]
+    #[case(0.00812, 8)]
+    #[case(100.0, 0)]
+    #[case(1.5, 1)]
+    fn test_qty_from_f64_round_trip(#[case] value: f64, #[case] precision: u8) {
+        let qty = Quantity::from_f64(value, precision).unwrap();
+        assert_eq!(qty.precision, precision);
+        assert_eq!(qty.as_f64(), value);
+        assert_eq!(Quantity::from_f64(qty.as_f64(), precision).unwrap(), qty);
+    }
+
+    #[test]
+    fn test_qty_rescale_up() {
+        let qty = Quantity::new(100.0, 0).rescale(3).unwrap();
+        assert_eq!(qty.precision, 3);
+        assert_eq!(qty.to_string(), "100.000");
+    }
+
+    #[test]
+    fn test_qty_rescale_down() {
+        let qty = Quantity::new(1.5, 3).rescale(1).unwrap();
+        assert_eq!(qty.precision, 1);
+        assert_eq!(qty.to_string(), "1.5");
+    }
+
+    #[rstest]
+    #[case(1.25, 2, 1)]
+    #[case(0.001, 3, 0)]
+    #[case(1.0, 0, 10)]
+    fn test_qty_rescale_with_lost_digits_errors(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] new_precision: u8,
+    ) {
+        assert!(Quantity::new(value, precision)
+            .rescale(new_precision)
+            .is_err());
+    }
 
     #[test]
     fn test_qty_new() {
@@ -318,6 +625,25 @@ mod tests {
         assert!(Quantity::new(0.9, 1) <= Quantity::new(1.0, 1));
     }
 
+    #[rstest]
+    #[case(Quantity::new(1_000_000.0, 0), "1,000,000")]
+    #[case(Quantity::new(123_456_789.0, 0), "123,456,789")]
+    #[case(Quantity::new(1_000.0, 0), "1,000")]
+    #[case(Quantity::new(1_234_567.125, 3), "1,234,567.125")]
+    #[case(Quantity::new(10_000.5, 2), "10,000.50")]
+    #[case(Quantity::new(999.0, 0), "999")]
+    #[case(Quantity::new(12.75, 2), "12.75")]
+    #[case(Quantity::new(0.00812, 8), "0.00812000")]
+    #[case(Quantity::from_raw(0, 0), "0")]
+    fn test_qty_to_formatted_string(#[case] qty: Quantity, #[case] expected: &str) {
+        assert_eq!(qty.to_formatted_string(), expected);
+    }
+
+    #[test]
+    fn test_qty_display_has_no_separators() {
+        assert_eq!(Quantity::new(1_000_000.0, 0).to_string(), "1000000");
+    }
+
     #[test]
     fn test_qty_display() {
         use std::fmt::Write as FmtWrite;
@@ -0,0 +1,31 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+#[test]
+fn test_identifier_macros() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/identifier_macros_valid.rs");
+}
+
+// The `.stderr` snapshots hold the wording of const evaluation errors, which
+// changes between compiler versions, so they are only checked on the compiler
+// that generated them.
+#[rustversion::attr(not(since(1.95)), ignore)]
+#[test]
+fn test_identifier_macros_with_invalid_values() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/instrument_id_macro_without_venue.rs");
+    t.compile_fail("tests/ui/client_id_macro_with_non_ascii.rs");
+}
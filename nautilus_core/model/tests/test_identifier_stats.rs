@@ -0,0 +1,31 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+// The count is global to the process, so this is the only test of this
+// binary to keep other constructions from running concurrently.
+#[test]
+fn test_client_order_id_constructed_count() {
+    use nautilus_model::identifiers::client_order_id::ClientOrderId;
+
+    let before = ClientOrderId::constructed_count();
+    let ids: Vec<ClientOrderId> = (0..10)
+        .map(|i| ClientOrderId::new(&format!("O-{i:03}")))
+        .collect();
+    let clones = ids.clone();
+    assert!("".parse::<ClientOrderId>().is_err());
+
+    assert_eq!(ClientOrderId::constructed_count() - before, 10);
+    assert_eq!(clones, ids);
+}
@@ -0,0 +1,5 @@
+use nautilus_model::client_id;
+
+fn main() {
+    let _id = client_id!("BÖRSE");
+}
@@ -0,0 +1,8 @@
+use nautilus_model::{client_id, instrument_id};
+
+fn main() {
+    let id = instrument_id!("EUR/USD.SIM");
+    assert_eq!(id.to_string(), "EUR/USD.SIM");
+    let id = client_id!("BINANCE");
+    assert_eq!(id.to_string(), "BINANCE");
+}
@@ -0,0 +1,5 @@
+use nautilus_model::instrument_id;
+
+fn main() {
+    let _id = instrument_id!("EUR/USD");
+}
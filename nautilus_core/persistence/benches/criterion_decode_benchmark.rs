@@ -0,0 +1,54 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{black_box, criterion_group, Criterion};
+use nautilus_model::{
+    data::tick::QuoteTick,
+    types::{price::Price, quantity::Quantity},
+};
+use nautilus_persistence::parquet::{
+    multi_instrument_quote_tick_schema, ParquetReader, ParquetWriter,
+};
+
+pub fn criterion_decode_benchmark(c: &mut Criterion) {
+    let instruments = ["EUR/USD.SIM", "GBP/USD.SIM", "USD/JPY.SIM"];
+    let ticks: Vec<QuoteTick> = (0..10_000)
+        .map(|i| QuoteTick {
+            instrument_id: instruments[i % instruments.len()].into(),
+            bid: Price::new(1.0, 5),
+            ask: Price::new(1.00001, 5),
+            bid_size: Quantity::new(100.0, 0),
+            ask_size: Quantity::new(100.0, 0),
+            ts_event: i as u64,
+            ts_init: i as u64,
+        })
+        .collect();
+    let dictionary: Vec<_> = instruments.iter().map(|id| ((*id).into(), 5, 0)).collect();
+    let schema = multi_instrument_quote_tick_schema(&dictionary);
+    let mut writer = ParquetWriter::<QuoteTick, _>::new(Vec::new(), schema);
+    writer.write(&ticks).unwrap();
+    let buffer = writer.flush();
+
+    c.bench_function("decode_multi_instrument_quote_ticks", |b| {
+        b.iter(|| {
+            let reader: ParquetReader<QuoteTick, _> =
+                ParquetReader::from_bytes(black_box(&buffer), 100).unwrap();
+            reader.flat_iter().map(Result::unwrap).count()
+        })
+    });
+}
+
+criterion_group!(benches, criterion_decode_benchmark);
+criterion::criterion_main!(benches);
@@ -0,0 +1,179 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::env;
+use std::fs::File;
+use std::process;
+
+use arrow2::error::Error;
+use arrow2::io::parquet::read;
+use nautilus_model::data::tick::QuoteTick;
+use nautilus_persistence::csv::{write_quote_ticks_csv_streaming, CsvQuoteConfig};
+use nautilus_persistence::parquet::{quote_tick_schema, AtomicParquetWriter, Result};
+
+const USAGE: &str = "usage:
+  parquet_tool inspect <file>
+  parquet_tool convert <input.csv> <output.parquet> --instrument-id <id>
+      --price-precision <n> --size-precision <n> [--timestamp-col <n>]
+      [--bid-col <n>] [--ask-col <n>] [--bid-size-col <n>] [--ask-size-col <n>]
+      [--timestamp-format <fmt>] [--time-resolution <secs|millis|micros|nanos>]
+      [--strict <true|false>] [--has-header]";
+
+/// The flags of `convert` which take a value.
+const CONVERT_VALUE_FLAGS: [&str; 11] = [
+    "--instrument-id",
+    "--price-precision",
+    "--size-precision",
+    "--timestamp-col",
+    "--bid-col",
+    "--ask-col",
+    "--bid-size-col",
+    "--ask-size-col",
+    "--timestamp-format",
+    "--time-resolution",
+    "--strict",
+];
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["inspect", path] => inspect(path),
+        ["convert", input, output, flags @ ..] => match parse_config(flags) {
+            Ok(config) => convert(input, output, &config),
+            Err(e) => {
+                eprintln!("error: {e}\n{USAGE}");
+                process::exit(2);
+            }
+        },
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+/// Prints the schema, metadata, row group count and row count of the parquet
+/// file at `path`, reading only the file footer.
+fn inspect(path: &str) -> Result<()> {
+    let mut file = File::open(path)?;
+    let metadata = read::read_metadata(&mut file)?;
+    let schema = read::infer_schema(&metadata)?;
+
+    println!("schema:");
+    for field in &schema.fields {
+        println!("  {}: {:?}", field.name, field.data_type);
+    }
+    println!("metadata:");
+    for (key, value) in &schema.metadata {
+        println!("  {key}: {value}");
+    }
+    println!("row_groups: {}", metadata.row_groups.len());
+    println!("rows: {}", metadata.num_rows);
+    Ok(())
+}
+
+/// The number of ticks converted at a time, bounding memory use for large
+/// files.
+const CONVERT_BATCH_SIZE: usize = 100_000;
+
+/// Converts the quote ticks of the CSV file at `input` into a parquet file at
+/// `output`, streaming them in batches.
+fn convert(input: &str, output: &str, config: &CsvQuoteConfig) -> Result<()> {
+    let schema = quote_tick_schema(
+        &config.instrument_id,
+        config.price_precision,
+        config.size_precision,
+    );
+    let mut writer: AtomicParquetWriter<QuoteTick> = AtomicParquetWriter::create(output, schema)?;
+    let num_rows = write_quote_ticks_csv_streaming(
+        File::open(input)?,
+        config,
+        writer.writer_mut(),
+        CONVERT_BATCH_SIZE,
+    )?;
+    writer.end()?;
+    println!("wrote {num_rows} rows to {output}");
+    Ok(())
+}
+
+fn parse_config(flags: &[&str]) -> Result<CsvQuoteConfig> {
+    let mut values: Vec<(&str, &str)> = Vec::new();
+    let mut has_header = false;
+    let mut iter = flags.iter();
+    while let Some(flag) = iter.next() {
+        if *flag == "--has-header" {
+            has_header = true;
+            continue;
+        }
+        if !CONVERT_VALUE_FLAGS.contains(flag) {
+            return Err(Error::InvalidArgumentError(format!("unknown flag {flag}")).into());
+        }
+        let value = iter
+            .next()
+            .ok_or_else(|| Error::InvalidArgumentError(format!("missing value for {flag}")))?;
+        values.push((flag, value));
+    }
+    let get = |name: &str| values.iter().find(|(flag, _)| *flag == name).map(|v| v.1);
+    let required = |name: &str| {
+        get(name).ok_or_else(|| Error::InvalidArgumentError(format!("missing {name}")))
+    };
+
+    let mut config = CsvQuoteConfig::new(
+        parse_arg(required("--instrument-id")?)?,
+        parse_arg(required("--price-precision")?)?,
+        parse_arg(required("--size-precision")?)?,
+    );
+    if let Some(value) = get("--timestamp-col") {
+        config.timestamp_col = parse_arg(value)?;
+    }
+    if let Some(value) = get("--bid-col") {
+        config.bid_col = parse_arg(value)?;
+    }
+    if let Some(value) = get("--ask-col") {
+        config.ask_col = parse_arg(value)?;
+    }
+    if let Some(value) = get("--bid-size-col") {
+        config.bid_size_col = Some(parse_arg(value)?);
+    }
+    if let Some(value) = get("--ask-size-col") {
+        config.ask_size_col = Some(parse_arg(value)?);
+    }
+    if let Some(value) = get("--timestamp-format") {
+        config.timestamp_format = value.to_string();
+    }
+    if let Some(value) = get("--time-resolution") {
+        config.time_resolution = Some(parse_arg(value)?);
+    }
+    if let Some(value) = get("--strict") {
+        config.strict = parse_arg(value)?;
+    }
+    config.has_header = has_header;
+    Ok(config)
+}
+
+fn parse_arg<T: std::str::FromStr>(value: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| Error::InvalidArgumentError(format!("invalid value '{value}'")).into())
+}
@@ -0,0 +1,338 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::io::{Read, Write};
+use std::iter;
+use std::str::FromStr;
+
+use arrow2::error::{Error, Result};
+use arrow2::io::csv::read::{ByteRecord, Reader, ReaderBuilder};
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use nautilus_core::time::UnixNanos;
+use nautilus_model::{
+    data::tick::QuoteTick,
+    identifiers::instrument_id::InstrumentId,
+    types::{
+        fixed::FIXED_PRECISION,
+        price::{Price, PRICE_MAX, PRICE_MIN},
+        quantity::{Quantity, QUANTITY_MAX, QUANTITY_MIN},
+    },
+};
+
+use crate::parquet::{self, ParquetWriter};
+
+/// The resolution of integer UNIX epoch timestamps.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeResolution {
+    Secs,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl TimeResolution {
+    /// Returns the number of nanoseconds in one unit of this resolution.
+    #[must_use]
+    pub fn nanos_per_unit(&self) -> u64 {
+        match self {
+            TimeResolution::Secs => 1_000_000_000,
+            TimeResolution::Millis => 1_000_000,
+            TimeResolution::Micros => 1_000,
+            TimeResolution::Nanos => 1,
+        }
+    }
+}
+
+impl FromStr for TimeResolution {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "secs" => Ok(TimeResolution::Secs),
+            "millis" => Ok(TimeResolution::Millis),
+            "micros" => Ok(TimeResolution::Micros),
+            "nanos" => Ok(TimeResolution::Nanos),
+            _ => Err(format!("invalid `TimeResolution` '{s}'")),
+        }
+    }
+}
+
+/// Describes how to read quote ticks from the columns of a CSV file.
+#[derive(Clone, Debug)]
+pub struct CsvQuoteConfig {
+    pub instrument_id: InstrumentId,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub timestamp_col: usize,
+    pub bid_col: usize,
+    pub ask_col: usize,
+    /// The bid size column, sizes are zero if `None`.
+    pub bid_size_col: Option<usize>,
+    /// The ask size column, sizes are zero if `None`.
+    pub ask_size_col: Option<usize>,
+    /// The `chrono` format of the UTC timestamp column.
+    pub timestamp_format: String,
+    /// If set the timestamp column holds integer UNIX epoch timestamps of
+    /// this resolution, and `timestamp_format` is ignored.
+    pub time_resolution: Option<TimeResolution>,
+    pub has_header: bool,
+    /// If a record with too few fields for the configured columns is an
+    /// error, otherwise such records are skipped.
+    pub strict: bool,
+}
+
+impl CsvQuoteConfig {
+    /// Creates a new config reading timestamp, bid and ask from the first
+    /// three columns of a headerless file.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId, price_precision: u8, size_precision: u8) -> Self {
+        CsvQuoteConfig {
+            instrument_id,
+            price_precision,
+            size_precision,
+            timestamp_col: 0,
+            bid_col: 1,
+            ask_col: 2,
+            bid_size_col: None,
+            ask_size_col: None,
+            timestamp_format: "%Y-%m-%d %H:%M:%S%.f".to_string(),
+            time_resolution: None,
+            has_header: false,
+            strict: true,
+        }
+    }
+
+    /// Returns the number of fields a record needs for all the configured
+    /// columns.
+    fn required_fields(&self) -> usize {
+        [
+            Some(self.timestamp_col),
+            Some(self.bid_col),
+            Some(self.ask_col),
+            self.bid_size_col,
+            self.ask_size_col,
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .map_or(0, |col| col + 1)
+    }
+}
+
+/// Reads all quote ticks from CSV `reader` as described by `config`.
+///
+/// Both `ts_event` and `ts_init` are set from the timestamp column. Records
+/// with too few fields for the configured columns are skipped, unless the
+/// config is `strict`.
+///
+/// # Errors
+/// - If the CSV is malformed or a field cannot be parsed.
+/// - If the config is `strict` and a record has too few fields, with its
+///   line number.
+/// - If a price or size is out of range or NaN, or a precision exceeds
+///   `FIXED_PRECISION`, with its line number.
+pub fn read_quote_ticks_csv<R: Read>(reader: R, config: &CsvQuoteConfig) -> Result<Vec<QuoteTick>> {
+    let mut records = QuoteRecords::new(reader, config);
+    let mut ticks = Vec::new();
+    while let Some(tick) = records.next_tick()? {
+        ticks.push(tick);
+    }
+    Ok(ticks)
+}
+
+/// Streams the quote ticks of CSV `reader`, read as by [`read_quote_ticks_csv`],
+/// into `writer` in batches of `batch_size` ticks, so memory is bounded by
+/// the batch size rather than the size of the file.
+///
+/// Each batch is written as a row group. Returns the number of ticks written.
+///
+/// # Errors
+/// - If `batch_size` is zero.
+/// - If the CSV cannot be read, after writing the batches read before the
+///   error.
+/// - If writing a batch fails.
+pub fn write_quote_ticks_csv_streaming<R: Read, W: Write>(
+    reader: R,
+    config: &CsvQuoteConfig,
+    writer: &mut ParquetWriter<QuoteTick, W>,
+    batch_size: usize,
+) -> parquet::Result<usize> {
+    if batch_size == 0 {
+        return Err(Error::InvalidArgumentError(
+            "`batch_size` must be greater than zero".to_string(),
+        )
+        .into());
+    }
+    let mut records = QuoteRecords::new(reader, config);
+    let mut num_rows = 0;
+    let mut read_error = None;
+    let batches = iter::from_fn(|| match records.next_batch(batch_size) {
+        Ok(batch) if batch.is_empty() => None,
+        Ok(batch) => {
+            num_rows += batch.len();
+            Some(batch)
+        }
+        Err(e) => {
+            read_error = Some(e);
+            None
+        }
+    });
+    writer.write_streaming(batches)?;
+    match read_error {
+        Some(e) => Err(e.into()),
+        None => Ok(num_rows),
+    }
+}
+
+/// Reads quote ticks one CSV record at a time.
+struct QuoteRecords<'a, R: Read> {
+    csv_reader: Reader<R>,
+    record: ByteRecord,
+    config: &'a CsvQuoteConfig,
+    required_fields: usize,
+}
+
+impl<'a, R: Read> QuoteRecords<'a, R> {
+    fn new(reader: R, config: &'a CsvQuoteConfig) -> Self {
+        let csv_reader = ReaderBuilder::new()
+            .has_headers(config.has_header)
+            .flexible(true)
+            .from_reader(reader);
+        QuoteRecords {
+            csv_reader,
+            record: ByteRecord::new(),
+            config,
+            required_fields: config.required_fields(),
+        }
+    }
+
+    /// Returns up to `batch_size` ticks, empty at the end of the file.
+    fn next_batch(&mut self, batch_size: usize) -> Result<Vec<QuoteTick>> {
+        let mut batch = Vec::with_capacity(batch_size);
+        while batch.len() < batch_size {
+            match self.next_tick()? {
+                Some(tick) => batch.push(tick),
+                None => break,
+            }
+        }
+        Ok(batch)
+    }
+
+    /// Returns the tick of the next record not skipped, `None` at the end of
+    /// the file.
+    fn next_tick(&mut self) -> Result<Option<QuoteTick>> {
+        let config = self.config;
+        while self.csv_reader.read_byte_record(&mut self.record)? {
+            let record = &self.record;
+            let line = record.position().map_or(0, |position| position.line());
+            if record.len() < self.required_fields {
+                if !config.strict {
+                    continue;
+                }
+                return Err(Error::ExternalFormat(format!(
+                    "CSV record at line {line} has {} fields, expected at least {}",
+                    record.len(),
+                    self.required_fields
+                )));
+            }
+            check_precision(config.price_precision, line)?;
+            check_precision(config.size_precision, line)?;
+            let ts = parse_timestamp(field(record, config.timestamp_col)?, config)?;
+            let bid_size = match config.bid_size_col {
+                Some(col) => parse_quantity(field(record, col)?, config.size_precision, line)?,
+                None => Quantity::new(0.0, config.size_precision),
+            };
+            let ask_size = match config.ask_size_col {
+                Some(col) => parse_quantity(field(record, col)?, config.size_precision, line)?,
+                None => Quantity::new(0.0, config.size_precision),
+            };
+            return Ok(Some(QuoteTick {
+                instrument_id: config.instrument_id.clone(),
+                bid: parse_price(field(record, config.bid_col)?, config.price_precision, line)?,
+                ask: parse_price(field(record, config.ask_col)?, config.price_precision, line)?,
+                bid_size,
+                ask_size,
+                ts_event: ts,
+                ts_init: ts,
+            }));
+        }
+        Ok(None)
+    }
+}
+
+fn field(record: &ByteRecord, col: usize) -> Result<&str> {
+    let bytes = record
+        .get(col)
+        .ok_or_else(|| Error::InvalidArgumentError(format!("CSV record has no column {col}")))?;
+    std::str::from_utf8(bytes).map_err(|e| Error::ExternalFormat(e.to_string()))
+}
+
+fn parse_f64(s: &str) -> Result<f64> {
+    s.trim()
+        .parse::<f64>()
+        .map_err(|e| Error::ExternalFormat(format!("invalid number '{s}': {e}")))
+}
+
+/// Parses a price of `precision` from field `s` of the record at `line`,
+/// checking the value so constructing the [`Price`] cannot panic.
+fn parse_price(s: &str, precision: u8, line: u64) -> Result<Price> {
+    let value = parse_f64(s)?;
+    if !(PRICE_MIN..=PRICE_MAX).contains(&value) {
+        return Err(Error::ExternalFormat(format!(
+            "CSV record at line {line} has price '{s}' not in range [{PRICE_MIN}, {PRICE_MAX}]"
+        )));
+    }
+    Ok(Price::new(value, precision))
+}
+
+/// Parses a quantity of `precision` from field `s` of the record at `line`,
+/// checking the value so constructing the [`Quantity`] cannot panic.
+fn parse_quantity(s: &str, precision: u8, line: u64) -> Result<Quantity> {
+    let value = parse_f64(s)?;
+    if !(QUANTITY_MIN..=QUANTITY_MAX).contains(&value) {
+        return Err(Error::ExternalFormat(format!(
+            "CSV record at line {line} has size '{s}' not in range [{QUANTITY_MIN}, {QUANTITY_MAX}]"
+        )));
+    }
+    Ok(Quantity::new(value, precision))
+}
+
+fn check_precision(precision: u8, line: u64) -> Result<()> {
+    if precision > FIXED_PRECISION {
+        return Err(Error::ExternalFormat(format!(
+            "CSV record at line {line} has precision {precision}, exceeding maximum {FIXED_PRECISION}"
+        )));
+    }
+    Ok(())
+}
+
+fn parse_timestamp(s: &str, config: &CsvQuoteConfig) -> Result<UnixNanos> {
+    if let Some(resolution) = config.time_resolution {
+        return s
+            .trim()
+            .parse::<u64>()
+            .map_err(|e| Error::ExternalFormat(format!("invalid timestamp '{s}': {e}")))?
+            .checked_mul(resolution.nanos_per_unit())
+            .ok_or_else(|| Error::ExternalFormat(format!("timestamp '{s}' out of range")));
+    }
+    let naive = NaiveDateTime::parse_from_str(s.trim(), &config.timestamp_format)
+        .map_err(|e| Error::ExternalFormat(format!("invalid timestamp '{s}': {e}")))?;
+    let dt = Utc.from_utc_datetime(&naive);
+    u64::try_from(dt.timestamp())
+        .ok()
+        .and_then(|secs| secs.checked_mul(1_000_000_000))
+        .and_then(|nanos| nanos.checked_add(u64::from(dt.timestamp_subsec_nanos())))
+        .ok_or_else(|| Error::ExternalFormat(format!("timestamp '{s}' out of range")))
+}
@@ -13,7 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod csv;
 pub mod parquet;
+pub mod synthetic;
 
 use std::{collections::BTreeMap, ffi::c_void, fs::File, io::Cursor, ptr::null_mut, slice};
 
@@ -22,6 +24,7 @@ use nautilus_model::data::tick::{QuoteTick, TradeTick};
 use parquet::{
     EncodeToChunk, GroupFilterArg, ParquetReader, ParquetReaderType, ParquetType, ParquetWriter,
 };
+use pyo3::exceptions::PyValueError;
 use pyo3::types::PyBytes;
 use pyo3::{prelude::*, types::PyCapsule};
 
@@ -84,20 +87,22 @@ impl PythonParquetReader {
         reader_type: ParquetReaderType,
         buffer: Option<&[u8]>,
         ts_init_filter: i64,
-    ) -> Self {
+    ) -> PyResult<Self> {
         let group_filter: GroupFilterArg = ts_init_filter.into();
         let reader = match (parquet_type, reader_type) {
             (ParquetType::QuoteTick, ParquetReaderType::File) => {
                 let file = File::open(&file_path)
                     .unwrap_or_else(|_| panic!("Unable to open parquet file {file_path}"));
-                let reader = ParquetReader::<QuoteTick, File>::new(file, chunk_size, group_filter);
+                let reader = ParquetReader::<QuoteTick, File>::new(file, chunk_size, group_filter)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
                 let reader = Box::new(reader);
                 Box::into_raw(reader) as *mut c_void
             }
             (ParquetType::TradeTick, ParquetReaderType::File) => {
                 let file = File::open(&file_path)
                     .unwrap_or_else(|_| panic!("Unable to open parquet file {file_path}"));
-                let reader = ParquetReader::<TradeTick, File>::new(file, chunk_size, group_filter);
+                let reader = ParquetReader::<TradeTick, File>::new(file, chunk_size, group_filter)
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
                 let reader = Box::new(reader);
                 Box::into_raw(reader) as *mut c_void
             }
@@ -107,7 +112,8 @@ impl PythonParquetReader {
                     cursor,
                     chunk_size,
                     group_filter,
-                );
+                )
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
                 let reader = Box::new(reader);
                 Box::into_raw(reader) as *mut c_void
             }
@@ -117,18 +123,19 @@ impl PythonParquetReader {
                     cursor,
                     chunk_size,
                     group_filter,
-                );
+                )
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
                 let reader = Box::new(reader);
                 Box::into_raw(reader) as *mut c_void
             }
         };
 
-        PythonParquetReader {
+        Ok(PythonParquetReader {
             reader,
             parquet_type,
             reader_type,
             current_chunk: None,
-        }
+        })
     }
 
     /// The reader implements an iterator.
@@ -136,8 +143,9 @@ impl PythonParquetReader {
         slf
     }
 
-    /// Each iteration returns a chunk of values read from the parquet file.
-    unsafe fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<PyObject> {
+    /// Each iteration returns a chunk of values read from the parquet file,
+    /// raising a `ValueError` if the chunk cannot be read or decoded.
+    unsafe fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
         slf.drop_chunk();
 
         let chunk: Option<CVec> = match (slf.parquet_type, slf.reader_type) {
@@ -146,14 +154,20 @@ impl PythonParquetReader {
                 let chunk = reader.next();
                 // Leak reader value back otherwise it will be dropped after this function
                 Box::into_raw(reader);
-                chunk.map_or_else(|| None, |data| Some(data.into()))
+                chunk
+                    .transpose()
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+                    .map(Into::into)
             }
             (ParquetType::TradeTick, ParquetReaderType::File) => {
                 let mut reader = Box::from_raw(slf.reader as *mut ParquetReader<TradeTick, File>);
                 let chunk = reader.next();
                 // Leak reader value back otherwise it will be dropped after this function
                 Box::into_raw(reader);
-                chunk.map_or_else(|| None, |data| Some(data.into()))
+                chunk
+                    .transpose()
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+                    .map(Into::into)
             }
             (ParquetType::QuoteTick, ParquetReaderType::Buffer) => {
                 let mut reader =
@@ -161,7 +175,10 @@ impl PythonParquetReader {
                 let chunk = reader.next();
                 // Leak reader value back otherwise it will be dropped after this function
                 Box::into_raw(reader);
-                chunk.map_or_else(|| None, |data| Some(data.into()))
+                chunk
+                    .transpose()
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+                    .map(Into::into)
             }
             (ParquetType::TradeTick, ParquetReaderType::Buffer) => {
                 let mut reader =
@@ -169,16 +186,21 @@ impl PythonParquetReader {
                 let chunk = reader.next();
                 // Leak reader value back otherwise it will be dropped after this function
                 Box::into_raw(reader);
-                chunk.map_or_else(|| None, |data| Some(data.into()))
+                chunk
+                    .transpose()
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?
+                    .map(Into::into)
             }
         };
 
         slf.current_chunk = chunk;
         match chunk {
             Some(cvec) => Python::with_gil(|py| {
-                Some(PyCapsule::new::<CVec>(py, cvec, None).unwrap().into_py(py))
+                Ok(Some(
+                    PyCapsule::new::<CVec>(py, cvec, None).unwrap().into_py(py),
+                ))
             }),
-            None => None,
+            None => Ok(None),
         }
     }
 
@@ -0,0 +1,99 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use arrow2::datatypes::Schema;
+use nautilus_model::data::GetTsInit;
+
+use super::{EncodeToChunk, ParquetWriter, Result};
+
+/// Writes a parquet file at a path via a temporary `{path}.tmp` file, which
+/// is only renamed to `path` once the file has been successfully ended.
+///
+/// Readers of `path` therefore never see a partially written file. The
+/// temporary file is removed if ending fails or the writer is dropped
+/// without being ended, such as after a write error.
+pub struct AtomicParquetWriter<A> {
+    // `None` once ended
+    writer: Option<ParquetWriter<A, File>>,
+    tmp_path: PathBuf,
+    path: PathBuf,
+}
+
+impl<A> AtomicParquetWriter<A>
+where
+    A: EncodeToChunk + GetTsInit,
+{
+    /// Creates a new writer to the file at `path`, creating or truncating
+    /// its temporary file.
+    ///
+    /// # Errors
+    /// - If the temporary file cannot be created.
+    pub fn create<P: AsRef<Path>>(path: P, schema: Schema) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let mut tmp_path = OsString::from(path.as_os_str());
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        let file = File::create(&tmp_path)?;
+        Ok(AtomicParquetWriter {
+            writer: Some(ParquetWriter::new(file, schema)),
+            tmp_path,
+            path,
+        })
+    }
+
+    /// Returns the underlying writer, such as to configure it before writing.
+    pub fn writer_mut(&mut self) -> &mut ParquetWriter<A, File> {
+        self.writer
+            .as_mut()
+            .expect("writer is only taken when ended")
+    }
+
+    /// Writes `data` to the temporary file, see [`ParquetWriter::write`].
+    ///
+    /// # Errors
+    /// - If `data` cannot be written.
+    pub fn write(&mut self, data: &[A]) -> Result<()> {
+        self.writer_mut().write(data)
+    }
+
+    /// Ends the file and renames it to its final path, which is returned.
+    ///
+    /// # Errors
+    /// - If the file cannot be ended, synced or renamed, in which case the
+    ///   temporary file is removed.
+    pub fn end(mut self) -> Result<PathBuf> {
+        let writer = self.writer.take().expect("writer is only taken when ended");
+        let result = writer
+            .end()
+            .and_then(|file| Ok(file.sync_all()?))
+            .and_then(|_| Ok(fs::rename(&self.tmp_path, &self.path)?));
+        if result.is_err() {
+            let _ = fs::remove_file(&self.tmp_path);
+        }
+        result.map(|_| self.path.clone())
+    }
+}
+
+impl<A> Drop for AtomicParquetWriter<A> {
+    fn drop(&mut self) {
+        if self.writer.take().is_some() {
+            let _ = fs::remove_file(&self.tmp_path);
+        }
+    }
+}
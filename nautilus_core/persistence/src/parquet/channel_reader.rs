@@ -0,0 +1,80 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::vec;
+
+use arrow2::array::Array;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::Schema;
+use crossbeam_channel::Receiver;
+
+use super::{DecodeFromChunk, Result};
+
+/// Decodes the chunks read ahead by the thread of a `ParquetReader`, see
+/// `ParquetReader::into_channel`.
+pub struct ChannelReader<A> {
+    receiver: Receiver<Result<Chunk<Box<dyn Array>>>>,
+    schema: Schema,
+    decoded: vec::IntoIter<A>,
+    done: bool,
+}
+
+impl<A> ChannelReader<A> {
+    pub(crate) fn new(receiver: Receiver<Result<Chunk<Box<dyn Array>>>>, schema: Schema) -> Self {
+        ChannelReader {
+            receiver,
+            schema,
+            decoded: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+
+    /// Returns the schema of the file being read, including its metadata.
+    #[must_use]
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+}
+
+impl<A> Iterator for ChannelReader<A>
+where
+    A: DecodeFromChunk,
+{
+    type Item = Result<A>;
+
+    /// Yields the decoded items in file order, ending after the first error.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.decoded.next() {
+                return Some(Ok(item));
+            }
+            if self.done {
+                return None;
+            }
+            // the channel disconnects once the thread has sent every chunk
+            let Ok(chunk) = self.receiver.recv() else {
+                self.done = true;
+                return None;
+            };
+            match chunk.and_then(|chunk| A::decode(&self.schema, chunk)) {
+                Ok(data) => self.decoded = data.into_iter(),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::path::Path;
+
+use nautilus_model::data::tick::QuoteTick;
+
+use super::{FileSummary, GroupFilterArg, ParquetReader, Result};
+
+/// The number of rows per chunk of the readers opened by `index_directory`,
+/// which only read the footer so never decode a chunk.
+const INDEX_CHUNK_SIZE: usize = 1;
+
+/// Returns a [`FileSummary`] of each `*.parquet` file directly within `dir`,
+/// such as for query planning over a directory of per-instrument files
+/// without opening them again.
+///
+/// Only the footer of each file is read. Summaries are sorted by their
+/// instruments, then by `ts_init_min`, with the `path` of each file set.
+///
+/// # Errors
+/// - If `dir` cannot be read.
+/// - If a file cannot be opened or its footer cannot be read.
+pub fn index_directory<P: AsRef<Path>>(dir: P) -> Result<Vec<FileSummary>> {
+    let mut summaries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() || path.extension() != Some(OsStr::new("parquet")) {
+            continue;
+        }
+        let reader: ParquetReader<QuoteTick, File> =
+            ParquetReader::new(File::open(&path)?, INDEX_CHUNK_SIZE, GroupFilterArg::None)?;
+        let mut summary = reader.summary()?;
+        summary.path = Some(path);
+        summaries.push(summary);
+    }
+    summaries.sort_by_cached_key(|summary| {
+        let instrument_ids: Vec<String> = summary
+            .instrument_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        (instrument_ids, summary.ts_init_min, summary.path.clone())
+    });
+    Ok(summaries)
+}
@@ -0,0 +1,56 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::io;
+
+use arrow2::datatypes::DataType;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, ParquetError>;
+
+/// The errors of reading, writing, encoding and decoding parquet data.
+#[derive(Error, Debug)]
+pub enum ParquetError {
+    /// An error raised by arrow2 or reported through its error type.
+    #[error("arrow error: {0}")]
+    Arrow(#[from] arrow2::error::Error),
+    /// An I/O error of the underlying reader or writer.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    /// The `schema_version` of a file differs from the supported version.
+    #[error("schema version mismatch, expected {expected} was {found}")]
+    SchemaVersionMismatch { expected: String, found: String },
+    /// The logical types recorded in a file differ from those of the decoder,
+    /// so the file has a matching physical schema but different semantics.
+    #[error("logical types mismatch, expected {expected} was {found}")]
+    LogicalTypeMismatch { expected: String, found: String },
+    /// A required schema metadata key is missing.
+    #[error("metadata missing key `{key}`")]
+    MissingMetadata { key: String },
+    /// A schema metadata value cannot be parsed as the expected type.
+    #[error("metadata key `{key}` has invalid value '{value}', {reason}")]
+    InvalidMetadata {
+        key: String,
+        value: String,
+        reason: String,
+    },
+    /// A column is not of the data type expected by the decoder.
+    #[error("column `{column}` expected data type {expected:?}, was {found:?}")]
+    ColumnTypeMismatch {
+        column: String,
+        expected: Box<DataType>,
+        found: Box<DataType>,
+    },
+}
@@ -13,5 +13,505 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+mod order_book_delta;
+mod partial_quote_tick;
 mod quote_tick;
 mod trade_tick;
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use arrow2::{
+    array::{Array, Int64Array, UInt64Array, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Schema},
+    error::Error,
+};
+
+use nautilus_model::{
+    identifiers::instrument_id::InstrumentId,
+    types::{
+        fixed::FIXED_PRECISION,
+        price::{Price, PRICE_MAX},
+        quantity::{Quantity, QUANTITY_MAX},
+    },
+};
+
+use crate::parquet::{ParquetError, Result};
+
+pub use self::quote_tick::{QuoteMetadata, QuoteRow, RawQuoteColumns};
+
+/// The schema metadata key listing the logical Nautilus type of each column,
+/// as comma separated `name=Type` pairs.
+const LOGICAL_TYPES_KEY: &str = "logical_types";
+
+/// The schema metadata key of the encoding of the price columns, either
+/// [`RAW_PRICE_ENCODING`] when absent or [`DECIMAL_PRICE_ENCODING`].
+pub const PRICE_ENCODING_KEY: &str = "price_encoding";
+
+/// Prices are written as the `Int64` fixed-point `raw` values of `Price`.
+pub const RAW_PRICE_ENCODING: &str = "raw";
+
+/// Prices are written as `Utf8` decimal strings with the digits of their
+/// precision, for downstream tools which cannot scale integers. The strings
+/// are exact but several times larger than the raw values, and compress less
+/// well, so prefer the raw encoding unless a consumer requires decimals.
+pub const DECIMAL_PRICE_ENCODING: &str = "decimal_string";
+
+/// The largest absolute `raw` value of a `Price`, at `PRICE_MAX`.
+const PRICE_RAW_MAX: i64 = PRICE_MAX as i64 * 10_i64.pow(FIXED_PRECISION as u32);
+
+/// The largest `raw` value of a `Quantity`, at `QUANTITY_MAX`.
+const QUANTITY_RAW_MAX: u64 = QUANTITY_MAX as u64 * 10_u64.pow(FIXED_PRECISION as u32);
+
+/// An instrument of the dictionary of a multi-instrument file, with the
+/// precisions of its prices and sizes.
+pub(crate) type InstrumentEntry = (InstrumentId, u8, u8);
+
+/// The maximum number of instrument ids interned per thread, the cache is
+/// cleared when full so files of many instruments cannot grow it unbounded.
+const MAX_INTERNED_INSTRUMENT_IDS: usize = 4096;
+
+thread_local! {
+    /// The instrument ids parsed from schema metadata by their string, per
+    /// thread as `InstrumentId` is not `Send`.
+    static INSTRUMENT_IDS: RefCell<HashMap<String, InstrumentId>> = RefCell::new(HashMap::new());
+}
+
+/// Returns the [`InstrumentId`] of `value`, reusing the id already parsed on
+/// this thread if any, so decoded ticks share their instrument strings rather
+/// than allocating them again for every chunk.
+///
+/// # Errors
+/// - If `value` is not a valid `InstrumentId`.
+fn intern_instrument_id(value: &str) -> std::result::Result<InstrumentId, String> {
+    INSTRUMENT_IDS.with(|ids| {
+        let mut ids = ids.borrow_mut();
+        if let Some(instrument_id) = ids.get(value) {
+            return Ok(instrument_id.clone());
+        }
+        let instrument_id = value.parse::<InstrumentId>()?;
+        if ids.len() >= MAX_INTERNED_INSTRUMENT_IDS {
+            ids.clear();
+        }
+        ids.insert(value.to_string(), instrument_id.clone());
+        Ok(instrument_id)
+    })
+}
+
+/// Returns the value of the metadata `key`.
+///
+/// # Errors
+/// - If the key is missing.
+fn metadata_value<'a>(metadata: &'a BTreeMap<String, String>, key: &str) -> Result<&'a str> {
+    metadata
+        .get(key)
+        .map(String::as_str)
+        .ok_or_else(|| ParquetError::MissingMetadata {
+            key: key.to_string(),
+        })
+}
+
+/// Parses the value of the metadata `key`.
+///
+/// # Errors
+/// - If the key is missing or its value cannot be parsed.
+pub(crate) fn parse_metadata<T>(metadata: &BTreeMap<String, String>, key: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = metadata_value(metadata, key)?;
+    value
+        .parse()
+        .map_err(|e: T::Err| invalid_metadata(key, value, e))
+}
+
+/// Returns the interned [`InstrumentId`] of the metadata `key`.
+///
+/// # Errors
+/// - If the key is missing or its value is not a valid `InstrumentId`.
+pub(crate) fn instrument_id_metadata(
+    metadata: &BTreeMap<String, String>,
+    key: &str,
+) -> Result<InstrumentId> {
+    let value = metadata_value(metadata, key)?;
+    intern_instrument_id(value).map_err(|e| invalid_metadata(key, value, e))
+}
+
+/// Parses the precision of the metadata `key`.
+///
+/// # Errors
+/// - If the key is missing or its value is not a `u8`.
+/// - If the precision exceeds `FIXED_PRECISION`.
+pub(crate) fn precision_metadata(metadata: &BTreeMap<String, String>, key: &str) -> Result<u8> {
+    let precision: u8 = parse_metadata(metadata, key)?;
+    if precision > FIXED_PRECISION {
+        return Err(invalid_metadata(
+            key,
+            &precision.to_string(),
+            format!("exceeds the maximum precision {FIXED_PRECISION}"),
+        ));
+    }
+    Ok(precision)
+}
+
+fn invalid_metadata(key: &str, value: &str, reason: impl Display) -> ParquetError {
+    ParquetError::InvalidMetadata {
+        key: key.to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Returns whether `metadata` selects the [`DECIMAL_PRICE_ENCODING`].
+///
+/// # Errors
+/// - If the price encoding is neither raw nor decimal strings.
+pub(crate) fn decimal_price_encoding(metadata: &BTreeMap<String, String>) -> Result<bool> {
+    match metadata.get(PRICE_ENCODING_KEY).map(String::as_str) {
+        None | Some(RAW_PRICE_ENCODING) => Ok(false),
+        Some(DECIMAL_PRICE_ENCODING) => Ok(true),
+        Some(value) => Err(invalid_metadata(
+            PRICE_ENCODING_KEY,
+            value,
+            format!("expected \"{RAW_PRICE_ENCODING}\" or \"{DECIMAL_PRICE_ENCODING}\""),
+        )),
+    }
+}
+
+/// Formats the fixed-point `raw` value of a price as a decimal string with
+/// `precision` decimal places, without going through `f64`.
+fn format_decimal_price(raw: i64, precision: u8) -> String {
+    let scalar = 10_u64.pow(u32::from(FIXED_PRECISION));
+    let sign = if raw < 0 { "-" } else { "" };
+    let units = raw.unsigned_abs() / scalar;
+    if precision == 0 {
+        return format!("{sign}{units}");
+    }
+    let fraction = raw.unsigned_abs() % scalar / 10_u64.pow(u32::from(FIXED_PRECISION - precision));
+    let width = usize::from(precision);
+    format!("{sign}{units}.{fraction:0width$}")
+}
+
+/// Parses a decimal string into the fixed-point `raw` value of a price,
+/// without going through `f64`.
+///
+/// Returns `None` if `value` is not a plain decimal, has more decimal places
+/// than `precision`, or `FIXED_PRECISION`, or overflows.
+fn parse_decimal_price(value: &str, precision: u8) -> Option<i64> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (units, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if units.is_empty() || fraction.len() > usize::from(precision.min(FIXED_PRECISION)) {
+        return None;
+    }
+    if !is_digits(units) || !is_digits(fraction) {
+        return None;
+    }
+    let mut raw = units
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(10_i64.pow(u32::from(FIXED_PRECISION)))?;
+    if !fraction.is_empty() {
+        let scale = 10_i64.pow(u32::from(FIXED_PRECISION) - fraction.len() as u32);
+        raw = raw.checked_add(fraction.parse::<i64>().ok()? * scale)?;
+    }
+    Some(if negative { -raw } else { raw })
+}
+
+/// Encodes `prices` as a `Utf8` array of decimal strings.
+fn decimal_price_array<'a, I>(prices: I) -> Utf8Array<i32>
+where
+    I: Iterator<Item = &'a Price>,
+{
+    prices
+        .map(|price| Some(format_decimal_price(price.raw, price.precision)))
+        .collect()
+}
+
+/// Inserts the ordered instrument `dictionary` of a multi-instrument file into
+/// `metadata`, as `instrument_{i}` with its `instrument_{i}_price_precision`
+/// and `instrument_{i}_size_precision`.
+pub(crate) fn insert_instrument_dictionary(
+    metadata: &mut BTreeMap<String, String>,
+    dictionary: &[InstrumentEntry],
+) {
+    for (i, (instrument_id, price_precision, size_precision)) in dictionary.iter().enumerate() {
+        metadata.insert(format!("instrument_{i}"), instrument_id.to_string());
+        metadata.insert(
+            format!("instrument_{i}_price_precision"),
+            price_precision.to_string(),
+        );
+        metadata.insert(
+            format!("instrument_{i}_size_precision"),
+            size_precision.to_string(),
+        );
+    }
+}
+
+/// Returns the ordered instrument dictionary of a multi-instrument file from
+/// `metadata`, or `None` if it has no `instrument_0` key.
+///
+/// # Errors
+/// - If an instrument is not a valid `InstrumentId`.
+/// - If the precisions of an instrument are missing or not a `u8`.
+/// - If the precisions of an instrument exceed `FIXED_PRECISION`.
+pub(crate) fn instrument_dictionary(
+    metadata: &BTreeMap<String, String>,
+) -> Result<Option<Vec<InstrumentEntry>>> {
+    let mut dictionary = Vec::new();
+    while metadata.contains_key(&format!("instrument_{}", dictionary.len())) {
+        let i = dictionary.len();
+        dictionary.push((
+            instrument_id_metadata(metadata, &format!("instrument_{i}"))?,
+            precision_metadata(metadata, &format!("instrument_{i}_price_precision"))?,
+            precision_metadata(metadata, &format!("instrument_{i}_size_precision"))?,
+        ));
+    }
+    Ok((!dictionary.is_empty()).then_some(dictionary))
+}
+
+/// Inserts the `logical_types` of the columns into `metadata`.
+fn insert_logical_types(metadata: &mut BTreeMap<String, String>, logical_types: &str) {
+    metadata.insert(LOGICAL_TYPES_KEY.to_string(), logical_types.to_string());
+}
+
+/// Checks the logical types recorded in the metadata of `schema` match the
+/// `expected` logical types, files without the key are assumed to match.
+///
+/// # Errors
+/// - If the recorded logical types differ from `expected`.
+fn check_logical_types(schema: &Schema, expected: &str) -> Result<()> {
+    match schema.metadata.get(LOGICAL_TYPES_KEY) {
+        Some(found) if found != expected => Err(ParquetError::LogicalTypeMismatch {
+            expected: expected.to_string(),
+            found: found.clone(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Downcasts the column at `index` to the concrete array type `T`.
+///
+/// # Errors
+/// - If the chunk has no column at `index`.
+/// - If the column is not of the `expected` data type.
+fn downcast_column<'a, T: Array + 'static>(
+    cols: &'a Chunk<Box<dyn Array>>,
+    index: usize,
+    name: &str,
+    expected: DataType,
+) -> Result<&'a T> {
+    let array = cols
+        .arrays()
+        .get(index)
+        .ok_or_else(|| Error::OutOfSpec(format!("missing column `{name}` at index {index}")))?;
+    array
+        .as_any()
+        .downcast_ref::<T>()
+        .ok_or_else(|| ParquetError::ColumnTypeMismatch {
+            column: name.to_string(),
+            expected: Box::new(expected),
+            found: Box::new(array.data_type().clone()),
+        })
+}
+
+/// Downcasts the column at `index` as [`downcast_column`], checking it has no
+/// nulls so its values can be read without unwrapping.
+///
+/// # Errors
+/// - If the chunk has no column at `index`.
+/// - If the column is not of the `expected` data type.
+/// - If the column has any nulls.
+fn required_column<'a, T: Array + 'static>(
+    cols: &'a Chunk<Box<dyn Array>>,
+    index: usize,
+    name: &str,
+    expected: DataType,
+) -> Result<&'a T> {
+    let array = downcast_column::<T>(cols, index, name, expected)?;
+    check_no_nulls(array, name)?;
+    Ok(array)
+}
+
+/// Downcasts the column of the field `name` in `schema` to the concrete array
+/// type `T`, so extra columns unknown to the decoder are ignored.
+///
+/// # Errors
+/// - If the schema has no field `name` or the chunk has no column for it.
+/// - If the column is not of the `expected` data type.
+fn downcast_named_column<'a, T: Array + 'static>(
+    schema: &Schema,
+    cols: &'a Chunk<Box<dyn Array>>,
+    name: &str,
+    expected: DataType,
+) -> Result<&'a T> {
+    let index = schema
+        .fields
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(|| Error::OutOfSpec(format!("missing column `{name}` in schema")))?;
+    downcast_column(cols, index, name, expected)
+}
+
+/// Downcasts the column of the field `name` as [`downcast_named_column`],
+/// checking it has no nulls, as files from other writers such as pyarrow may
+/// mark required columns nullable.
+///
+/// # Errors
+/// - If the column is missing or not of the `expected` data type.
+/// - If the column has any nulls.
+fn required_named_column<'a, T: Array + 'static>(
+    schema: &Schema,
+    cols: &'a Chunk<Box<dyn Array>>,
+    name: &str,
+    expected: DataType,
+) -> Result<&'a T> {
+    let array = downcast_named_column::<T>(schema, cols, name, expected)?;
+    check_no_nulls(array, name)?;
+    Ok(array)
+}
+
+/// Returns the `UInt64` timestamp column of the field `name`, also accepting
+/// the `Int64` timestamps written by pyarrow when none are negative.
+///
+/// # Errors
+/// - If the column is missing or neither `UInt64` nor `Int64`.
+/// - If the column has any nulls or negative timestamps.
+fn timestamp_named_column(
+    schema: &Schema,
+    cols: &Chunk<Box<dyn Array>>,
+    name: &str,
+) -> Result<UInt64Array> {
+    let field = schema.fields.iter().find(|field| field.name == name);
+    if !matches!(field.map(|field| &field.data_type), Some(DataType::Int64)) {
+        return required_named_column::<UInt64Array>(schema, cols, name, DataType::UInt64).cloned();
+    }
+    let array = required_named_column::<Int64Array>(schema, cols, name, DataType::Int64)?;
+    let values = array
+        .values()
+        .iter()
+        .map(|value| {
+            u64::try_from(*value).map_err(|_| {
+                Error::OutOfSpec(format!("column `{name}` has negative timestamp {value}"))
+            })
+        })
+        .collect::<std::result::Result<Vec<u64>, Error>>()?;
+    Ok(UInt64Array::from_vec(values))
+}
+
+/// Returns the `Int64` raw price column of the field `name`, parsing the
+/// decimal strings of files written with the [`DECIMAL_PRICE_ENCODING`] at
+/// the `price_precision` of each row.
+///
+/// # Errors
+/// - If the price encoding of the schema metadata is invalid.
+/// - If the column is missing, not of the expected data type or has nulls.
+/// - If a decimal string cannot be parsed, or has more decimal places than
+///   the precision of its row.
+fn price_named_column(
+    schema: &Schema,
+    cols: &Chunk<Box<dyn Array>>,
+    name: &str,
+    price_precision: &dyn Fn(usize) -> Result<u8>,
+) -> Result<Int64Array> {
+    if !decimal_price_encoding(&schema.metadata)? {
+        return required_named_column::<Int64Array>(schema, cols, name, DataType::Int64).cloned();
+    }
+    let array = required_named_column::<Utf8Array<i32>>(schema, cols, name, DataType::Utf8)?;
+    let values = array
+        .values_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            parse_decimal_price(value, price_precision(i)?).ok_or_else(|| {
+                Error::OutOfSpec(format!(
+                    "column `{name}` has invalid decimal price \"{value}\" at row {i}"
+                ))
+                .into()
+            })
+        })
+        .collect::<Result<Vec<i64>>>()?;
+    Ok(Int64Array::from_vec(values))
+}
+
+/// Checks the column `name` has no nulls.
+///
+/// # Errors
+/// - If `array` has any nulls.
+fn check_no_nulls(array: &dyn Array, name: &str) -> Result<()> {
+    match array.null_count() {
+        0 => Ok(()),
+        count => {
+            Err(Error::OutOfSpec(format!("required column `{name}` has {count} nulls")).into())
+        }
+    }
+}
+
+/// Checks the `desc` precision of the element at `index` matches the
+/// `expected` precision of the schema metadata.
+///
+/// # Errors
+/// - If `precision` is not equal to `expected`.
+fn check_precision(index: usize, desc: &str, precision: u8, expected: u8) -> Result<()> {
+    if precision == expected {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "`{desc}` precision of {precision} at index {index} differs from {expected} of the schema metadata"
+        ))
+        .into())
+    }
+}
+
+/// Checks the `desc` price at `index` is within the range of `Price`.
+///
+/// # Errors
+/// - If the raw value is beyond `PRICE_MIN` or `PRICE_MAX`.
+fn check_price_bounds(index: usize, desc: &str, price: &Price) -> Result<()> {
+    if (-PRICE_RAW_MAX..=PRICE_RAW_MAX).contains(&price.raw) {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "`{desc}` raw value {} at index {index} was not in the `Price` range [{}, {PRICE_RAW_MAX}]",
+            price.raw, -PRICE_RAW_MAX
+        ))
+        .into())
+    }
+}
+
+/// Checks the `desc` size at `index` is within the range of `Quantity`.
+///
+/// # Errors
+/// - If the raw value is greater than `QUANTITY_MAX`.
+fn check_quantity_bounds(index: usize, desc: &str, quantity: &Quantity) -> Result<()> {
+    if quantity.raw <= QUANTITY_RAW_MAX {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "`{desc}` raw value {} at index {index} exceeded the `Quantity` maximum {QUANTITY_RAW_MAX}",
+            quantity.raw
+        ))
+        .into())
+    }
+}
+
+/// Checks the quote at `index` is not crossed, with its `bid` above its `ask`.
+///
+/// # Errors
+/// - If `bid` is greater than `ask`.
+fn check_not_crossed(index: usize, bid: &Price, ask: &Price) -> Result<()> {
+    if bid.raw > ask.raw {
+        Err(Error::InvalidArgumentError(format!(
+            "crossed quote at index {index}, bid {bid} was greater than ask {ask}"
+        ))
+        .into())
+    } else {
+        Ok(())
+    }
+}
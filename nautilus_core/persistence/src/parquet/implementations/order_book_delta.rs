@@ -0,0 +1,212 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use arrow2::{
+    array::{Array, Int64Array, UInt64Array, UInt8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    error::Error,
+    io::parquet::write::{transverse, Encoding},
+};
+use nautilus_model::data::delta::OrderBookDelta;
+use nautilus_model::enums::{BookAction, OrderSide};
+use nautilus_model::orderbook::order::BookOrder;
+use nautilus_model::types::{price::Price, quantity::Quantity};
+
+use super::{
+    check_logical_types, check_precision, check_price_bounds, check_quantity_bounds,
+    insert_logical_types, instrument_id_metadata, precision_metadata, required_column,
+};
+use crate::parquet::{DecodeFromChunk, EncodeToChunk, Result};
+
+/// The logical Nautilus type of each column of order book deltas.
+const ORDER_BOOK_DELTA_LOGICAL_TYPES: &str =
+    "action=BookAction,side=OrderSide,price=Price,size=Quantity,order_id=UInt64,sequence=UInt64,ts_event=Timestamp,ts_init=Timestamp";
+
+impl EncodeToChunk for OrderBookDelta {
+    fn assert_metadata(metadata: &BTreeMap<String, String>) {
+        let keys = ["instrument_id", "price_precision", "size_precision"];
+        for key in keys {
+            (!metadata.contains_key(key)).then(|| panic!("metadata missing key \"{key}\""));
+        }
+    }
+
+    fn encodings(metadata: BTreeMap<String, String>) -> Vec<Vec<Encoding>> {
+        OrderBookDelta::encode_schema(metadata)
+            .fields
+            .iter()
+            .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+            .collect()
+    }
+
+    fn encode_schema(mut metadata: BTreeMap<String, String>) -> Schema {
+        Self::assert_metadata(&metadata);
+        insert_logical_types(&mut metadata, ORDER_BOOK_DELTA_LOGICAL_TYPES);
+        let fields = vec![
+            Field::new("action", DataType::UInt8, false),
+            Field::new("side", DataType::UInt8, false),
+            Field::new("price", DataType::Int64, false),
+            Field::new("size", DataType::UInt64, false),
+            Field::new("order_id", DataType::UInt64, false),
+            Field::new("sequence", DataType::UInt64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        Schema::from(fields).with_metadata(metadata)
+    }
+
+    fn check_precisions(price_precision: u8, size_precision: u8, data: &[Self]) -> Result<()> {
+        for (i, delta) in data.iter().enumerate() {
+            check_precision(i, "price", delta.order.price.precision, price_precision)?;
+            check_precision(i, "size", delta.order.size.precision, size_precision)?;
+        }
+        Ok(())
+    }
+
+    fn check_bounds(data: &[Self]) -> Result<()> {
+        for (i, delta) in data.iter().enumerate() {
+            check_price_bounds(i, "price", &delta.order.price)?;
+            check_quantity_bounds(i, "size", &delta.order.size)?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn encode<'a, I>(data: I) -> Chunk<Box<dyn Array>>
+    where
+        I: Iterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        let (
+            mut action_column,
+            mut side_column,
+            mut price_column,
+            mut size_column,
+            mut order_id_column,
+            mut sequence_column,
+            mut ts_event_column,
+            mut ts_init_column,
+        ): (
+            Vec<u8>,
+            Vec<u8>,
+            Vec<i64>,
+            Vec<u64>,
+            Vec<u64>,
+            Vec<u64>,
+            Vec<u64>,
+            Vec<u64>,
+        ) = (
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+
+        data.fold((), |(), delta| {
+            action_column.push(delta.action as u8);
+            side_column.push(u8::from(delta.order.side));
+            price_column.push(delta.order.price.raw);
+            size_column.push(delta.order.size.raw);
+            order_id_column.push(delta.order.order_id);
+            sequence_column.push(delta.sequence);
+            ts_event_column.push(delta.ts_event);
+            ts_init_column.push(delta.ts_init);
+        });
+
+        let action_array = UInt8Array::from_vec(action_column);
+        let side_array = UInt8Array::from_vec(side_column);
+        let price_array = Int64Array::from_vec(price_column);
+        let size_array = UInt64Array::from_vec(size_column);
+        let order_id_array = UInt64Array::from_vec(order_id_column);
+        let sequence_array = UInt64Array::from_vec(sequence_column);
+        let ts_event_array = UInt64Array::from_vec(ts_event_column);
+        let ts_init_array = UInt64Array::from_vec(ts_init_column);
+        Chunk::new(vec![
+            action_array.to_boxed(),
+            side_array.to_boxed(),
+            price_array.to_boxed(),
+            size_array.to_boxed(),
+            order_id_array.to_boxed(),
+            sequence_array.to_boxed(),
+            ts_event_array.to_boxed(),
+            ts_init_array.to_boxed(),
+        ])
+    }
+}
+
+impl DecodeFromChunk for OrderBookDelta {
+    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Result<Vec<Self>> {
+        check_logical_types(schema, ORDER_BOOK_DELTA_LOGICAL_TYPES)?;
+        let instrument_id = instrument_id_metadata(&schema.metadata, "instrument_id")?;
+        let price_precision = precision_metadata(&schema.metadata, "price_precision")?;
+        let size_precision = precision_metadata(&schema.metadata, "size_precision")?;
+
+        // extract field value arrays from chunk separately
+        let action_values = required_column::<UInt8Array>(&cols, 0, "action", DataType::UInt8)?;
+        let side_values = required_column::<UInt8Array>(&cols, 1, "side", DataType::UInt8)?;
+        let price_values = required_column::<Int64Array>(&cols, 2, "price", DataType::Int64)?;
+        let size_values = required_column::<UInt64Array>(&cols, 3, "size", DataType::UInt64)?;
+        let order_id_values =
+            required_column::<UInt64Array>(&cols, 4, "order_id", DataType::UInt64)?;
+        let sequence_values =
+            required_column::<UInt64Array>(&cols, 5, "sequence", DataType::UInt64)?;
+        let ts_event_values =
+            required_column::<UInt64Array>(&cols, 6, "ts_event", DataType::UInt64)?;
+        let ts_init_values = required_column::<UInt64Array>(&cols, 7, "ts_init", DataType::UInt64)?;
+
+        // construct iterator of values from field value arrays
+        let values = action_values
+            .values_iter()
+            .zip(side_values.values_iter())
+            .zip(price_values.values_iter())
+            .zip(size_values.values_iter())
+            .zip(order_id_values.values_iter())
+            .zip(sequence_values.values_iter())
+            .zip(ts_event_values.values_iter())
+            .zip(ts_init_values.values_iter())
+            .map(
+                |(
+                    ((((((&action, &side), &price), &size), &order_id), &sequence), &ts_event),
+                    &ts_init,
+                )| {
+                    let action = BookAction::from_repr(action as usize).ok_or_else(|| {
+                        Error::OutOfSpec(format!("invalid `BookAction` enum value, was {action}"))
+                    })?;
+                    Ok(OrderBookDelta {
+                        instrument_id: instrument_id.clone(),
+                        action,
+                        order: BookOrder {
+                            price: Price::from_raw(price, price_precision),
+                            size: Quantity::from_raw(size, size_precision),
+                            side: OrderSide::try_from(side).map_err(Error::OutOfSpec)?,
+                            order_id,
+                        },
+                        sequence,
+                        ts_event,
+                        ts_init,
+                    })
+                },
+            );
+
+        values.collect()
+    }
+}
@@ -0,0 +1,192 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+use arrow2::{
+    array::{Array, Int64Array, UInt64Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    io::parquet::write::{transverse, Encoding},
+};
+use nautilus_model::data::tick::PartialQuoteTick;
+use nautilus_model::types::{price::Price, quantity::Quantity};
+
+use super::quote_tick::{QuoteMetadata, QUOTE_TICK_LOGICAL_TYPES};
+use super::{
+    check_logical_types, check_not_crossed, check_precision, check_price_bounds,
+    check_quantity_bounds, downcast_column, insert_logical_types, required_column,
+};
+use crate::parquet::{DecodeFromChunk, EncodeToChunk, Result};
+
+impl EncodeToChunk for PartialQuoteTick {
+    fn assert_metadata(metadata: &BTreeMap<String, String>) {
+        let keys = ["instrument_id", "price_precision", "size_precision"];
+        for key in keys {
+            (!metadata.contains_key(key)).then(|| panic!("metadata missing key \"{key}\""));
+        }
+    }
+
+    fn encodings(metadata: BTreeMap<String, String>) -> Vec<Vec<Encoding>> {
+        PartialQuoteTick::encode_schema(metadata)
+            .fields
+            .iter()
+            .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+            .collect()
+    }
+
+    fn encode_schema(mut metadata: BTreeMap<String, String>) -> Schema {
+        Self::assert_metadata(&metadata);
+        insert_logical_types(&mut metadata, QUOTE_TICK_LOGICAL_TYPES);
+        let fields = vec![
+            Field::new("bid", DataType::Int64, false),
+            Field::new("ask", DataType::Int64, false),
+            Field::new("bid_size", DataType::UInt64, true),
+            Field::new("ask_size", DataType::UInt64, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        Schema::from(fields).with_metadata(metadata)
+    }
+
+    fn check_precisions(price_precision: u8, size_precision: u8, data: &[Self]) -> Result<()> {
+        for (i, quote) in data.iter().enumerate() {
+            check_precision(i, "bid", quote.bid.precision, price_precision)?;
+            check_precision(i, "ask", quote.ask.precision, price_precision)?;
+            if let Some(size) = &quote.bid_size {
+                check_precision(i, "bid_size", size.precision, size_precision)?;
+            }
+            if let Some(size) = &quote.ask_size {
+                check_precision(i, "ask_size", size.precision, size_precision)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_not_crossed(data: &[Self]) -> Result<()> {
+        for (i, quote) in data.iter().enumerate() {
+            check_not_crossed(i, &quote.bid, &quote.ask)?;
+        }
+        Ok(())
+    }
+
+    fn check_bounds(data: &[Self]) -> Result<()> {
+        for (i, quote) in data.iter().enumerate() {
+            check_price_bounds(i, "bid", &quote.bid)?;
+            check_price_bounds(i, "ask", &quote.ask)?;
+            if let Some(bid_size) = &quote.bid_size {
+                check_quantity_bounds(i, "bid_size", bid_size)?;
+            }
+            if let Some(ask_size) = &quote.ask_size {
+                check_quantity_bounds(i, "ask_size", ask_size)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn encode<'a, I>(data: I) -> Chunk<Box<dyn Array>>
+    where
+        I: Iterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        let (
+            mut bid_column,
+            mut ask_column,
+            mut bid_size_column,
+            mut ask_size_column,
+            mut ts_event_column,
+            mut ts_init_column,
+        ): (
+            Vec<i64>,
+            Vec<i64>,
+            Vec<Option<u64>>,
+            Vec<Option<u64>>,
+            Vec<u64>,
+            Vec<u64>,
+        ) = (vec![], vec![], vec![], vec![], vec![], vec![]);
+
+        data.fold((), |(), quote| {
+            bid_column.push(quote.bid.raw);
+            ask_column.push(quote.ask.raw);
+            bid_size_column.push(quote.bid_size.as_ref().map(|size| size.raw));
+            ask_size_column.push(quote.ask_size.as_ref().map(|size| size.raw));
+            ts_event_column.push(quote.ts_event);
+            ts_init_column.push(quote.ts_init);
+        });
+
+        // sizes are written with a validity bitmap so unknown sizes decode as `None`
+        let bid_array = Int64Array::from_vec(bid_column);
+        let ask_array = Int64Array::from_vec(ask_column);
+        let bid_size_array = UInt64Array::from(bid_size_column);
+        let ask_size_array = UInt64Array::from(ask_size_column);
+        let ts_event_array = UInt64Array::from_vec(ts_event_column);
+        let ts_init_array = UInt64Array::from_vec(ts_init_column);
+        Chunk::new(vec![
+            bid_array.to_boxed(),
+            ask_array.to_boxed(),
+            bid_size_array.to_boxed(),
+            ask_size_array.to_boxed(),
+            ts_event_array.to_boxed(),
+            ts_init_array.to_boxed(),
+        ])
+    }
+}
+
+impl DecodeFromChunk for PartialQuoteTick {
+    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Result<Vec<Self>> {
+        check_logical_types(schema, QUOTE_TICK_LOGICAL_TYPES)?;
+        let QuoteMetadata {
+            instrument_id,
+            price_precision,
+            size_precision,
+        } = QuoteMetadata::from_schema(schema)?;
+
+        // extract field value arrays from chunk separately, only the sizes may
+        // have nulls
+        let bid_values = required_column::<Int64Array>(&cols, 0, "bid", DataType::Int64)?;
+        let ask_values = required_column::<Int64Array>(&cols, 1, "ask", DataType::Int64)?;
+        let bid_size_values =
+            downcast_column::<UInt64Array>(&cols, 2, "bid_size", DataType::UInt64)?;
+        let ask_size_values =
+            downcast_column::<UInt64Array>(&cols, 3, "ask_size", DataType::UInt64)?;
+        let ts_event_values =
+            required_column::<UInt64Array>(&cols, 4, "ts_event", DataType::UInt64)?;
+        let ts_init_values = required_column::<UInt64Array>(&cols, 5, "ts_init", DataType::UInt64)?;
+
+        // construct iterator of values from field value arrays
+        let values = bid_values
+            .values_iter()
+            .zip(ask_values.values_iter())
+            .zip(bid_size_values)
+            .zip(ask_size_values)
+            .zip(ts_event_values.values_iter())
+            .zip(ts_init_values.values_iter())
+            .map(
+                |(((((&bid, &ask), bid_size), ask_size), &ts_event), &ts_init)| PartialQuoteTick {
+                    instrument_id: instrument_id.clone(),
+                    bid: Price::from_raw(bid, price_precision),
+                    ask: Price::from_raw(ask, price_precision),
+                    bid_size: bid_size.map(|raw| Quantity::from_raw(*raw, size_precision)),
+                    ask_size: ask_size.map(|raw| Quantity::from_raw(*raw, size_precision)),
+                    ts_event,
+                    ts_init,
+                },
+            );
+
+        Ok(values.collect())
+    }
+}
@@ -16,21 +16,96 @@
 use std::collections::BTreeMap;
 
 use arrow2::{
-    array::{Array, Int64Array, UInt64Array},
+    array::{Array, Int64Array, UInt32Array, UInt64Array},
     chunk::Chunk,
     datatypes::{DataType, Field, Schema},
+    error::Error,
     io::parquet::write::{transverse, Encoding},
 };
 use nautilus_model::data::tick::QuoteTick;
 use nautilus_model::{
     identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
 };
 
-use crate::parquet::{DecodeFromChunk, EncodeToChunk};
+use super::{
+    check_logical_types, check_not_crossed, check_precision, check_price_bounds,
+    check_quantity_bounds, decimal_price_array, decimal_price_encoding, insert_logical_types,
+    instrument_dictionary, instrument_id_metadata, precision_metadata, price_named_column,
+    required_named_column, timestamp_named_column, InstrumentEntry,
+};
+use crate::parquet::{DecodeFromChunk, EncodeToChunk, Result};
+
+/// The logical Nautilus type of each column of quote ticks.
+pub(super) const QUOTE_TICK_LOGICAL_TYPES: &str =
+    "bid=Price,ask=Price,bid_size=Quantity,ask_size=Quantity,ts_event=Timestamp,ts_init=Timestamp";
+
+/// The logical Nautilus type of each column of quote ticks of multiple
+/// instruments, with the index of each row's instrument in the dictionary.
+const MULTI_QUOTE_TICK_LOGICAL_TYPES: &str = "bid=Price,ask=Price,bid_size=Quantity,ask_size=Quantity,ts_event=Timestamp,ts_init=Timestamp,instrument_index=InstrumentIndex";
+
+/// Returns the index in `dictionary` of `instrument_id`.
+///
+/// # Errors
+/// - If `instrument_id` is not in the dictionary.
+fn dictionary_index(
+    dictionary: &[InstrumentEntry],
+    index: usize,
+    instrument_id: &InstrumentId,
+) -> Result<usize> {
+    dictionary
+        .iter()
+        .position(|(id, _, _)| id == instrument_id)
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "instrument {instrument_id} at index {index} is not in the instrument dictionary"
+            ))
+            .into()
+        })
+}
+
+/// The instrument and precisions of a single-instrument file of quotes, parsed
+/// from its schema metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuoteMetadata {
+    pub instrument_id: InstrumentId,
+    pub price_precision: u8,
+    pub size_precision: u8,
+}
+
+impl QuoteMetadata {
+    /// Parses the `instrument_id`, `price_precision` and `size_precision`
+    /// keys of the metadata of `schema`.
+    ///
+    /// # Errors
+    /// - If a key is missing.
+    /// - If the `instrument_id` is not a valid `InstrumentId`.
+    /// - If a precision is not a `u8` or exceeds `FIXED_PRECISION`.
+    pub fn from_schema(schema: &Schema) -> Result<Self> {
+        Self::from_metadata(&schema.metadata)
+    }
+
+    /// Parses the `instrument_id`, `price_precision` and `size_precision`
+    /// keys of `metadata`, see [`QuoteMetadata::from_schema`].
+    ///
+    /// # Errors
+    /// - If a key is missing or invalid.
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Result<Self> {
+        Ok(QuoteMetadata {
+            instrument_id: instrument_id_metadata(metadata, "instrument_id")?,
+            price_precision: precision_metadata(metadata, "price_precision")?,
+            size_precision: precision_metadata(metadata, "size_precision")?,
+        })
+    }
+}
 
 impl EncodeToChunk for QuoteTick {
     fn assert_metadata(metadata: &BTreeMap<String, String>) {
+        decimal_price_encoding(metadata).unwrap_or_else(|e| panic!("{e}"));
+        // the instrument dictionary is checked when writing, returning an error
+        if metadata.contains_key("instrument_0") {
+            return;
+        }
         let keys = ["instrument_id", "price_precision", "size_precision"];
         for key in keys {
             (!metadata.contains_key(key)).then(|| panic!("metadata missing key \"{key}\""));
@@ -45,20 +120,87 @@ impl EncodeToChunk for QuoteTick {
             .collect()
     }
 
-    fn encode_schema(metadata: BTreeMap<String, String>) -> Schema {
+    fn encode_schema(mut metadata: BTreeMap<String, String>) -> Schema {
         Self::assert_metadata(&metadata);
-        let fields = vec![
-            Field::new("bid", DataType::Int64, false),
-            Field::new("ask", DataType::Int64, false),
+        let price_type = if decimal_price_encoding(&metadata).expect("checked price encoding") {
+            DataType::Utf8
+        } else {
+            DataType::Int64
+        };
+        let mut fields = vec![
+            Field::new("bid", price_type.clone(), false),
+            Field::new("ask", price_type, false),
             Field::new("bid_size", DataType::UInt64, false),
             Field::new("ask_size", DataType::UInt64, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
         ];
+        if metadata.contains_key("instrument_0") {
+            insert_logical_types(&mut metadata, MULTI_QUOTE_TICK_LOGICAL_TYPES);
+            fields.push(Field::new("instrument_index", DataType::UInt32, false));
+        } else {
+            insert_logical_types(&mut metadata, QUOTE_TICK_LOGICAL_TYPES);
+        }
 
         Schema::from(fields).with_metadata(metadata)
     }
 
+    fn check_precisions(price_precision: u8, size_precision: u8, data: &[Self]) -> Result<()> {
+        for (i, quote) in data.iter().enumerate() {
+            check_precision(i, "bid", quote.bid.precision, price_precision)?;
+            check_precision(i, "ask", quote.ask.precision, price_precision)?;
+            check_precision(i, "bid_size", quote.bid_size.precision, size_precision)?;
+            check_precision(i, "ask_size", quote.ask_size.precision, size_precision)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the precisions of each quote match those of its instrument in
+    /// the instrument dictionary if the metadata has one, otherwise those of
+    /// the [`QuoteMetadata`].
+    fn check_metadata_precisions(metadata: &BTreeMap<String, String>, data: &[Self]) -> Result<()> {
+        let Some(dictionary) = instrument_dictionary(metadata)? else {
+            let metadata = QuoteMetadata::from_metadata(metadata)?;
+            return Self::check_precisions(metadata.price_precision, metadata.size_precision, data);
+        };
+        for (i, quote) in data.iter().enumerate() {
+            let (instrument_id, price_precision, size_precision) =
+                &dictionary[dictionary_index(&dictionary, i, &quote.instrument_id)?];
+            let precisions = [
+                ("bid", quote.bid.precision, *price_precision),
+                ("ask", quote.ask.precision, *price_precision),
+                ("bid_size", quote.bid_size.precision, *size_precision),
+                ("ask_size", quote.ask_size.precision, *size_precision),
+            ];
+            for (desc, precision, expected) in precisions {
+                if precision != expected {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "`{desc}` precision of {precision} at index {i} differs from {expected} of instrument {instrument_id}"
+                    ))
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_not_crossed(data: &[Self]) -> Result<()> {
+        for (i, quote) in data.iter().enumerate() {
+            check_not_crossed(i, &quote.bid, &quote.ask)?;
+        }
+        Ok(())
+    }
+
+    fn check_bounds(data: &[Self]) -> Result<()> {
+        for (i, quote) in data.iter().enumerate() {
+            check_price_bounds(i, "bid", &quote.bid)?;
+            check_price_bounds(i, "ask", &quote.ask)?;
+            check_quantity_bounds(i, "bid_size", &quote.bid_size)?;
+            check_quantity_bounds(i, "ask_size", &quote.ask_size)?;
+        }
+        Ok(())
+    }
+
     #[allow(clippy::type_complexity)]
     fn encode<'a, I>(data: I) -> Chunk<Box<dyn Array>>
     where
@@ -99,71 +241,292 @@ impl EncodeToChunk for QuoteTick {
             ts_init_array.to_boxed(),
         ])
     }
+
+    /// Writes the `bid` and `ask` columns as decimal strings if the metadata
+    /// selects that price encoding, and appends the `instrument_index` column
+    /// of each quote's instrument in the instrument dictionary, if the
+    /// metadata has one.
+    fn encode_with_metadata<'a, I>(
+        metadata: &BTreeMap<String, String>,
+        data: I,
+    ) -> Chunk<Box<dyn Array>>
+    where
+        I: Iterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        let dictionary = instrument_dictionary(metadata).expect("checked instrument dictionary");
+        let decimal_prices = decimal_price_encoding(metadata).expect("checked price encoding");
+        if dictionary.is_none() && !decimal_prices {
+            return Self::encode(data);
+        }
+        let data: Vec<&QuoteTick> = data.collect();
+        let mut arrays = Self::encode(data.iter().copied()).into_arrays();
+        if decimal_prices {
+            arrays[0] = decimal_price_array(data.iter().map(|quote| &quote.bid)).to_boxed();
+            arrays[1] = decimal_price_array(data.iter().map(|quote| &quote.ask)).to_boxed();
+        }
+        if let Some(dictionary) = dictionary {
+            let index_column: Vec<u32> = data
+                .iter()
+                .enumerate()
+                .map(|(i, quote)| {
+                    let index = dictionary_index(&dictionary, i, &quote.instrument_id)
+                        .expect("checked instrument dictionary");
+                    u32::try_from(index).expect("instrument dictionary exceeds `u32::MAX` entries")
+                })
+                .collect();
+            arrays.push(UInt32Array::from_vec(index_column).to_boxed());
+        }
+        Chunk::new(arrays)
+    }
 }
 
 impl DecodeFromChunk for QuoteTick {
-    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Vec<Self> {
-        let instrument_id =
-            InstrumentId::from(schema.metadata.get("instrument_id").unwrap().as_str());
-        let price_precision = schema
-            .metadata
-            .get("price_precision")
-            .unwrap()
-            .parse::<u8>()
-            .unwrap();
-        let size_precision = schema
-            .metadata
-            .get("size_precision")
-            .unwrap()
-            .parse::<u8>()
-            .unwrap();
-
-        // extract field value arrays from chunk separately
-        let bid_values = cols.arrays()[0]
-            .as_any()
-            .downcast_ref::<Int64Array>()
-            .unwrap();
-        let ask_values = cols.arrays()[1]
-            .as_any()
-            .downcast_ref::<Int64Array>()
-            .unwrap();
-        let ask_size_values = cols.arrays()[2]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
-        let bid_size_values = cols.arrays()[3]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
-        let ts_event_values = cols.arrays()[4]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
-        let ts_init_values = cols.arrays()[5]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
-
-        // construct iterator of values from field value arrays
-        let values = bid_values
-            .into_iter()
-            .zip(ask_values.into_iter())
-            .zip(ask_size_values.into_iter())
-            .zip(bid_size_values.into_iter())
-            .zip(ts_event_values.into_iter())
-            .zip(ts_init_values.into_iter())
-            .map(
-                |(((((bid, ask), ask_size), bid_size), ts_event), ts_init)| QuoteTick {
-                    instrument_id: instrument_id.clone(),
-                    bid: Price::from_raw(*bid.unwrap(), price_precision),
-                    ask: Price::from_raw(*ask.unwrap(), price_precision),
-                    bid_size: Quantity::from_raw(*bid_size.unwrap(), size_precision),
-                    ask_size: Quantity::from_raw(*ask_size.unwrap(), size_precision),
-                    ts_event: *ts_event.unwrap(),
-                    ts_init: *ts_init.unwrap(),
-                },
-            );
-
-        values.collect()
+    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Result<Vec<Self>> {
+        if let Some(dictionary) = instrument_dictionary(&schema.metadata)? {
+            return decode_multi(schema, &cols, &dictionary);
+        }
+        check_logical_types(schema, QUOTE_TICK_LOGICAL_TYPES)?;
+        let QuoteMetadata {
+            instrument_id,
+            price_precision,
+            size_precision,
+        } = QuoteMetadata::from_schema(schema)?;
+
+        // extract field value arrays from chunk by name, ignoring unknown columns
+        let columns = RawQuoteColumns::columns(schema, &cols, &|_| Ok(price_precision))?;
+
+        let values = (0..columns.len()).map(|i| QuoteTick {
+            instrument_id: instrument_id.clone(),
+            bid: Price::from_raw(columns.bid()[i], price_precision),
+            ask: Price::from_raw(columns.ask()[i], price_precision),
+            bid_size: Quantity::from_raw(columns.bid_size()[i], size_precision),
+            ask_size: Quantity::from_raw(columns.ask_size()[i], size_precision),
+            ts_event: columns.ts_event()[i],
+            ts_init: columns.ts_init()[i],
+        });
+
+        Ok(values.collect())
+    }
+}
+
+/// Decodes the quote ticks of a multi-instrument file, resolving the
+/// instrument and precisions of each row from its `instrument_index`.
+///
+/// # Errors
+/// - If a column is missing or not of the expected data type.
+/// - If an `instrument_index` is outside the instrument dictionary.
+fn decode_multi(
+    schema: &Schema,
+    cols: &Chunk<Box<dyn Array>>,
+    dictionary: &[InstrumentEntry],
+) -> Result<Vec<QuoteTick>> {
+    check_logical_types(schema, MULTI_QUOTE_TICK_LOGICAL_TYPES)?;
+    let index_values =
+        required_named_column::<UInt32Array>(schema, cols, "instrument_index", DataType::UInt32)?;
+    let entry = |i: usize| -> Result<&InstrumentEntry> {
+        let index = index_values.values()[i];
+        dictionary.get(index as usize).ok_or_else(|| {
+            let len = dictionary.len();
+            Error::OutOfSpec(format!(
+                "`instrument_index` {index} at row {i} is outside the dictionary of {len}"
+            ))
+            .into()
+        })
+    };
+    let columns = RawQuoteColumns::columns(schema, cols, &|i| Ok(entry(i)?.1))?;
+
+    (0..index_values.len())
+        .map(|i| {
+            let (instrument_id, price_precision, size_precision) = entry(i)?;
+            Ok(QuoteTick {
+                instrument_id: instrument_id.clone(),
+                bid: Price::from_raw(columns.bid()[i], *price_precision),
+                ask: Price::from_raw(columns.ask()[i], *price_precision),
+                bid_size: Quantity::from_raw(columns.bid_size()[i], *size_precision),
+                ask_size: Quantity::from_raw(columns.ask_size()[i], *size_precision),
+                ts_event: columns.ts_event()[i],
+                ts_init: columns.ts_init()[i],
+            })
+        })
+        .collect()
+}
+
+/// The raw values of the columns of a chunk of quote ticks, for numeric
+/// pipelines which do not need to construct `QuoteTick`s.
+///
+/// Prices and sizes are the fixed-point `raw` values of `Price` and
+/// `Quantity` at the precisions recorded in the schema metadata.
+#[derive(Clone, Debug)]
+pub struct RawQuoteColumns {
+    bid: Int64Array,
+    ask: Int64Array,
+    bid_size: UInt64Array,
+    ask_size: UInt64Array,
+    ts_event: UInt64Array,
+    ts_init: UInt64Array,
+}
+
+impl RawQuoteColumns {
+    /// Extracts the columns of a chunk of quote ticks by name, sharing their
+    /// buffers rather than copying.
+    ///
+    /// # Errors
+    /// - If the logical types of the schema are not those of quote ticks.
+    /// - If a column is missing or not of the expected data type.
+    pub(crate) fn from_chunk(schema: &Schema, cols: &Chunk<Box<dyn Array>>) -> Result<Self> {
+        check_logical_types(schema, QUOTE_TICK_LOGICAL_TYPES)?;
+        let price_precision = if decimal_price_encoding(&schema.metadata)? {
+            precision_metadata(&schema.metadata, "price_precision")?
+        } else {
+            FIXED_PRECISION
+        };
+        Self::columns(schema, cols, &|_| Ok(price_precision))
+    }
+
+    /// Extracts the columns of a chunk by name without checking the logical
+    /// types, for chunks with further columns such as `instrument_index`.
+    ///
+    /// Columns may be in any order and marked nullable if they have no nulls,
+    /// and timestamps may be `Int64`, as in files written by pyarrow. Prices
+    /// written as decimal strings are parsed back into raw values, with at
+    /// most the `price_precision` of each row decimal places.
+    fn columns(
+        schema: &Schema,
+        cols: &Chunk<Box<dyn Array>>,
+        price_precision: &dyn Fn(usize) -> Result<u8>,
+    ) -> Result<Self> {
+        Ok(RawQuoteColumns {
+            bid: price_named_column(schema, cols, "bid", price_precision)?,
+            ask: price_named_column(schema, cols, "ask", price_precision)?,
+            bid_size: required_named_column::<UInt64Array>(
+                schema,
+                cols,
+                "bid_size",
+                DataType::UInt64,
+            )?
+            .clone(),
+            ask_size: required_named_column::<UInt64Array>(
+                schema,
+                cols,
+                "ask_size",
+                DataType::UInt64,
+            )?
+            .clone(),
+            ts_event: timestamp_named_column(schema, cols, "ts_event")?,
+            ts_init: timestamp_named_column(schema, cols, "ts_init")?,
+        })
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ts_init.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn bid(&self) -> &[i64] {
+        self.bid.values()
+    }
+
+    #[must_use]
+    pub fn ask(&self) -> &[i64] {
+        self.ask.values()
+    }
+
+    #[must_use]
+    pub fn bid_size(&self) -> &[u64] {
+        self.bid_size.values()
+    }
+
+    #[must_use]
+    pub fn ask_size(&self) -> &[u64] {
+        self.ask_size.values()
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> &[u64] {
+        self.ts_event.values()
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> &[u64] {
+        self.ts_init.values()
+    }
+}
+
+/// A single row of a chunk of quote ticks, borrowing the chunk's columns and
+/// computing each `Price` and `Quantity` on demand rather than constructing
+/// a `QuoteTick`.
+#[derive(Clone, Copy, Debug)]
+pub struct QuoteRow<'a> {
+    columns: &'a RawQuoteColumns,
+    metadata: &'a QuoteMetadata,
+    index: usize,
+}
+
+impl<'a> QuoteRow<'a> {
+    pub(crate) fn new(
+        columns: &'a RawQuoteColumns,
+        metadata: &'a QuoteMetadata,
+        index: usize,
+    ) -> Self {
+        QuoteRow {
+            columns,
+            metadata,
+            index,
+        }
+    }
+
+    #[must_use]
+    pub fn instrument_id(&self) -> &'a InstrumentId {
+        &self.metadata.instrument_id
+    }
+
+    #[must_use]
+    pub fn bid(&self) -> Price {
+        Price::from_raw(
+            self.columns.bid()[self.index],
+            self.metadata.price_precision,
+        )
+    }
+
+    #[must_use]
+    pub fn ask(&self) -> Price {
+        Price::from_raw(
+            self.columns.ask()[self.index],
+            self.metadata.price_precision,
+        )
+    }
+
+    #[must_use]
+    pub fn bid_size(&self) -> Quantity {
+        Quantity::from_raw(
+            self.columns.bid_size()[self.index],
+            self.metadata.size_precision,
+        )
+    }
+
+    #[must_use]
+    pub fn ask_size(&self) -> Quantity {
+        Quantity::from_raw(
+            self.columns.ask_size()[self.index],
+            self.metadata.size_precision,
+        )
+    }
+
+    #[must_use]
+    pub fn ts_event(&self) -> u64 {
+        self.columns.ts_event()[self.index]
+    }
+
+    #[must_use]
+    pub fn ts_init(&self) -> u64 {
+        self.columns.ts_init()[self.index]
     }
 }
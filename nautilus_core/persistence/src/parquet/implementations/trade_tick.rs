@@ -19,17 +19,23 @@ use arrow2::{
     array::{Array, Int64Array, UInt64Array, UInt8Array, Utf8Array},
     chunk::Chunk,
     datatypes::{DataType, Field, Schema},
+    error::Error,
     io::parquet::write::{transverse, Encoding},
 };
 use nautilus_model::data::tick::TradeTick;
 use nautilus_model::enums::AggressorSide;
 use nautilus_model::identifiers::trade_id::TradeId;
-use nautilus_model::{
-    identifiers::instrument_id::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+use nautilus_model::types::{price::Price, quantity::Quantity};
+
+use super::{
+    check_logical_types, check_precision, check_price_bounds, check_quantity_bounds,
+    insert_logical_types, instrument_id_metadata, precision_metadata, required_column,
 };
+use crate::parquet::{DecodeFromChunk, EncodeToChunk, Result};
 
-use crate::parquet::{DecodeFromChunk, EncodeToChunk};
+/// The logical Nautilus type of each column of trade ticks.
+const TRADE_TICK_LOGICAL_TYPES: &str =
+    "price=Price,size=Quantity,aggressor_side=AggressorSide,trade_id=TradeId,ts_event=Timestamp,ts_init=Timestamp";
 
 impl EncodeToChunk for TradeTick {
     fn assert_metadata(metadata: &BTreeMap<String, String>) {
@@ -47,8 +53,9 @@ impl EncodeToChunk for TradeTick {
             .collect()
     }
 
-    fn encode_schema(metadata: BTreeMap<String, String>) -> Schema {
+    fn encode_schema(mut metadata: BTreeMap<String, String>) -> Schema {
         Self::assert_metadata(&metadata);
+        insert_logical_types(&mut metadata, TRADE_TICK_LOGICAL_TYPES);
         let fields = vec![
             Field::new("price", DataType::Int64, false),
             Field::new("size", DataType::UInt64, false),
@@ -61,6 +68,22 @@ impl EncodeToChunk for TradeTick {
         Schema::from(fields).with_metadata(metadata)
     }
 
+    fn check_precisions(price_precision: u8, size_precision: u8, data: &[Self]) -> Result<()> {
+        for (i, trade) in data.iter().enumerate() {
+            check_precision(i, "price", trade.price.precision, price_precision)?;
+            check_precision(i, "size", trade.size.precision, size_precision)?;
+        }
+        Ok(())
+    }
+
+    fn check_bounds(data: &[Self]) -> Result<()> {
+        for (i, trade) in data.iter().enumerate() {
+            check_price_bounds(i, "price", &trade.price)?;
+            check_quantity_bounds(i, "size", &trade.size)?;
+        }
+        Ok(())
+    }
+
     #[allow(clippy::type_complexity)]
     fn encode<'a, I>(data: I) -> Chunk<Box<dyn Array>>
     where
@@ -80,7 +103,7 @@ impl EncodeToChunk for TradeTick {
         data.fold((), |(), tick| {
             price_column.push(tick.price.raw);
             size_column.push(tick.size.raw);
-            aggressor_side_column.push(tick.aggressor_side as u8);
+            aggressor_side_column.push(u8::from(tick.aggressor_side));
             trade_id_column.push(&tick.trade_id.value);
             ts_event_column.push(tick.ts_event);
             ts_init_column.push(tick.ts_init);
@@ -104,66 +127,43 @@ impl EncodeToChunk for TradeTick {
 }
 
 impl DecodeFromChunk for TradeTick {
-    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Vec<Self> {
-        let instrument_id =
-            InstrumentId::from(schema.metadata.get("instrument_id").unwrap().as_str());
-        let price_precision = schema
-            .metadata
-            .get("price_precision")
-            .unwrap()
-            .parse::<u8>()
-            .unwrap();
-        let size_precision = schema
-            .metadata
-            .get("size_precision")
-            .unwrap()
-            .parse::<u8>()
-            .unwrap();
+    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Result<Vec<Self>> {
+        check_logical_types(schema, TRADE_TICK_LOGICAL_TYPES)?;
+        let instrument_id = instrument_id_metadata(&schema.metadata, "instrument_id")?;
+        let price_precision = precision_metadata(&schema.metadata, "price_precision")?;
+        let size_precision = precision_metadata(&schema.metadata, "size_precision")?;
 
         // extract field value arrays from chunk separately
-        let price_values = cols.arrays()[0]
-            .as_any()
-            .downcast_ref::<Int64Array>()
-            .unwrap();
-        let size_values = cols.arrays()[1]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
-        let aggressor_side_values = cols.arrays()[2]
-            .as_any()
-            .downcast_ref::<UInt8Array>()
-            .unwrap();
-        let trade_id_values_values = cols.arrays()[3]
-            .as_any()
-            .downcast_ref::<Utf8Array<i32>>()
-            .unwrap();
-        let ts_event_values = cols.arrays()[4]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
-        let ts_init_values = cols.arrays()[5]
-            .as_any()
-            .downcast_ref::<UInt64Array>()
-            .unwrap();
+        let price_values = required_column::<Int64Array>(&cols, 0, "price", DataType::Int64)?;
+        let size_values = required_column::<UInt64Array>(&cols, 1, "size", DataType::UInt64)?;
+        let aggressor_side_values =
+            required_column::<UInt8Array>(&cols, 2, "aggressor_side", DataType::UInt8)?;
+        let trade_id_values =
+            required_column::<Utf8Array<i32>>(&cols, 3, "trade_id", DataType::Utf8)?;
+        let ts_event_values =
+            required_column::<UInt64Array>(&cols, 4, "ts_event", DataType::UInt64)?;
+        let ts_init_values = required_column::<UInt64Array>(&cols, 5, "ts_init", DataType::UInt64)?;
 
         // construct iterator of values from field value arrays
         let values = price_values
-            .into_iter()
-            .zip(size_values.into_iter())
-            .zip(aggressor_side_values.into_iter())
-            .zip(trade_id_values_values.into_iter())
-            .zip(ts_event_values.into_iter())
-            .zip(ts_init_values.into_iter())
+            .values_iter()
+            .zip(size_values.values_iter())
+            .zip(aggressor_side_values.values_iter())
+            .zip(trade_id_values.values_iter())
+            .zip(ts_event_values.values_iter())
+            .zip(ts_init_values.values_iter())
             .map(
-                |(((((price, size), aggressor_side), trade_id), ts_event), ts_init)| TradeTick {
-                    instrument_id: instrument_id.clone(),
-                    price: Price::from_raw(*price.unwrap(), price_precision),
-                    size: Quantity::from_raw(*size.unwrap(), size_precision),
-                    aggressor_side: AggressorSide::from_repr(*aggressor_side.unwrap() as usize)
-                        .expect("cannot parse enum value"),
-                    trade_id: TradeId::new(trade_id.unwrap()),
-                    ts_event: *ts_event.unwrap(),
-                    ts_init: *ts_init.unwrap(),
+                |(((((&price, &size), &aggressor_side), trade_id), &ts_event), &ts_init)| {
+                    Ok(TradeTick {
+                        instrument_id: instrument_id.clone(),
+                        price: Price::from_raw(price, price_precision),
+                        size: Quantity::from_raw(size, size_precision),
+                        aggressor_side: AggressorSide::try_from(aggressor_side)
+                            .map_err(Error::OutOfSpec)?,
+                        trade_id: TradeId::new(trade_id),
+                        ts_event,
+                        ts_init,
+                    })
                 },
             );
 
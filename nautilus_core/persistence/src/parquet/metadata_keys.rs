@@ -0,0 +1,86 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+
+/// The schema metadata key names of the instrument and precisions of a
+/// single-instrument file, for systems expecting other names such as
+/// `symbol` rather than `instrument_id`.
+///
+/// Writers and readers map these names to and from the default names used
+/// when encoding and decoding, so a file must be read with the same keys it
+/// was written with. Other keys, such as those of an instrument dictionary,
+/// are not renamed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetadataKeys {
+    pub instrument_id: String,
+    pub price_precision: String,
+    pub size_precision: String,
+}
+
+impl Default for MetadataKeys {
+    fn default() -> Self {
+        MetadataKeys {
+            instrument_id: "instrument_id".to_string(),
+            price_precision: "price_precision".to_string(),
+            size_precision: "size_precision".to_string(),
+        }
+    }
+}
+
+impl MetadataKeys {
+    /// Returns `metadata` with the default key names renamed to these keys,
+    /// as written to a file.
+    #[must_use]
+    pub fn to_file_metadata(
+        &self,
+        metadata: &BTreeMap<String, String>,
+    ) -> BTreeMap<String, String> {
+        let default = MetadataKeys::default();
+        rename_keys(metadata, &default, self)
+    }
+
+    /// Returns `metadata` read from a file with these keys renamed to the
+    /// default key names, as expected when decoding.
+    #[must_use]
+    pub fn to_default_metadata(
+        &self,
+        metadata: &BTreeMap<String, String>,
+    ) -> BTreeMap<String, String> {
+        let default = MetadataKeys::default();
+        rename_keys(metadata, self, &default)
+    }
+}
+
+/// Returns `metadata` with each of the `from` keys renamed to the matching
+/// `to` key, removing all before inserting any so keys may be swapped.
+fn rename_keys(
+    metadata: &BTreeMap<String, String>,
+    from: &MetadataKeys,
+    to: &MetadataKeys,
+) -> BTreeMap<String, String> {
+    let mut metadata = metadata.clone();
+    let renames = [
+        (&from.instrument_id, &to.instrument_id),
+        (&from.price_precision, &to.price_precision),
+        (&from.size_precision, &to.size_precision),
+    ];
+    let values: Vec<(String, String)> = renames
+        .iter()
+        .filter_map(|(from, to)| metadata.remove(*from).map(|value| ((*to).clone(), value)))
+        .collect();
+    metadata.extend(values);
+    metadata
+}
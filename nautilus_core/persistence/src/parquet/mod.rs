@@ -13,7 +13,14 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+mod atomic_writer;
+mod channel_reader;
+mod directory_index;
+mod error;
 mod implementations;
+mod metadata_keys;
+mod multi_reader;
+mod partitioned_writer;
 mod reader;
 mod writer;
 
@@ -22,8 +29,28 @@ use std::collections::BTreeMap;
 use arrow2::{array::Array, chunk::Chunk, datatypes::Schema, io::parquet::write::Encoding};
 use pyo3::prelude::*;
 
-pub use crate::parquet::reader::{GroupFilterArg, ParquetReader};
-pub use crate::parquet::writer::ParquetWriter;
+use crate::parquet::implementations::parse_metadata;
+
+pub use crate::parquet::atomic_writer::AtomicParquetWriter;
+pub use crate::parquet::channel_reader::ChannelReader;
+pub use crate::parquet::directory_index::index_directory;
+pub use crate::parquet::error::{ParquetError, Result};
+pub use crate::parquet::implementations::{
+    QuoteMetadata, QuoteRow, RawQuoteColumns, DECIMAL_PRICE_ENCODING, PRICE_ENCODING_KEY,
+    RAW_PRICE_ENCODING,
+};
+pub use crate::parquet::metadata_keys::MetadataKeys;
+pub use crate::parquet::multi_reader::MultiFileQuoteReader;
+pub use crate::parquet::partitioned_writer::PartitionedQuoteWriter;
+pub use crate::parquet::reader::{FileSummary, GroupFilterArg, ParquetReader, QuoteRows};
+pub use crate::parquet::writer::{
+    multi_instrument_quote_tick_schema, quote_tick_schema, quote_tick_schema_json,
+    write_quote_ticks_to_bytes, ParquetWriter, DEFAULT_WRITE_OPTIONS,
+};
+
+/// The version of the schema layout supported by the readers, files with a
+/// `schema_version` metadata key must match it.
+pub const SCHEMA_VERSION: &str = "1";
 
 #[repr(C)]
 #[pyclass]
@@ -45,7 +72,11 @@ pub trait DecodeFromChunk
 where
     Self: Sized,
 {
-    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Vec<Self>;
+    /// Decodes the columns of a chunk read with the given schema.
+    ///
+    /// # Errors
+    /// - If a column is missing or not of the expected data type.
+    fn decode(schema: &Schema, cols: Chunk<Box<dyn Array>>) -> Result<Vec<Self>>;
 }
 
 pub trait EncodeToChunk
@@ -60,6 +91,41 @@ where
     /// Creates a schema using the given metadata for the given Struct
     /// ! Panics if metadata is not in the required shape.
     fn encode_schema(metadata: BTreeMap<String, String>) -> Schema;
+    /// Checks every element of the batch has the given price and size
+    /// precisions, as the schema can only record one of each.
+    ///
+    /// # Errors
+    /// - If any element has a precision which differs from those given.
+    fn check_precisions(price_precision: u8, size_precision: u8, data: &[Self]) -> Result<()>;
+    /// Checks the precisions of the batch agree with the schema `metadata`
+    /// it is written with, by default the `price_precision` and
+    /// `size_precision` keys with [`EncodeToChunk::check_precisions`].
+    ///
+    /// # Errors
+    /// - If a precision key is missing or not a `u8`.
+    /// - If any element has a precision the metadata cannot record.
+    fn check_metadata_precisions(metadata: &BTreeMap<String, String>, data: &[Self]) -> Result<()> {
+        let price_precision = parse_metadata(metadata, "price_precision")?;
+        let size_precision = parse_metadata(metadata, "size_precision")?;
+        Self::check_precisions(price_precision, size_precision, data)
+    }
+    /// Checks no element of the batch is a crossed quote with its bid above
+    /// its ask, data types without a bid and ask are never crossed.
+    ///
+    /// # Errors
+    /// - If any element is crossed.
+    fn check_not_crossed(_data: &[Self]) -> Result<()> {
+        Ok(())
+    }
+    /// Checks every price and size of the batch is within the range of its
+    /// type, as raw values from `from_raw` are not validated, data types
+    /// without such values are always in range.
+    ///
+    /// # Errors
+    /// - If any value is out of range.
+    fn check_bounds(_data: &[Self]) -> Result<()> {
+        Ok(())
+    }
     /// This is the most general type of an encoder. It only needs an iterator
     /// of references it does not require ownership of the data, nor for
     /// the data to be collected in a container.
@@ -67,4 +133,18 @@ where
     where
         I: Iterator<Item = &'a Self>,
         Self: 'a;
+    /// Encodes the batch for a file with the schema `metadata`, for columns
+    /// which depend on the metadata, by default with [`EncodeToChunk::encode`].
+    ///
+    /// The batch must have passed [`EncodeToChunk::check_metadata_precisions`].
+    fn encode_with_metadata<'a, I>(
+        _metadata: &BTreeMap<String, String>,
+        data: I,
+    ) -> Chunk<Box<dyn Array>>
+    where
+        I: Iterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        Self::encode(data)
+    }
 }
@@ -0,0 +1,109 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{Read, Seek};
+use std::vec::IntoIter;
+
+use nautilus_model::data::tick::QuoteTick;
+
+use super::{GroupFilterArg, ParquetError, ParquetReader, Result};
+
+/// Reads quote ticks from several parquet sources as a single stream
+/// ordered by `ts_init`.
+///
+/// Each source must be individually sorted by `ts_init`. Ticks with equal
+/// `ts_init` are yielded in the order of their sources. A source whose next
+/// chunk cannot be read or decoded yields the error and then ends, while the
+/// other sources continue.
+pub struct MultiFileQuoteReader<R = File>
+where
+    R: Read + Seek,
+{
+    readers: Vec<ParquetReader<QuoteTick, R>>,
+    chunks: Vec<IntoIter<QuoteTick>>,
+    heads: Vec<Option<QuoteTick>>,
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+    // the errors of sources which ended, yielded before the next tick
+    errors: Vec<ParquetError>,
+}
+
+impl<R> MultiFileQuoteReader<R>
+where
+    R: Read + Seek,
+{
+    /// Opens a [`ParquetReader`] for each source, reading chunks of
+    /// `chunk_size` rows at a time.
+    ///
+    /// # Errors
+    /// - If any of the underlying readers cannot be created.
+    pub fn new(sources: Vec<R>, chunk_size: usize) -> Result<Self> {
+        let readers = sources
+            .into_iter()
+            .map(|source| ParquetReader::new(source, chunk_size, GroupFilterArg::None))
+            .collect::<Result<Vec<_>>>()?;
+        let len = readers.len();
+        let mut multi_reader = MultiFileQuoteReader {
+            readers,
+            chunks: (0..len).map(|_| Vec::new().into_iter()).collect(),
+            heads: vec![None; len],
+            heap: BinaryHeap::with_capacity(len),
+            errors: Vec::new(),
+        };
+        for i in 0..len {
+            multi_reader.advance(i);
+        }
+        Ok(multi_reader)
+    }
+
+    /// Loads the next tick of the source at `index` as its head and
+    /// schedules it on the heap.
+    fn advance(&mut self, index: usize) {
+        loop {
+            if let Some(tick) = self.chunks[index].next() {
+                self.heap.push(Reverse((tick.ts_init, index)));
+                self.heads[index] = Some(tick);
+                return;
+            }
+            match self.readers[index].next() {
+                Some(Ok(chunk)) => self.chunks[index] = chunk.into_iter(),
+                Some(Err(e)) => {
+                    self.errors.push(e);
+                    return;
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+impl<R> Iterator for MultiFileQuoteReader<R>
+where
+    R: Read + Seek,
+{
+    type Item = Result<QuoteTick>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.errors.pop() {
+            return Some(Err(e));
+        }
+        let Reverse((_, index)) = self.heap.pop()?;
+        let tick = self.heads[index].take();
+        self.advance(index);
+        tick.map(Ok)
+    }
+}
@@ -0,0 +1,99 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use arrow2::datatypes::Schema;
+use nautilus_core::datetime::unix_nanos_to_iso8601;
+use nautilus_model::data::tick::QuoteTick;
+
+use super::{AtomicParquetWriter, EncodeToChunk, Result};
+
+/// Writes quote ticks into one parquet file per UTC day of their `ts_init`,
+/// named `YYYY-MM-DD.parquet` within a directory.
+///
+/// A writer is opened per day the first time a tick for that day is seen and
+/// kept open until [`PartitionedQuoteWriter::finalize`] is called, day files
+/// are written via a temporary file so only appear once complete.
+pub struct PartitionedQuoteWriter {
+    dir: PathBuf,
+    schema: Schema,
+    writers: HashMap<String, AtomicParquetWriter<QuoteTick>>,
+}
+
+impl PartitionedQuoteWriter {
+    /// Creates a new writer into `dir` using the given schema `metadata`.
+    ///
+    /// # Panics
+    /// - If `metadata` is missing a required key.
+    #[must_use]
+    pub fn new<P: AsRef<Path>>(dir: P, metadata: BTreeMap<String, String>) -> Self {
+        PartitionedQuoteWriter {
+            dir: dir.as_ref().to_path_buf(),
+            schema: QuoteTick::encode_schema(metadata),
+            writers: HashMap::new(),
+        }
+    }
+
+    /// Routes each tick to the file for its `ts_init` day, writing each
+    /// day's ticks from `data` as a single row group.
+    ///
+    /// # Errors
+    /// - If a day file cannot be created or written to.
+    pub fn write(&mut self, data: &[QuoteTick]) -> Result<()> {
+        let mut partitions: BTreeMap<String, Vec<QuoteTick>> = BTreeMap::new();
+        for tick in data {
+            partitions
+                .entry(partition_key(tick.ts_init))
+                .or_default()
+                .push(tick.clone());
+        }
+
+        for (day, ticks) in partitions {
+            let writer = match self.writers.get_mut(&day) {
+                Some(writer) => writer,
+                None => {
+                    let path = self.dir.join(format!("{day}.parquet"));
+                    let writer = AtomicParquetWriter::create(path, self.schema.clone())?;
+                    self.writers.entry(day).or_insert(writer)
+                }
+            };
+            writer.write(&ticks)?;
+        }
+        Ok(())
+    }
+
+    /// Ends all open writers, returning the paths of the written files in
+    /// day order.
+    ///
+    /// # Errors
+    /// - If a day file cannot be ended, the remaining day files are then
+    ///   not written.
+    pub fn finalize(self) -> Result<Vec<PathBuf>> {
+        let mut writers: Vec<(String, AtomicParquetWriter<QuoteTick>)> =
+            self.writers.into_iter().collect();
+        writers.sort_by(|(a, _), (b, _)| a.cmp(b));
+        writers
+            .into_iter()
+            .map(|(_day, writer)| writer.end())
+            .collect()
+    }
+}
+
+/// Returns the UTC day of `ts_init` formatted as `YYYY-MM-DD`.
+fn partition_key(ts_init: u64) -> String {
+    unix_nanos_to_iso8601(ts_init)[..10].to_string()
+}
@@ -14,18 +14,33 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::cmp::Ordering;
-use std::collections::HashSet;
-use std::io::{Read, Seek};
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io::{Cursor, Read, Seek};
+use std::iter;
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::thread;
 
-use arrow2::array::UInt64Array;
-use arrow2::io::parquet::read::{self, RowGroupMetaData};
+use arrow2::array::{Array, BooleanArray, UInt64Array};
+use arrow2::chunk::Chunk;
+use arrow2::compute::filter::filter_chunk;
+use arrow2::datatypes::Schema;
+use arrow2::error::Error;
+use arrow2::io::parquet::read::{self, read_columns_many, RowGroupDeserializer, RowGroupMetaData};
 use arrow2::io::parquet::write::FileMetaData;
-use arrow2::{datatypes::Schema, io::parquet::read::FileReader};
+use memmap2::Mmap;
+use nautilus_model::data::tick::QuoteTick;
+use nautilus_model::identifiers::instrument_id::InstrumentId;
 use pyo3::types::PyInt;
 use pyo3::FromPyObject;
 
-use super::DecodeFromChunk;
+use super::channel_reader::ChannelReader;
+use super::implementations::instrument_dictionary;
+use super::{
+    DecodeFromChunk, MetadataKeys, ParquetError, QuoteMetadata, QuoteRow, RawQuoteColumns, Result,
+    SCHEMA_VERSION,
+};
 
 #[repr(C)]
 /// Filter groups based on a field's metadata values.
@@ -81,7 +96,8 @@ impl GroupFilterArg {
                         .iter()
                         .enumerate()
                         .filter_map(|(i, ts_group_min)| {
-                            let min = ts_group_min.unwrap_or(&u64::MAX);
+                            // groups without statistics are always selected
+                            let min = ts_group_min.unwrap_or(&0);
                             if min < limit {
                                 Some(i)
                             } else {
@@ -117,6 +133,7 @@ impl GroupFilterArg {
                         .iter()
                         .enumerate()
                         .filter_map(|(i, ts_group_max)| {
+                            // groups without statistics are always selected
                             let max = ts_group_max.unwrap_or(&u64::MAX);
                             if max > limit {
                                 Some(i)
@@ -141,43 +158,574 @@ impl GroupFilterArg {
     }
 }
 
+/// A summary of a parquet file read from its metadata, see
+/// [`ParquetReader::summary`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSummary {
+    pub num_rows: usize,
+    /// The minimum `ts_init`, `None` if there are no row group statistics.
+    pub ts_init_min: Option<u64>,
+    /// The maximum `ts_init`, `None` if there are no row group statistics.
+    pub ts_init_max: Option<u64>,
+    pub instrument_ids: Vec<InstrumentId>,
+    /// The path of the file, `None` unless summarized by `index_directory`.
+    pub path: Option<PathBuf>,
+}
+
+/// Reads and decodes a parquet file in chunks.
+///
+/// The file footer is parsed once on creation, so the metadata methods such
+/// as [`ParquetReader::summary`] do not read from the underlying reader.
 pub struct ParquetReader<A, R>
 where
     R: Read + Seek,
 {
-    file_reader: FileReader<R>,
-    reader_type: PhantomData<*const A>,
+    reader: R,
+    chunk_size: usize,
+    // the group being read and the index of the next group to read
+    current_group: Option<RowGroupDeserializer>,
+    next_group: usize,
+    // the file schema, with its metadata keys mapped to the default names
+    schema: Schema,
+    // the metadata of the selected groups, kept for `summary`
+    row_groups: Vec<RowGroupMetaData>,
+    stride: usize,
+    // rows of the selected groups outside `[skip_rows, end_row)` are filtered out
+    skip_rows: usize,
+    end_row: usize,
+    rows_read: usize,
+    // `fn() -> A` keeps the reader `Send` when `R` is, whatever `A` is
+    reader_type: PhantomData<fn() -> A>,
 }
 
 impl<A, R> ParquetReader<A, R>
 where
     R: Read + Seek,
 {
-    #[must_use]
-    pub fn new(mut reader: R, chunk_size: usize, filter_arg: GroupFilterArg) -> Self {
-        let metadata = read::read_metadata(&mut reader).expect("Unable to read metadata");
-        let schema = read::infer_schema(&metadata).expect("Unable to infer schema");
+    /// Creates a new reader yielding chunks of at most `chunk_size` rows.
+    ///
+    /// The chunk size trades memory for throughput: each chunk is fully
+    /// decoded into a `Vec<A>` before being yielded, so larger chunks mean
+    /// fewer iterations and less per-chunk overhead at the cost of holding
+    /// more decoded rows in memory at once.
+    ///
+    /// # Errors
+    /// - If `chunk_size` is zero.
+    /// - If the parquet metadata or schema cannot be read.
+    /// - If the `schema_version` of the file is not `SCHEMA_VERSION`.
+    pub fn new(reader: R, chunk_size: usize, filter_arg: GroupFilterArg) -> Result<Self> {
+        Self::new_with_metadata_keys(reader, chunk_size, filter_arg, &MetadataKeys::default())
+    }
+
+    /// Creates a new reader of a file whose instrument and precision keys
+    /// were written under the names of `metadata_keys`, see [`MetadataKeys`].
+    ///
+    /// The keys are renamed to their default names in [`ParquetReader::schema`],
+    /// as expected when decoding.
+    ///
+    /// # Errors
+    /// - If `chunk_size` is zero.
+    /// - If the parquet metadata or schema cannot be read.
+    /// - If the `schema_version` of the file is not `SCHEMA_VERSION`.
+    pub fn new_with_metadata_keys(
+        mut reader: R,
+        chunk_size: usize,
+        filter_arg: GroupFilterArg,
+        metadata_keys: &MetadataKeys,
+    ) -> Result<Self> {
+        check_chunk_size(chunk_size)?;
+        let (metadata, mut schema) = read_schema(&mut reader)?;
+        schema.metadata = metadata_keys.to_default_metadata(&schema.metadata);
         let row_groups = filter_arg.selected_groups(metadata, &schema);
-        let fr = FileReader::new(reader, row_groups, schema, Some(chunk_size), None, None);
+        Ok(Self::from_row_groups(
+            reader, row_groups, schema, chunk_size,
+        ))
+    }
+
+    /// Creates a new reader yielding only the rows `[offset, offset + limit)`
+    /// of the file, such as for paginated inspection.
+    ///
+    /// Row groups entirely outside the slice are skipped using the row counts
+    /// in the file metadata, so only the boundary groups are read in full and
+    /// trimmed before decoding.
+    ///
+    /// # Errors
+    /// - If `chunk_size` is zero.
+    /// - If the parquet metadata or schema cannot be read.
+    /// - If the `schema_version` of the file is not `SCHEMA_VERSION`.
+    pub fn slice(mut reader: R, chunk_size: usize, offset: usize, limit: usize) -> Result<Self> {
+        check_chunk_size(chunk_size)?;
+        let (metadata, schema) = read_schema(&mut reader)?;
+        let end = offset.saturating_add(limit);
+        let mut group_start = 0;
+        let mut skip_rows = 0;
+        let mut row_groups = Vec::new();
+        for row_group in metadata.row_groups {
+            let group_end = group_start + row_group.num_rows();
+            if group_end > offset && group_start < end {
+                if row_groups.is_empty() {
+                    skip_rows = offset - group_start;
+                }
+                row_groups.push(row_group);
+            }
+            group_start = group_end;
+        }
+        let mut parquet_reader = Self::from_row_groups(reader, row_groups, schema, chunk_size);
+        parquet_reader.skip_rows = skip_rows;
+        parquet_reader.end_row = skip_rows.saturating_add(limit);
+        Ok(parquet_reader)
+    }
+
+    fn from_row_groups(
+        reader: R,
+        row_groups: Vec<RowGroupMetaData>,
+        schema: Schema,
+        chunk_size: usize,
+    ) -> Self {
         ParquetReader {
-            file_reader: fr,
+            reader,
+            chunk_size,
+            current_group: None,
+            next_group: 0,
+            schema,
+            row_groups,
+            stride: 1,
+            skip_rows: 0,
+            end_row: usize::MAX,
+            rows_read: 0,
             reader_type: PhantomData,
         }
     }
 }
 
+/// Reads the column chunks of `row_group`, returning a deserializer yielding
+/// chunks of at most `chunk_size` rows.
+fn read_group<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    schema: &Schema,
+    chunk_size: usize,
+) -> Result<RowGroupDeserializer> {
+    let num_rows = row_group.num_rows();
+    let columns = read_columns_many(
+        reader,
+        row_group,
+        schema.fields.clone(),
+        Some(chunk_size),
+        None,
+        None,
+    )?;
+    Ok(RowGroupDeserializer::new(columns, num_rows, None))
+}
+
+/// Returns the non-null values of the `ts_init` statistics of each row group.
+fn downcast_statistics(values: &dyn Array) -> Result<impl Iterator<Item = u64> + '_> {
+    let values = values
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .ok_or_else(|| Error::OutOfSpec("`ts_init` statistics are not `UInt64`".to_string()))?;
+    Ok(values.iter().flatten().copied())
+}
+
+fn check_chunk_size(chunk_size: usize) -> Result<()> {
+    if chunk_size == 0 {
+        return Err(Error::InvalidArgumentError(
+            "`chunk_size` must be greater than zero".to_string(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Reads the metadata and schema of the file, checking its schema version.
+fn read_schema<R: Read + Seek>(reader: &mut R) -> Result<(FileMetaData, Schema)> {
+    let metadata = read::read_metadata(reader)?;
+    let schema = read::infer_schema(&metadata)?;
+    if let Some(found) = schema.metadata.get("schema_version") {
+        if found != SCHEMA_VERSION {
+            return Err(ParquetError::SchemaVersionMismatch {
+                expected: SCHEMA_VERSION.to_string(),
+                found: found.clone(),
+            });
+        }
+    }
+    Ok((metadata, schema))
+}
+
+impl<A, R> ParquetReader<A, R>
+where
+    R: Read + Seek,
+{
+    /// Returns the number of row groups selected for reading.
+    #[must_use]
+    pub fn num_row_groups(&self) -> usize {
+        self.row_groups.len()
+    }
+
+    /// Returns the number of rows in the row groups selected for reading,
+    /// summed from the file footer without decoding any rows.
+    ///
+    /// Like [`ParquetReader::summary`] this covers whole row groups, so
+    /// ignores any slice or stride.
+    #[must_use]
+    pub fn total_rows(&self) -> usize {
+        self.row_groups.iter().map(RowGroupMetaData::num_rows).sum()
+    }
+
+    /// Returns a summary of the row groups selected for reading, taken from
+    /// the file metadata and row group statistics without decoding any rows.
+    ///
+    /// The summary covers whole row groups, so ignores any slice or stride.
+    /// The instruments are taken from the instrument dictionary of
+    /// multi-instrument files in dictionary order, otherwise from the
+    /// `instrument_id` metadata key.
+    ///
+    /// # Errors
+    /// - If the `ts_init` statistics cannot be read.
+    /// - If the instrument metadata is not a valid `InstrumentId`.
+    pub fn summary(&self) -> Result<FileSummary> {
+        let num_rows = self.total_rows();
+        let mut ts_init_min = None;
+        let mut ts_init_max = None;
+        let ts_init_field = self
+            .schema()
+            .fields
+            .iter()
+            .find(|field| field.name == "ts_init");
+        if let (Some(field), false) = (ts_init_field, self.row_groups.is_empty()) {
+            let statistics = read::statistics::deserialize(field, &self.row_groups)?;
+            ts_init_min = downcast_statistics(statistics.min_value.as_ref())?.min();
+            ts_init_max = downcast_statistics(statistics.max_value.as_ref())?.max();
+        }
+        let metadata = &self.schema().metadata;
+        let instrument_ids = match (
+            instrument_dictionary(metadata)?,
+            metadata.get("instrument_id"),
+        ) {
+            (Some(dictionary), _) => dictionary.into_iter().map(|(id, _, _)| id).collect(),
+            (None, Some(value)) => vec![value.parse::<InstrumentId>().map_err(Error::OutOfSpec)?],
+            (None, None) => Vec::new(),
+        };
+        Ok(FileSummary {
+            num_rows,
+            ts_init_min,
+            ts_init_max,
+            instrument_ids,
+            path: None,
+        })
+    }
+
+    /// Returns the number of nulls in each column of the row groups selected
+    /// for reading, summed from the row group statistics without decoding
+    /// any rows.
+    ///
+    /// Like [`ParquetReader::summary`] this covers whole row groups. Columns
+    /// with a row group lacking null count statistics are omitted, rather
+    /// than reported with an unknown count.
+    #[must_use]
+    pub fn null_counts(&self) -> BTreeMap<String, usize> {
+        self.schema()
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let statistics = read::statistics::deserialize(field, &self.row_groups).ok()?;
+                let null_counts = statistics
+                    .null_count
+                    .as_any()
+                    .downcast_ref::<UInt64Array>()?;
+                let total = null_counts
+                    .iter()
+                    .map(|count| count.map(|count| *count as usize))
+                    .sum::<Option<usize>>()?;
+                Some((field.name.clone(), total))
+            })
+            .collect()
+    }
+
+    /// Returns the schema of the file, including its metadata.
+    ///
+    /// Metadata keys of a reader created with
+    /// [`ParquetReader::new_with_metadata_keys`] are renamed to their default
+    /// names.
+    #[must_use]
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Sets the reader to yield only every `stride`th row, starting from the
+    /// first row read (or of the slice), such as for previewing large files.
+    ///
+    /// Skipped rows are filtered out of each chunk before it is decoded. The
+    /// stride applies to the rows of the row groups selected by the filter
+    /// the reader was created with.
+    ///
+    /// # Errors
+    /// - If `stride` is zero.
+    pub fn with_stride(mut self, stride: usize) -> Result<Self> {
+        if stride == 0 {
+            return Err(Error::InvalidArgumentError(
+                "`stride` must be greater than zero".to_string(),
+            )
+            .into());
+        }
+        self.stride = stride;
+        Ok(self)
+    }
+
+    /// Filters `chunk` to the rows within the slice at multiples of the
+    /// stride, counting from the first row read.
+    fn filter_rows(&mut self, chunk: Chunk<Box<dyn Array>>) -> Result<Chunk<Box<dyn Array>>> {
+        let offset = self.rows_read;
+        self.rows_read += chunk.len();
+        if self.stride == 1 && offset >= self.skip_rows && self.rows_read <= self.end_row {
+            return Ok(chunk);
+        }
+        let (stride, skip_rows, end_row) = (self.stride, self.skip_rows, self.end_row);
+        let mask: BooleanArray = (offset..self.rows_read)
+            .map(|row| Some(row >= skip_rows && row < end_row && (row - skip_rows) % stride == 0))
+            .collect();
+        Ok(filter_chunk(&chunk, &mask)?)
+    }
+
+    /// Reads the next chunk of the selected row groups, reading the column
+    /// chunks of each group as the previous one is exhausted.
+    fn next_file_chunk(&mut self) -> Option<Result<Chunk<Box<dyn Array>>>> {
+        loop {
+            if let Some(chunk) = self.current_group.as_mut().and_then(Iterator::next) {
+                return Some(chunk.map_err(Into::into));
+            }
+            let row_group = self.row_groups.get(self.next_group)?;
+            self.next_group += 1;
+            match read_group(&mut self.reader, row_group, &self.schema, self.chunk_size) {
+                Ok(group) => self.current_group = Some(group),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Reads the next chunk with the slice and stride applied, skipping
+    /// chunks left with no rows.
+    fn next_chunk(&mut self) -> Option<Result<Chunk<Box<dyn Array>>>> {
+        loop {
+            if self.rows_read >= self.end_row {
+                return None;
+            }
+            let chunk = match self.next_file_chunk()? {
+                Ok(chunk) => chunk,
+                Err(e) => return Some(Err(e)),
+            };
+            let num_rows = chunk.len();
+            let chunk = match self.filter_rows(chunk) {
+                Ok(chunk) => chunk,
+                Err(e) => return Some(Err(e)),
+            };
+            if chunk.is_empty() && num_rows > 0 {
+                continue;
+            }
+            return Some(Ok(chunk));
+        }
+    }
+}
+
+impl<A, R> ParquetReader<A, R>
+where
+    A: DecodeFromChunk,
+    R: Read + Seek,
+{
+    /// Returns an iterator over the individual decoded items, decoding each
+    /// chunk lazily as the previous one is exhausted.
+    ///
+    /// A chunk which cannot be read or decoded is yielded as a single error.
+    pub fn flat_iter(self) -> impl Iterator<Item = Result<A>> {
+        self.flat_map(|chunk| {
+            let (data, error) = match chunk {
+                Ok(data) => (data, None),
+                Err(e) => (Vec::new(), Some(Err(e))),
+            };
+            data.into_iter().map(Ok).chain(error)
+        })
+    }
+
+    /// Reads and decodes only the row group at `index` of the selected row
+    /// groups, seeking past the preceding groups rather than reading them,
+    /// such as for inspecting a suspect group.
+    ///
+    /// The group is read in full, ignoring any slice or stride, and does not
+    /// advance the iteration of the reader.
+    ///
+    /// # Errors
+    /// - If `index` is not less than [`ParquetReader::num_row_groups`].
+    /// - If the row group cannot be read or decoded into `A`.
+    pub fn read_row_group(&mut self, index: usize) -> Result<Vec<A>> {
+        let Some(row_group) = self.row_groups.get(index) else {
+            return Err(Error::InvalidArgumentError(format!(
+                "row group index {index} out of range for {} row groups",
+                self.row_groups.len()
+            ))
+            .into());
+        };
+        let chunk_size = row_group.num_rows().max(1);
+        let mut data = Vec::new();
+        for chunk in read_group(&mut self.reader, row_group, &self.schema, chunk_size)? {
+            data.extend(A::decode(&self.schema, chunk?)?);
+        }
+        Ok(data)
+    }
+}
+
+impl<A, R> ParquetReader<A, R>
+where
+    A: DecodeFromChunk + 'static,
+    R: Read + Seek + Send + 'static,
+{
+    /// Moves the reader onto a new thread which reads ahead of the consumer,
+    /// returning a [`ChannelReader`] that yields the decoded items.
+    ///
+    /// The thread sends raw chunks over a bounded channel holding at most
+    /// `capacity` chunks, blocking once it is full so a slow consumer applies
+    /// backpressure rather than buffering the whole file. Chunks are decoded
+    /// on the consumer side as the identifiers of decoded data are reference
+    /// counted and cannot be sent between threads.
+    ///
+    /// A read error is sent as the final message, after which the thread
+    /// exits. Dropping the [`ChannelReader`] also stops the thread.
+    #[must_use]
+    pub fn into_channel(mut self, capacity: usize) -> ChannelReader<A> {
+        let schema = self.schema().clone();
+        let (sender, receiver) = crossbeam_channel::bounded(capacity);
+        thread::spawn(move || {
+            while let Some(chunk) = self.next_chunk() {
+                let is_err = chunk.is_err();
+                // the send only fails once the receiver has been dropped
+                if sender.send(chunk).is_err() || is_err {
+                    break;
+                }
+            }
+        });
+        ChannelReader::new(receiver, schema)
+    }
+}
+
+impl<R> ParquetReader<QuoteTick, R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over the raw columns of each chunk, avoiding the
+    /// construction of a `QuoteTick` per row for numeric pipelines.
+    ///
+    /// The slice and stride of the reader apply as for decoded reads.
+    pub fn raw_columns(mut self) -> impl Iterator<Item = Result<RawQuoteColumns>> {
+        iter::from_fn(move || {
+            let chunk = self.next_chunk()?;
+            Some(chunk.and_then(|chunk| RawQuoteColumns::from_chunk(self.schema(), &chunk)))
+        })
+    }
+
+    /// Returns the rows of the file as [`QuoteRow`]s which compute their
+    /// prices on demand from the columns of the current chunk, for streaming
+    /// transforms which do not need a `QuoteTick` per row.
+    ///
+    /// The slice and stride of the reader apply as for decoded reads.
+    ///
+    /// # Errors
+    /// - If the schema metadata has no single instrument and precisions, as
+    ///   for multi-instrument files.
+    pub fn rows(self) -> Result<QuoteRows<R>> {
+        let metadata = QuoteMetadata::from_schema(self.schema())?;
+        Ok(QuoteRows {
+            reader: self,
+            metadata,
+            columns: None,
+            index: 0,
+        })
+    }
+}
+
+/// The rows of a reader of quote ticks, see [`ParquetReader::rows`].
+///
+/// Each row borrows the columns of the current chunk, so is only valid until
+/// the next row is read, which is why this is not an `Iterator`.
+pub struct QuoteRows<R>
+where
+    R: Read + Seek,
+{
+    reader: ParquetReader<QuoteTick, R>,
+    metadata: QuoteMetadata,
+    columns: Option<RawQuoteColumns>,
+    index: usize,
+}
+
+impl<R> QuoteRows<R>
+where
+    R: Read + Seek,
+{
+    /// Returns the next row, reading the next chunk once the current one is
+    /// exhausted, or `None` once all rows have been read.
+    ///
+    /// # Errors
+    /// - If a chunk cannot be read or its columns are not those of quote
+    ///   ticks.
+    pub fn next_row(&mut self) -> Option<Result<QuoteRow<'_>>> {
+        while !matches!(&self.columns, Some(columns) if self.index < columns.len()) {
+            let columns = self
+                .reader
+                .next_chunk()?
+                .and_then(|chunk| RawQuoteColumns::from_chunk(self.reader.schema(), &chunk));
+            match columns {
+                Ok(columns) => self.columns = Some(columns),
+                Err(e) => return Some(Err(e)),
+            }
+            self.index = 0;
+        }
+        let columns = self.columns.as_ref()?;
+        let row = QuoteRow::new(columns, &self.metadata, self.index);
+        self.index += 1;
+        Some(Ok(row))
+    }
+}
+
+impl<'a, A> ParquetReader<A, Cursor<&'a [u8]>> {
+    /// Creates a new reader over parquet data held in memory, such as a
+    /// downloaded blob, without needing to write it to a file first.
+    ///
+    /// # Errors
+    /// - If `chunk_size` is zero.
+    /// - If the parquet metadata or schema cannot be read.
+    pub fn from_bytes(bytes: &'a [u8], chunk_size: usize) -> Result<Self> {
+        Self::new(Cursor::new(bytes), chunk_size, GroupFilterArg::None)
+    }
+}
+
+impl<A> ParquetReader<A, Cursor<Mmap>> {
+    /// Creates a new reader over the memory mapped file at `path`, avoiding
+    /// the read syscalls of a `File` based reader for large files.
+    ///
+    /// # Safety
+    /// The file must not be truncated or otherwise modified while mapped, as
+    /// this is undefined behavior (typically a `SIGBUS` on access).
+    ///
+    /// # Errors
+    /// - If the file cannot be opened or mapped.
+    /// - If `chunk_size` is zero.
+    /// - If the parquet metadata or schema cannot be read.
+    pub unsafe fn from_mmap<P: AsRef<Path>>(path: P, chunk_size: usize) -> Result<Self> {
+        let file = File::open(path)?;
+        let mmap = Mmap::map(&file)?;
+        Self::new(Cursor::new(mmap), chunk_size, GroupFilterArg::None)
+    }
+}
+
 impl<A, R> Iterator for ParquetReader<A, R>
 where
     A: DecodeFromChunk,
     R: Read + Seek,
 {
-    type Item = Vec<A>;
+    type Item = Result<Vec<A>>;
 
+    /// Yields the decoded items of each chunk, or the error of a chunk which
+    /// cannot be read or decoded into `A`.
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(Ok(chunk)) = self.file_reader.next() {
-            Some(A::decode(self.file_reader.schema(), chunk))
-        } else {
-            None
-        }
+        let chunk = self.next_chunk()?;
+        Some(chunk.and_then(|chunk| A::decode(self.schema(), chunk)))
     }
 }
@@ -13,94 +13,589 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::io::Write;
+use std::collections::{BTreeMap, HashSet};
+use std::io::{Cursor, Write};
 use std::marker::PhantomData;
 
 use arrow2::{
-    datatypes::Schema,
-    error::Result,
+    array::{new_null_array, Array},
+    chunk::Chunk,
+    datatypes::{DataType, Schema},
+    error::Error,
     io::parquet::write::{
-        CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+        can_encode, transverse, CompressionOptions, Encoding, FileWriter, RowGroupIterator,
+        Version, WriteOptions,
     },
 };
 
-use super::EncodeToChunk;
+use nautilus_model::data::{tick::QuoteTick, GetTsInit};
+use nautilus_model::identifiers::instrument_id::InstrumentId;
+use serde_json::json;
 
+use super::implementations::insert_instrument_dictionary;
+use super::{EncodeToChunk, MetadataKeys, ParquetError, Result};
+
+/// The options of [`ParquetWriter::new`], writing uncompressed V2 data pages
+/// with row group statistics.
+pub const DEFAULT_WRITE_OPTIONS: WriteOptions = WriteOptions {
+    write_statistics: true,
+    compression: CompressionOptions::Uncompressed,
+    version: Version::V2,
+    data_pagesize_limit: None,
+};
+
+/// Writes data of type `A` as a parquet file to `W`.
+///
+/// The file is ended, writing its footer, by [`ParquetWriter::end`] or
+/// [`ParquetWriter::flush`]. If the writer is dropped without being ended the
+/// file is ended on drop, with any error logged, so the written row groups
+/// are still readable. Ending the file explicitly is still preferred as `end`
+/// propagates errors to the caller.
 pub struct ParquetWriter<A, W>
 where
     W: Write,
 {
-    pub writer: FileWriter<W>,
+    // `None` once the file is ended
+    writer: Option<FileWriter<W>>,
     pub encodings: Vec<Vec<Encoding>>,
     pub options: WriteOptions,
+    /// If each written batch must be sorted by non-decreasing `ts_init`.
+    pub validate_sorted: bool,
+    /// If each written batch is stably sorted by `ts_init` before encoding,
+    /// keeping the input order of equal timestamps, rather than requiring
+    /// sorted input.
+    ///
+    /// `write` sorts references to the batch, allocating a pointer per
+    /// element, and `write_streaming` sorts each owned chunk in place, where
+    /// the stable sort allocates a buffer of up to half the chunk.
+    pub sort_on_write: bool,
+    /// If written quotes with a bid above their ask are rejected, for data
+    /// types with a bid and ask.
+    pub reject_crossed: bool,
+    /// The maximum rows per row group, batches are split into multiple row
+    /// groups when they exceed this limit.
+    pub max_rows_per_group: Option<usize>,
+    /// If set, the `instrument_id` of the schema metadata must be one of
+    /// these known instruments for writes to succeed.
+    pub instrument_registry: Option<HashSet<InstrumentId>>,
+    /// For each field of a target schema, the index of the encoded column
+    /// written to it, or the data type of the nulls it is padded with.
+    column_map: Option<Vec<ColumnSource>>,
+    /// The names of the instrument and precision keys in the file metadata.
+    metadata_keys: MetadataKeys,
     pub parquet_type: PhantomData<*const A>,
 }
 
 impl<'a, A, W> ParquetWriter<A, W>
 where
-    A: EncodeToChunk + 'a + Sized,
+    A: EncodeToChunk + GetTsInit + 'a + Sized,
     W: Write,
 {
     #[must_use]
     pub fn new(w: W, schema: Schema) -> Self {
-        let options = WriteOptions {
-            write_statistics: true,
-            compression: CompressionOptions::Uncompressed,
-            version: Version::V2,
-            data_pagesize_limit: None,
-        };
+        Self::new_with_options(w, schema, DEFAULT_WRITE_OPTIONS)
+    }
+
+    /// Creates a writer with the given write `options`, rather than
+    /// [`DEFAULT_WRITE_OPTIONS`].
+    ///
+    /// Turning `write_statistics` off saves CPU when writing throwaway files,
+    /// but readers can then no longer skip row groups by their `ts_init`
+    /// range, so a `GroupFilterArg` selects every group and `summary` has no
+    /// time bounds.
+    ///
+    /// Some older readers, such as older versions of Spark, only support
+    /// `Version::V1` data pages. V1 files can be read by any reader but lose
+    /// the V2 page header improvements, such as readers being able to skip
+    /// decompressing the repetition and definition levels.
+    #[must_use]
+    pub fn new_with_options(w: W, schema: Schema, options: WriteOptions) -> Self {
+        Self::from_parts(w, schema, options, MetadataKeys::default())
+    }
+
+    /// Creates a writer of the instrument and precision keys of `schema`
+    /// under the names of `metadata_keys` in the file metadata, which must
+    /// then be read with the same keys, see [`MetadataKeys`].
+    ///
+    /// The `schema` has the default key names, as expected when encoding.
+    #[must_use]
+    pub fn new_with_metadata_keys(w: W, schema: Schema, metadata_keys: MetadataKeys) -> Self {
+        Self::from_parts(w, schema, DEFAULT_WRITE_OPTIONS, metadata_keys)
+    }
+
+    fn from_parts(
+        w: W,
+        mut schema: Schema,
+        options: WriteOptions,
+        metadata_keys: MetadataKeys,
+    ) -> Self {
         let encodings = A::encodings(schema.metadata.clone());
-        let writer = FileWriter::try_new(w, schema, options).unwrap();
+        schema.metadata = metadata_keys.to_file_metadata(&schema.metadata);
+        let writer = Some(FileWriter::try_new(w, schema, options).unwrap());
 
         ParquetWriter {
             writer,
             encodings,
             options,
+            validate_sorted: false,
+            sort_on_write: false,
+            reject_crossed: false,
+            max_rows_per_group: None,
+            instrument_registry: None,
+            column_map: None,
+            metadata_keys,
             parquet_type: PhantomData,
         }
     }
 
+    /// Creates a writer for a `target` schema which may have more fields than
+    /// the encoded data, for interop with schemas having optional fields.
+    ///
+    /// Encoded columns are written to the target fields of the same name and
+    /// target fields without a matching column are padded with nulls.
+    ///
+    /// # Errors
+    /// - If a target field without a matching column is not nullable.
+    /// - If a matching column has a different data type to its target field.
+    /// - If an encoded column has no target field of the same name.
+    pub fn new_with_target_schema(w: W, target: Schema) -> Result<Self> {
+        let source = A::encode_schema(target.metadata.clone());
+        let mut column_map = Vec::with_capacity(target.fields.len());
+        for field in &target.fields {
+            match source.fields.iter().position(|f| f.name == field.name) {
+                Some(i) if source.fields[i].data_type == field.data_type => {
+                    column_map.push(ColumnSource::Column(i));
+                }
+                Some(i) => {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "column `{}` of data type {:?} differs from target {:?}",
+                        field.name, source.fields[i].data_type, field.data_type
+                    ))
+                    .into())
+                }
+                None if field.is_nullable => {
+                    column_map.push(ColumnSource::Nulls(field.data_type.clone()));
+                }
+                None => {
+                    return Err(Error::InvalidArgumentError(format!(
+                        "target field `{}` is not nullable and has no matching column",
+                        field.name
+                    ))
+                    .into())
+                }
+            }
+        }
+        if let Some(field) = source
+            .fields
+            .iter()
+            .find(|f| !target.fields.iter().any(|t| t.name == f.name))
+        {
+            return Err(Error::InvalidArgumentError(format!(
+                "column `{}` is not in the target schema",
+                field.name
+            ))
+            .into());
+        }
+
+        let mut writer = Self::new(w, target);
+        // encoded columns keep the encodings of `A`, padded columns are plain
+        writer.encodings = column_map
+            .iter()
+            .map(|source| match source {
+                ColumnSource::Column(i) => writer.encodings[*i].clone(),
+                ColumnSource::Nulls(data_type) => transverse(data_type, |_| Encoding::Plain),
+            })
+            .collect();
+        writer.column_map = Some(column_map);
+        Ok(writer)
+    }
+
     #[must_use]
     pub fn new_buffer_writer(schema: Schema) -> ParquetWriter<A, Vec<u8>> {
         ParquetWriter::new(Vec::new(), schema)
     }
 
+    /// Sets the encoding of each column by field name, columns not in
+    /// `encodings` fall back to `Encoding::Plain`.
+    ///
+    /// For example monotonic timestamp columns are far more compact when
+    /// written with `Encoding::DeltaBinaryPacked`.
+    ///
+    /// # Errors
+    /// - If an encoding is not supported for the data type of its column.
+    pub fn set_column_encodings(&mut self, encodings: &BTreeMap<String, Encoding>) -> Result<()> {
+        let fields = &self.schema().fields;
+        let mut column_encodings = Vec::with_capacity(fields.len());
+        for field in fields {
+            let encoding = encodings
+                .get(&field.name)
+                .copied()
+                .unwrap_or(Encoding::Plain);
+            if !can_encode(&field.data_type, encoding) {
+                return Err(Error::InvalidArgumentError(format!(
+                    "cannot encode column `{}` of data type {:?} as {encoding:?}",
+                    field.name, field.data_type
+                ))
+                .into());
+            }
+            column_encodings.push(transverse(&field.data_type, |_| encoding));
+        }
+        self.encodings = column_encodings;
+        Ok(())
+    }
+
+    /// Returns the schema metadata with the default key names, as expected
+    /// when encoding.
+    fn default_metadata(&self) -> BTreeMap<String, String> {
+        self.metadata_keys
+            .to_default_metadata(&self.schema().metadata)
+    }
+
+    /// Writes `data` as one row group, or several of at most
+    /// `max_rows_per_group` rows if set.
+    ///
+    /// An empty batch writes no row group, as for empty chunks of
+    /// [`ParquetWriter::write_streaming`].
     pub fn write(&mut self, data: &[A]) -> Result<()> {
-        let cols = A::encode(data.iter());
-        let iter = vec![Ok(cols)];
-        let row_groups = RowGroupIterator::try_new(
-            iter.into_iter(),
-            self.writer.schema(),
-            self.options,
-            self.encodings.clone(),
-        )?;
+        self.check_registered()?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        let metadata = self.default_metadata();
+        A::check_metadata_precisions(&metadata, data)?;
+        A::check_bounds(data)?;
+        let sorted: Option<Vec<&A>> = self.sort_on_write.then(|| {
+            let mut sorted: Vec<&A> = data.iter().collect();
+            sorted.sort_by_key(|item| item.get_ts_init());
+            sorted
+        });
+        if self.validate_sorted && sorted.is_none() {
+            check_sorted(data)?;
+        }
+        if self.reject_crossed {
+            A::check_not_crossed(data)?;
+        }
+        let group_size = match self.max_rows_per_group {
+            Some(0) => {
+                return Err(Error::InvalidArgumentError(
+                    "`max_rows_per_group` must be greater than zero".to_string(),
+                )
+                .into())
+            }
+            Some(max_rows) => max_rows,
+            None => data.len(),
+        };
+        check_unique_names(self.schema())?;
+        let num_fields = self.schema().fields.len();
+        let column_map = self.column_map.clone();
+        let iter = (0..data.len()).step_by(group_size).map(|start| {
+            let end = data.len().min(start + group_size);
+            let encoded = match &sorted {
+                Some(sorted) => {
+                    A::encode_with_metadata(&metadata, sorted[start..end].iter().copied())
+                }
+                None => A::encode_with_metadata(&metadata, data[start..end].iter()),
+            };
+            let cols = map_columns(column_map.as_deref(), encoded);
+            check_column_count(num_fields, &cols).map_err(into_arrow_error)?;
+            Ok(cols)
+        });
+        let row_groups =
+            RowGroupIterator::try_new(iter, self.schema(), self.options, self.encodings.clone())?;
 
         for group in row_groups {
-            self.writer.write(group?)?;
+            self.file_writer().write(group?)?;
         }
         Ok(())
     }
 
+    /// Writes each chunk of `data_stream` as a row group, encoding chunks
+    /// lazily as they are pulled from the stream.
+    ///
+    /// Empty chunks are skipped, so write no row group.
     pub fn write_streaming<I>(&mut self, data_stream: I) -> Result<()>
     where
         I: Iterator<Item = Vec<A>>,
     {
-        let chunk_stream = data_stream.map(|chunk| Ok(A::encode(chunk.iter())));
+        self.check_registered()?;
+        let validate_sorted = self.validate_sorted && !self.sort_on_write;
+        let sort_on_write = self.sort_on_write;
+        let reject_crossed = self.reject_crossed;
+        check_unique_names(self.schema())?;
+        let num_fields = self.schema().fields.len();
+        let column_map = self.column_map.clone();
+        let metadata = self.default_metadata();
+        let chunk_stream = data_stream
+            .filter(|chunk| !chunk.is_empty())
+            .map(|mut chunk| {
+                if sort_on_write {
+                    chunk.sort_by_key(GetTsInit::get_ts_init);
+                }
+                let encode = || {
+                    A::check_metadata_precisions(&metadata, &chunk)?;
+                    A::check_bounds(&chunk)?;
+                    if validate_sorted {
+                        check_sorted(&chunk)?;
+                    }
+                    if reject_crossed {
+                        A::check_not_crossed(&chunk)?;
+                    }
+                    let cols = map_columns(
+                        column_map.as_deref(),
+                        A::encode_with_metadata(&metadata, chunk.iter()),
+                    );
+                    check_column_count(num_fields, &cols)?;
+                    Ok(cols)
+                };
+                encode().map_err(into_arrow_error)
+            });
         let row_groups = RowGroupIterator::try_new(
             chunk_stream,
-            self.writer.schema(),
+            self.schema(),
             self.options,
             self.encodings.clone(),
         )?;
 
         for group in row_groups {
-            self.writer.write(group?)?;
+            self.file_writer().write(group?)?;
         }
         Ok(())
     }
 
+    /// Checks the `instrument_id` of the schema metadata is in the
+    /// instrument registry, if one is set.
+    fn check_registered(&self) -> Result<()> {
+        let Some(registry) = &self.instrument_registry else {
+            return Ok(());
+        };
+        let key = &self.metadata_keys.instrument_id;
+        let value = self.schema().metadata.get(key).ok_or_else(|| {
+            Error::InvalidArgumentError(format!("metadata missing key \"{key}\""))
+        })?;
+        if registry.contains(&InstrumentId::from(value.as_str())) {
+            Ok(())
+        } else {
+            Err(Error::InvalidArgumentError(format!(
+                "unknown `instrument_id` '{value}' not in instrument registry"
+            ))
+            .into())
+        }
+    }
+
+    /// Ends the file, writing its footer, and returns the inner writer.
+    ///
+    /// An error ending the file is logged and otherwise ignored, so the
+    /// returned writer may hold an incomplete file, use
+    /// [`ParquetWriter::end`] to handle the error.
     pub fn flush(mut self) -> W {
-        let _size = self.writer.end(None);
-        self.writer.into_inner()
+        let mut writer = self.take_file_writer();
+        if let Err(e) = writer.end(None) {
+            log::error!("Failed to end parquet file on flush: {e}");
+        }
+        writer.into_inner()
+    }
+
+    /// Ends the file, writing its footer, and returns the inner writer.
+    ///
+    /// Unlike [`ParquetWriter::flush`] this returns any error ending the file.
+    ///
+    /// # Errors
+    /// - If the footer cannot be written.
+    pub fn end(mut self) -> Result<W> {
+        let mut writer = self.take_file_writer();
+        writer.end(None)?;
+        Ok(writer.into_inner())
+    }
+
+    fn schema(&self) -> &Schema {
+        self.writer
+            .as_ref()
+            .expect("writer is only taken when ended")
+            .schema()
+    }
+
+    fn file_writer(&mut self) -> &mut FileWriter<W> {
+        self.writer
+            .as_mut()
+            .expect("writer is only taken when ended")
+    }
+
+    /// Takes the file writer to end the file, so it is not ended again on drop.
+    fn take_file_writer(&mut self) -> FileWriter<W> {
+        self.writer.take().expect("writer is only taken when ended")
+    }
+}
+
+impl<A, W> Drop for ParquetWriter<A, W>
+where
+    W: Write,
+{
+    fn drop(&mut self) {
+        if let Some(mut writer) = self.writer.take() {
+            if let Err(e) = writer.end(None) {
+                log::error!("Failed to end parquet file on drop: {e}");
+            }
+        }
+    }
+}
+
+/// Returns the canonical parquet schema for quote ticks of the given
+/// instrument, with the instrument id and precisions as schema metadata.
+#[must_use]
+pub fn quote_tick_schema(
+    instrument_id: &InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+) -> Schema {
+    QuoteTick::encode_schema(BTreeMap::from([
+        ("instrument_id".to_string(), instrument_id.to_string()),
+        ("price_precision".to_string(), price_precision.to_string()),
+        ("size_precision".to_string(), size_precision.to_string()),
+    ]))
+}
+
+/// Returns the parquet schema for quote ticks of multiple instruments, with
+/// the ordered instrument dictionary of `(instrument_id, price_precision,
+/// size_precision)` stored in the metadata as `instrument_0`,
+/// `instrument_0_price_precision`, `instrument_0_size_precision` and so on.
+///
+/// Each row records the index of its instrument in an `instrument_index`
+/// column, and every written quote must have the precisions of its instrument.
+///
+/// # Panics
+/// - If `instruments` is empty.
+#[must_use]
+pub fn multi_instrument_quote_tick_schema(instruments: &[(InstrumentId, u8, u8)]) -> Schema {
+    assert!(!instruments.is_empty(), "`instruments` was empty");
+    let mut metadata = BTreeMap::new();
+    insert_instrument_dictionary(&mut metadata, instruments);
+    QuoteTick::encode_schema(metadata)
+}
+
+/// Returns the canonical parquet schema for quote ticks of the given
+/// instrument as JSON, so other languages can check they agree on the schema
+/// by comparing strings.
+///
+/// The JSON holds the `fields` in column order, each with its `name`, arrow
+/// `data_type` and `nullable`, and the `metadata` sorted by key.
+#[must_use]
+pub fn quote_tick_schema_json(
+    instrument_id: &InstrumentId,
+    price_precision: u8,
+    size_precision: u8,
+) -> String {
+    let schema = quote_tick_schema(instrument_id, price_precision, size_precision);
+    let fields: Vec<serde_json::Value> = schema
+        .fields
+        .iter()
+        .map(|field| {
+            json!({
+                "name": field.name,
+                "data_type": format!("{:?}", field.data_type),
+                "nullable": field.is_nullable,
+            })
+        })
+        .collect();
+    // `BTreeMap` serializes in key order
+    json!({ "fields": fields, "metadata": schema.metadata }).to_string()
+}
+
+/// Writes `ticks` as parquet into an in-memory buffer, so the output can be
+/// uploaded to object storage without going through the local filesystem.
+pub fn write_quote_ticks_to_bytes(
+    ticks: &[QuoteTick],
+    metadata: BTreeMap<String, String>,
+) -> Result<Vec<u8>> {
+    let schema = QuoteTick::encode_schema(metadata);
+    let mut writer: ParquetWriter<QuoteTick, Cursor<Vec<u8>>> =
+        ParquetWriter::new(Cursor::new(Vec::new()), schema);
+    writer.write(ticks)?;
+    Ok(writer.flush().into_inner())
+}
+
+/// The source of the values of a field of a target schema.
+#[derive(Clone)]
+enum ColumnSource {
+    /// The encoded column at the given index.
+    Column(usize),
+    /// Nulls of the given data type.
+    Nulls(DataType),
+}
+
+/// Maps the encoded `cols` onto the fields of a target schema, returning
+/// `cols` unchanged if there is no `column_map`.
+fn map_columns(
+    column_map: Option<&[ColumnSource]>,
+    cols: Chunk<Box<dyn Array>>,
+) -> Chunk<Box<dyn Array>> {
+    let Some(column_map) = column_map else {
+        return cols;
+    };
+    let len = cols.len();
+    let arrays = column_map
+        .iter()
+        .map(|source| match source {
+            ColumnSource::Column(i) => cols.arrays()[*i].clone(),
+            ColumnSource::Nulls(data_type) => new_null_array(data_type.clone(), len),
+        })
+        .collect();
+    Chunk::new(arrays)
+}
+
+/// Converts `error` for returning through the arrow2 row group iterator,
+/// arrow2 errors are unwrapped so they convert back unchanged.
+fn into_arrow_error(error: ParquetError) -> Error {
+    match error {
+        ParquetError::Arrow(e) => e,
+        e => Error::External(String::new(), Box::new(e)),
+    }
+}
+
+/// Checks all field names of `schema` are unique.
+fn check_unique_names(schema: &Schema) -> Result<()> {
+    let mut names = HashSet::with_capacity(schema.fields.len());
+    match schema
+        .fields
+        .iter()
+        .find(|field| !names.insert(&field.name))
+    {
+        Some(field) => Err(Error::InvalidArgumentError(format!(
+            "schema has duplicate column name `{}`",
+            field.name
+        ))
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// Checks the encoded `cols` has an array for each of the `num_fields`
+/// fields of the schema.
+fn check_column_count(num_fields: usize, cols: &Chunk<Box<dyn Array>>) -> Result<()> {
+    if cols.arrays().len() == num_fields {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgumentError(format!(
+            "chunk has {} columns but schema has {num_fields} fields",
+            cols.arrays().len(),
+        ))
+        .into())
+    }
+}
+
+/// Checks `data` is sorted by non-decreasing `ts_init`, returning an error
+/// identifying the first out of order index.
+fn check_sorted<A: GetTsInit>(data: &[A]) -> Result<()> {
+    match data
+        .windows(2)
+        .position(|w| w[1].get_ts_init() < w[0].get_ts_init())
+    {
+        Some(i) => Err(Error::InvalidArgumentError(format!(
+            "data not sorted by `ts_init` at index {}, {} was less than previous {}",
+            i + 1,
+            data[i + 1].get_ts_init(),
+            data[i].get_ts_init(),
+        ))
+        .into()),
+        None => Ok(()),
     }
 }
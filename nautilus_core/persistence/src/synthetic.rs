@@ -0,0 +1,75 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::tick::QuoteTick,
+    identifiers::instrument_id::InstrumentId,
+    types::{price::Price, quantity::Quantity},
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The price precision of generated quotes.
+pub const PRICE_PRECISION: u8 = 5;
+/// The size precision of generated quotes.
+pub const SIZE_PRECISION: u8 = 0;
+
+/// The raw value of one tick of 0.00001 at the fixed precision of 9.
+const TICK_RAW: i64 = 10_000;
+/// The raw value of one unit of size at the fixed precision of 9.
+const SIZE_UNIT_RAW: u64 = 1_000_000_000;
+/// The initial mid price of 1.00000 as a number of ticks.
+const INITIAL_MID_TICKS: i64 = 100_000;
+/// The lowest mid price as a number of ticks, so bids stay positive.
+const MIN_MID_TICKS: i64 = 1_000;
+/// The `ts_init` of the first quote, 2020-01-01 UTC.
+const INITIAL_TS: u64 = 1_577_836_800_000_000_000;
+
+/// Generates `count` quotes of `instrument_id` as a random walk from a mid
+/// price of 1.00000, reproducible for the same `seed`.
+///
+/// Prices have a precision of [`PRICE_PRECISION`] with spreads of one to three
+/// ticks, sizes have a precision of [`SIZE_PRECISION`] in lots of 100,000 and
+/// `ts_event` and `ts_init` increase by up to one second per quote.
+///
+/// The output is reproducible for a given version of `rand`.
+#[must_use]
+pub fn generate_quotes(instrument_id: InstrumentId, count: usize, seed: u64) -> Vec<QuoteTick> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut mid_ticks = INITIAL_MID_TICKS;
+    let mut ts = INITIAL_TS;
+    (0..count)
+        .map(|_| {
+            mid_ticks = (mid_ticks + rng.gen_range(-2..=2)).max(MIN_MID_TICKS);
+            let spread_ticks = rng.gen_range(1..=3);
+            let bid_ticks = mid_ticks - spread_ticks / 2;
+            ts += rng.gen_range(1..=1_000_000_000);
+            QuoteTick {
+                instrument_id: instrument_id.clone(),
+                bid: Price::from_raw(bid_ticks * TICK_RAW, PRICE_PRECISION),
+                ask: Price::from_raw((bid_ticks + spread_ticks) * TICK_RAW, PRICE_PRECISION),
+                bid_size: Quantity::from_raw(
+                    rng.gen_range(1..=10) * 100_000 * SIZE_UNIT_RAW,
+                    SIZE_PRECISION,
+                ),
+                ask_size: Quantity::from_raw(
+                    rng.gen_range(1..=10) * 100_000 * SIZE_UNIT_RAW,
+                    SIZE_PRECISION,
+                ),
+                ts_event: ts,
+                ts_init: ts,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,164 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Write;
+
+use nautilus_model::{
+    data::tick::QuoteTick,
+    types::{price::Price, quantity::Quantity},
+};
+use nautilus_persistence::csv::{
+    read_quote_ticks_csv, write_quote_ticks_csv_streaming, CsvQuoteConfig, TimeResolution,
+};
+use nautilus_persistence::parquet::{quote_tick_schema, ParquetReader, ParquetWriter};
+use rstest::rstest;
+
+#[rstest]
+#[case(TimeResolution::Secs, "1577898000")]
+#[case(TimeResolution::Millis, "1577898000000")]
+#[case(TimeResolution::Micros, "1577898000000000")]
+#[case(TimeResolution::Nanos, "1577898000000000000")]
+fn test_read_quote_ticks_csv_with_time_resolution(
+    #[case] resolution: TimeResolution,
+    #[case] timestamp: &str,
+) {
+    let csv = format!("{timestamp},1.12120,1.12172\n");
+    let mut config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    config.time_resolution = Some(resolution);
+
+    let ticks = read_quote_ticks_csv(csv.as_bytes(), &config).unwrap();
+
+    assert_eq!(ticks.len(), 1);
+    assert_eq!(ticks[0].ts_event, 1_577_898_000_000_000_000);
+    assert_eq!(ticks[0].ts_init, 1_577_898_000_000_000_000);
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_formatted_timestamp() {
+    let csv = "2020-01-01 17:00:00.000,1.12120,1.12172\n";
+    let config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+
+    let ticks = read_quote_ticks_csv(csv.as_bytes(), &config).unwrap();
+
+    assert_eq!(ticks[0].ts_init, 1_577_898_000_000_000_000);
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_time_resolution_overflow_errors() {
+    let csv = "1577898000000000000,1.12120,1.12172\n";
+    let mut config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    config.time_resolution = Some(TimeResolution::Secs);
+
+    assert!(read_quote_ticks_csv(csv.as_bytes(), &config).is_err());
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_short_row_strict_errors() {
+    let csv = "2020-01-01 17:00:00.000,1.12120,1.12172\n2020-01-01 17:00:01.000,1.12121\n";
+    let config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+
+    let err = read_quote_ticks_csv(csv.as_bytes(), &config).unwrap_err();
+
+    assert!(err.to_string().contains("line 2 has 2 fields"), "{err}");
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_short_row_lenient_skips() {
+    let csv = "2020-01-01 17:00:00.000,1.12120,1.12172\n\
+               2020-01-01 17:00:01.000\n\
+               2020-01-01 17:00:02.000,1.12122,1.12174\n";
+    let mut config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    config.strict = false;
+
+    let ticks = read_quote_ticks_csv(csv.as_bytes(), &config).unwrap();
+
+    assert_eq!(ticks.len(), 2);
+    assert_eq!(ticks[1].ts_init, 1_577_898_002_000_000_000);
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_nan_bid_errors() {
+    let csv = "2020-01-01 17:00:00.000,1.12120,1.12172\n2020-01-01 17:00:01.000,nan,1.12172\n";
+    let config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+
+    let err = read_quote_ticks_csv(csv.as_bytes(), &config).unwrap_err();
+
+    assert!(err.to_string().contains("line 2 has price 'nan'"), "{err}");
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_negative_size_errors() {
+    let csv = "2020-01-01 17:00:00.000,1.12120,1.12172,-1\n";
+    let mut config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    config.bid_size_col = Some(3);
+
+    let err = read_quote_ticks_csv(csv.as_bytes(), &config).unwrap_err();
+
+    assert!(err.to_string().contains("line 1 has size '-1'"), "{err}");
+}
+
+#[test]
+fn test_read_quote_ticks_csv_with_precision_over_maximum_errors() {
+    let csv = "2020-01-01 17:00:00.000,1.12120,1.12172\n";
+    let config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 10, 0);
+
+    assert!(read_quote_ticks_csv(csv.as_bytes(), &config).is_err());
+}
+
+#[test]
+fn test_write_quote_ticks_csv_streaming() {
+    let num_rows = 100_000;
+    let mut csv = String::new();
+    for i in 0..num_rows {
+        writeln!(
+            csv,
+            "{i},1.{:05},2.{:05},{}",
+            i % 100_000,
+            i % 100_000,
+            i % 7
+        )
+        .unwrap();
+    }
+    let mut config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    config.time_resolution = Some(TimeResolution::Secs);
+    config.bid_size_col = Some(3);
+    let schema = quote_tick_schema(&config.instrument_id, 5, 0);
+    let mut writer = ParquetWriter::<QuoteTick, _>::new(Vec::new(), schema);
+
+    let written =
+        write_quote_ticks_csv_streaming(csv.as_bytes(), &config, &mut writer, 30_000).unwrap();
+    let buffer = writer.flush();
+
+    assert_eq!(written, num_rows);
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10_000).unwrap();
+    assert_eq!(reader.num_row_groups(), 4);
+    assert_eq!(reader.total_rows(), num_rows);
+    let ticks: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(ticks.len(), num_rows);
+    let tick = &ticks[54_321];
+    assert_eq!(tick.bid, Price::new(1.54321, 5));
+    assert_eq!(tick.ask, Price::new(2.54321, 5));
+    assert_eq!(tick.bid_size, Quantity::new(1.0, 0));
+    assert_eq!(tick.ts_init, 54_321_000_000_000);
+}
+
+#[test]
+fn test_write_quote_ticks_csv_streaming_with_zero_batch_size_errors() {
+    let config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    let schema = quote_tick_schema(&config.instrument_id, 5, 0);
+    let mut writer = ParquetWriter::<QuoteTick, _>::new(Vec::new(), schema);
+
+    assert!(write_quote_ticks_csv_streaming("".as_bytes(), &config, &mut writer, 0).is_err());
+}
@@ -0,0 +1,274 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::io;
+
+use arrow2::{
+    array::{UInt64Array, Utf8Array},
+    chunk::Chunk,
+    datatypes::DataType,
+    error::Error,
+};
+use nautilus_model::data::delta::OrderBookDelta;
+use nautilus_model::data::tick::{QuoteTick, TradeTick};
+use nautilus_model::types::{price::Price, quantity::Quantity};
+use nautilus_persistence::parquet::{
+    multi_instrument_quote_tick_schema, quote_tick_schema, DecodeFromChunk, EncodeToChunk,
+    ParquetError, ParquetReader, ParquetWriter, QuoteMetadata, DECIMAL_PRICE_ENCODING,
+    PRICE_ENCODING_KEY,
+};
+
+#[test]
+fn test_arrow_error_display() {
+    let err = ParquetError::from(Error::OutOfSpec("bad footer".to_string()));
+
+    assert_eq!(err.to_string(), "arrow error: bad footer");
+}
+
+#[test]
+fn test_io_error_display() {
+    let err = ParquetError::from(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+
+    assert_eq!(err.to_string(), "I/O error: no such file");
+}
+
+#[test]
+fn test_schema_version_mismatch_display() {
+    let err = ParquetError::SchemaVersionMismatch {
+        expected: "1".to_string(),
+        found: "2".to_string(),
+    };
+
+    assert_eq!(err.to_string(), "schema version mismatch, expected 1 was 2");
+}
+
+#[test]
+fn test_logical_type_mismatch_display() {
+    let err = ParquetError::LogicalTypeMismatch {
+        expected: "bid=Price".to_string(),
+        found: "bid=Quantity".to_string(),
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "logical types mismatch, expected bid=Price was bid=Quantity"
+    );
+}
+
+#[test]
+fn test_column_type_mismatch_display() {
+    let err = ParquetError::ColumnTypeMismatch {
+        column: "bid".to_string(),
+        expected: Box::new(DataType::Int64),
+        found: Box::new(DataType::UInt64),
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "column `bid` expected data type Int64, was UInt64"
+    );
+}
+
+#[test]
+fn test_missing_metadata_display() {
+    let err = ParquetError::MissingMetadata {
+        key: "price_precision".to_string(),
+    };
+
+    assert_eq!(err.to_string(), "metadata missing key `price_precision`");
+}
+
+#[test]
+fn test_invalid_metadata_display() {
+    let err = ParquetError::InvalidMetadata {
+        key: "price_precision".to_string(),
+        value: "five".to_string(),
+        reason: "invalid digit found in string".to_string(),
+    };
+
+    assert_eq!(
+        err.to_string(),
+        "metadata key `price_precision` has invalid value 'five', invalid digit found in string"
+    );
+}
+
+#[test]
+fn test_quote_metadata_from_schema() {
+    let schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+
+    let metadata = QuoteMetadata::from_schema(&schema).unwrap();
+
+    assert_eq!(
+        metadata,
+        QuoteMetadata {
+            instrument_id: "EUR/USD.SIM".into(),
+            price_precision: 5,
+            size_precision: 0,
+        }
+    );
+}
+
+#[test]
+fn test_quote_metadata_from_schema_with_missing_key_errors() {
+    let mut schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+    schema.metadata.remove("size_precision");
+
+    let err = QuoteMetadata::from_schema(&schema).unwrap_err();
+
+    assert!(matches!(err, ParquetError::MissingMetadata { key } if key == "size_precision"));
+}
+
+#[test]
+fn test_quote_metadata_from_schema_with_malformed_precision_errors() {
+    let mut schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+    schema
+        .metadata
+        .insert("price_precision".to_string(), "-1".to_string());
+
+    let err = QuoteMetadata::from_schema(&schema).unwrap_err();
+
+    assert!(matches!(
+        err,
+        ParquetError::InvalidMetadata { key, value, .. } if key == "price_precision" && value == "-1"
+    ));
+}
+
+#[test]
+fn test_quote_metadata_from_schema_with_precision_above_maximum_errors() {
+    let mut schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+    schema
+        .metadata
+        .insert("price_precision".to_string(), "10".to_string());
+
+    let err = QuoteMetadata::from_schema(&schema).unwrap_err();
+
+    assert!(matches!(
+        err,
+        ParquetError::InvalidMetadata { key, value, .. } if key == "price_precision" && value == "10"
+    ));
+}
+
+#[test]
+fn test_decode_decimal_price_with_more_decimal_places_than_precision_errors() {
+    let mut metadata = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0).metadata;
+    metadata.insert(
+        PRICE_ENCODING_KEY.to_string(),
+        DECIMAL_PRICE_ENCODING.to_string(),
+    );
+    let schema = QuoteTick::encode_schema(metadata);
+    let prices = |value: &str| Utf8Array::<i32>::from_slice([value]).boxed();
+    let values = || UInt64Array::from_slice([1]).boxed();
+    let chunk = Chunk::new(vec![
+        prices("1.000001"),
+        prices("1.00001"),
+        values(),
+        values(),
+        values(),
+        values(),
+    ]);
+
+    let result = QuoteTick::decode(&schema, chunk);
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::Arrow(Error::OutOfSpec(ref msg))) if msg.contains("\"1.000001\" at row 0")
+    ));
+}
+
+#[test]
+fn test_decode_trade_ticks_with_missing_precision_errors() {
+    let mut schema =
+        TradeTick::encode_schema(quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0).metadata);
+    schema.metadata.remove("size_precision");
+
+    let result = TradeTick::decode(&schema, Chunk::new(vec![]));
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::MissingMetadata { ref key }) if key == "size_precision"
+    ));
+}
+
+#[test]
+fn test_decode_order_book_deltas_with_malformed_precision_errors() {
+    let mut schema =
+        OrderBookDelta::encode_schema(quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0).metadata);
+    schema
+        .metadata
+        .insert("price_precision".to_string(), "five".to_string());
+
+    let result = OrderBookDelta::decode(&schema, Chunk::new(vec![]));
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::InvalidMetadata { ref key, ref value, .. })
+            if key == "price_precision" && value == "five"
+    ));
+}
+
+#[test]
+fn test_reader_with_unsupported_schema_version_errors() {
+    let mut schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+    schema
+        .metadata
+        .insert("schema_version".to_string(), "2".to_string());
+    let writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    let buffer = writer.flush();
+
+    let result = ParquetReader::<QuoteTick, _>::from_bytes(&buffer, 10);
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::SchemaVersionMismatch { ref found, .. }) if found == "2"
+    ));
+}
+
+#[test]
+fn test_decode_multi_instrument_with_precision_above_maximum_errors() {
+    let schema = multi_instrument_quote_tick_schema(&[("EUR/USD.SIM".into(), 10, 0)]);
+
+    let result = QuoteTick::decode(&schema, Chunk::new(vec![]));
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::InvalidMetadata { ref key, ref value, .. })
+            if key == "instrument_0_price_precision" && value == "10"
+    ));
+}
+
+#[test]
+fn test_writer_with_malformed_instrument_dictionary_errors() {
+    let mut schema = multi_instrument_quote_tick_schema(&[("EUR/USD.SIM".into(), 5, 0)]);
+    schema
+        .metadata
+        .insert("instrument_0_size_precision".to_string(), "x".to_string());
+    let tick = QuoteTick {
+        instrument_id: "EUR/USD.SIM".into(),
+        bid: Price::new(1.0, 5),
+        ask: Price::new(1.00001, 5),
+        bid_size: Quantity::new(100.0, 0),
+        ask_size: Quantity::new(100.0, 0),
+        ts_event: 1,
+        ts_init: 1,
+    };
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+
+    let result = writer.write(&[tick]);
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::InvalidMetadata { ref key, .. }) if key == "instrument_0_size_precision"
+    ));
+}
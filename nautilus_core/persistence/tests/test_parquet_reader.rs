@@ -13,67 +13,1786 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::BTreeMap, fs::File, io::Cursor};
+use std::{
+    cell::Cell,
+    collections::{BTreeMap, HashSet},
+    fs::{self, File},
+    io::{self, Cursor, Read, Seek, SeekFrom},
+    rc::Rc,
+};
+
+use arrow2::{
+    array::{Array, Int64Array, UInt64Array, UInt8Array, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    io::parquet::read,
+    io::parquet::write::{
+        CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+    },
+};
+
+use nautilus_model::{
+    data::delta::OrderBookDelta,
+    data::tick::{PartialQuoteTick, QuoteTick, TradeTick},
+    data::GetTsInit,
+    enums::{BookAction, OrderSide},
+    identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
+    orderbook::order::BookOrder,
+    types::{price::Price, quantity::Quantity},
+};
+use nautilus_persistence::parquet::{
+    index_directory, multi_instrument_quote_tick_schema, quote_tick_schema, quote_tick_schema_json,
+    write_quote_ticks_to_bytes, AtomicParquetWriter, DecodeFromChunk, EncodeToChunk, FileSummary,
+    GroupFilterArg, MetadataKeys, MultiFileQuoteReader, ParquetError, ParquetReader, ParquetWriter,
+    PartitionedQuoteWriter, RawQuoteColumns, DECIMAL_PRICE_ENCODING, DEFAULT_WRITE_OPTIONS,
+    PRICE_ENCODING_KEY,
+};
+
+mod test_util;
+
+fn quote_ticks(ts_inits: &[u64]) -> Vec<QuoteTick> {
+    ts_inits
+        .iter()
+        .map(|ts_init| QuoteTick {
+            instrument_id: "EUR/USD.SIM".into(),
+            bid: Price::new(1.0, 5),
+            ask: Price::new(1.00001, 5),
+            bid_size: Quantity::new(100.0, 0),
+            ask_size: Quantity::new(100.0, 0),
+            ts_event: *ts_init,
+            ts_init: *ts_init,
+        })
+        .collect()
+}
+
+fn quote_tick_metadata() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("instrument_id".to_string(), "EUR/USD.SIM".to_string()),
+        ("price_precision".to_string(), "5".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ])
+}
+
+#[test]
+fn test_parquet_reader_native_quote_ticks() {
+    let file_path = "../../tests/test_data/quote_tick_data.parquet";
+    let file = File::open(file_path).expect("Unable to open given file");
+
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(file, 100, GroupFilterArg::None).unwrap();
+    let data: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!("EUR/USD.SIM", data[0].instrument_id.to_string());
+    assert_eq!(data.len(), 9500);
+}
+
+#[test]
+fn test_parquet_trade_ticks_round_trip() {
+    let file_path = "../../tests/test_data/trade_tick_data.parquet";
+    let file = File::open(file_path).expect("Unable to open given file");
+
+    let reader: ParquetReader<TradeTick, File> =
+        ParquetReader::new(file, 1000, GroupFilterArg::None).unwrap();
+    // the precisions of the written file must be those of the source file
+    let metadata = reader.schema().metadata.clone();
+    let data: Vec<TradeTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    let schema = TradeTick::encode_schema(metadata);
+    let mut writer: ParquetWriter<TradeTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let buf_reader: ParquetReader<TradeTick, Cursor<&[u8]>> =
+        ParquetReader::new(Cursor::new(buffer.as_slice()), 1000, GroupFilterArg::None).unwrap();
+    let buf_data: Vec<TradeTick> = buf_reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(buf_data, data);
+}
+
+#[test]
+fn test_decode_trade_ticks_with_null_trade_id_errors() {
+    let schema = TradeTick::encode_schema(quote_tick_metadata());
+    let chunk = Chunk::new(vec![
+        Int64Array::from_slice([100_000]).boxed(),
+        UInt64Array::from_slice([1]).boxed(),
+        UInt8Array::from_slice([1]).boxed(),
+        Utf8Array::<i32>::from([None::<&str>]).boxed(),
+        UInt64Array::from_slice([1]).boxed(),
+        UInt64Array::from_slice([1]).boxed(),
+    ]);
+
+    let err = TradeTick::decode(&schema, chunk).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("required column `trade_id` has 1 nulls"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parquet_reader_native_trade_ticks() {
+    let file_path = "../../tests/test_data/trade_tick_data.parquet";
+    let file = File::open(file_path).expect("Unable to open given file");
+
+    let reader: ParquetReader<TradeTick, File> =
+        ParquetReader::new(file, 100, GroupFilterArg::None).unwrap();
+    let data: Vec<TradeTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!("EUR/USD.SIM", data[0].instrument_id.to_string());
+    assert_eq!(data.len(), 100);
+}
+
+#[test]
+fn test_parquet_reader_yields_decode_errors() {
+    let file_path = "../../tests/test_data/trade_tick_data.parquet";
+    let file = File::open(file_path).expect("Unable to open given file");
+    let mut reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(file, 100, GroupFilterArg::None).unwrap();
+
+    let err = reader.next().unwrap().unwrap_err();
+
+    assert!(
+        err.to_string().contains("missing column `bid` in schema"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parquet_reader_with_zero_chunk_size_errors() {
+    let file_path = "../../tests/test_data/trade_tick_data.parquet";
+    let file = File::open(file_path).expect("Unable to open given file");
+
+    let result: Result<ParquetReader<TradeTick, File>, _> =
+        ParquetReader::new(file, 0, GroupFilterArg::None);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parquet_order_book_deltas_round_trip() {
+    let actions = [
+        BookAction::Add,
+        BookAction::Add,
+        BookAction::Update,
+        BookAction::Delete,
+        BookAction::Add,
+        BookAction::Update,
+    ];
+    let data: Vec<OrderBookDelta> = actions
+        .iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let side = if i % 2 == 0 {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            };
+            OrderBookDelta::new(
+                "AAPL.NASDAQ".into(),
+                *action,
+                BookOrder::new(
+                    Price::new(100.0 + i as f64, 2),
+                    Quantity::new(10.0 * (i + 1) as f64, 0),
+                    side,
+                    i as u64 % 3,
+                ),
+                i as u64 + 1,
+                i as u64 * 10,
+                i as u64 * 10 + 1,
+            )
+        })
+        .collect();
+
+    let metadata: BTreeMap<String, String> = BTreeMap::from([
+        ("instrument_id".to_string(), "AAPL.NASDAQ".to_string()),
+        ("price_precision".to_string(), "2".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let schema = OrderBookDelta::encode_schema(metadata);
+    let mut writer: ParquetWriter<OrderBookDelta, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<OrderBookDelta, Cursor<&[u8]>> =
+        ParquetReader::new(Cursor::new(buffer.as_slice()), 4, GroupFilterArg::None).unwrap();
+    let result: Vec<OrderBookDelta> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result, data);
+    assert!(result.windows(2).all(|w| w[0].sequence < w[1].sequence));
+}
+
+#[test]
+fn test_decode_order_book_delta_with_invalid_action_errors() {
+    let delta = OrderBookDelta::new(
+        "AAPL.NASDAQ".into(),
+        BookAction::Add,
+        BookOrder::new(
+            Price::new(100.0, 2),
+            Quantity::new(10.0, 0),
+            OrderSide::Buy,
+            1,
+        ),
+        1,
+        0,
+        1,
+    );
+    let metadata: BTreeMap<String, String> = BTreeMap::from([
+        ("instrument_id".to_string(), "AAPL.NASDAQ".to_string()),
+        ("price_precision".to_string(), "2".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let schema = OrderBookDelta::encode_schema(metadata);
+    let mut arrays = OrderBookDelta::encode([delta].iter()).into_arrays();
+    arrays[0] = UInt8Array::from_vec(vec![99]).to_boxed();
+
+    let err = OrderBookDelta::decode(&schema, Chunk::new(arrays)).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("invalid `BookAction` enum value, was 99"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_decode_order_book_delta_with_null_price_errors() {
+    let delta = OrderBookDelta::new(
+        "AAPL.NASDAQ".into(),
+        BookAction::Add,
+        BookOrder::new(
+            Price::new(100.0, 2),
+            Quantity::new(10.0, 0),
+            OrderSide::Buy,
+            1,
+        ),
+        1,
+        0,
+        1,
+    );
+    let metadata: BTreeMap<String, String> = BTreeMap::from([
+        ("instrument_id".to_string(), "AAPL.NASDAQ".to_string()),
+        ("price_precision".to_string(), "2".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let schema = OrderBookDelta::encode_schema(metadata);
+    let mut arrays = OrderBookDelta::encode([delta].iter()).into_arrays();
+    arrays[2] = Int64Array::from(vec![None]).to_boxed();
+
+    let err = OrderBookDelta::decode(&schema, Chunk::new(arrays)).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("required column `price` has 1 nulls"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_multi_file_quote_reader_merges_by_ts_init() {
+    let ts_inits: [Vec<u64>; 3] = [
+        vec![0, 3, 6, 9, 12, 15],
+        vec![1, 2, 3, 10, 11],
+        vec![4, 5, 6, 7, 8, 20, 21],
+    ];
+
+    let files: Vec<File> = ts_inits
+        .iter()
+        .map(|ts_init_values| {
+            let ticks = quote_ticks(ts_init_values);
+            let file = tempfile::tempfile().unwrap();
+            let schema = QuoteTick::encode_schema(quote_tick_metadata());
+            let mut writer: ParquetWriter<QuoteTick, File> = ParquetWriter::new(file, schema);
+            writer.write(&ticks).unwrap();
+            writer.flush()
+        })
+        .collect();
+
+    let reader = MultiFileQuoteReader::new(files, 2).unwrap();
+    let result: Vec<u64> = reader.map(|tick| tick.unwrap().ts_init).collect();
+
+    let mut expected: Vec<u64> = ts_inits.concat();
+    expected.sort();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_parquet_writer_validate_sorted_with_sorted_data() {
+    let data = quote_ticks(&[1, 2, 2, 3, 10]);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.validate_sorted = true;
+
+    assert!(writer.write(&data).is_ok());
+}
+
+#[test]
+fn test_parquet_writer_validate_sorted_with_unsorted_data() {
+    let data = quote_ticks(&[1, 2, 3, 2, 10, 4]);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.validate_sorted = true;
+
+    let err = writer.write(&data).unwrap_err();
+
+    assert!(err.to_string().contains("at index 3"), "{err}");
+}
+
+#[test]
+fn test_write_quote_ticks_to_bytes_round_trip() {
+    let data = quote_ticks(&[1, 2, 3, 4, 5]);
+
+    let buffer = write_quote_ticks_to_bytes(&data, quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, Cursor<&[u8]>> =
+        ParquetReader::new(Cursor::new(buffer.as_slice()), 2, GroupFilterArg::None).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result, data);
+}
+
+#[test]
+fn test_parquet_reader_from_bytes() {
+    let data = quote_ticks(&[1, 2, 3, 4, 5]);
+    let buffer = write_quote_ticks_to_bytes(&data, quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 3).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result, data);
+}
+
+#[test]
+fn test_decode_with_mistyped_column_errors() {
+    let data = quote_ticks(&[1, 2, 3]);
+    let schema = QuoteTick::encode_schema(quote_tick_metadata());
+    let mut arrays = QuoteTick::encode(data.iter()).into_arrays();
+    arrays[0] = UInt64Array::from_vec(vec![1, 2, 3]).to_boxed();
+
+    let err = QuoteTick::decode(&schema, Chunk::new(arrays)).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("column `bid` expected data type Int64, was UInt64"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_decode_with_extra_trailing_column() {
+    let mut data = quote_ticks(&[1, 2, 3]);
+    for tick in &mut data {
+        tick.ask_size = Quantity::new(200.0, 0);
+    }
+    let mut target = QuoteTick::encode_schema(quote_tick_metadata());
+    target
+        .fields
+        .push(Field::new("venue_seq", DataType::UInt64, true));
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new_with_target_schema(Vec::new(), target).unwrap();
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    assert_eq!(reader.schema().fields.len(), 7);
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result, data);
+}
+
+#[test]
+fn test_parquet_partial_quote_ticks_round_trip() {
+    let sizes = [
+        (Some(100.0), Some(200.0)),
+        (None, Some(0.0)),
+        (Some(0.0), None),
+        (None, None),
+    ];
+    let data: Vec<PartialQuoteTick> = sizes
+        .iter()
+        .enumerate()
+        .map(|(i, (bid_size, ask_size))| PartialQuoteTick {
+            instrument_id: "EUR/USD.SIM".into(),
+            bid: Price::new(1.0, 5),
+            ask: Price::new(1.00001, 5),
+            bid_size: bid_size.map(|size| Quantity::new(size, 0)),
+            ask_size: ask_size.map(|size| Quantity::new(size, 0)),
+            ts_event: i as u64,
+            ts_init: i as u64,
+        })
+        .collect();
+
+    let schema = PartialQuoteTick::encode_schema(quote_tick_metadata());
+    let mut writer: ParquetWriter<PartialQuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), schema);
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<PartialQuoteTick, _> =
+        ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let result: Vec<PartialQuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result, data);
+}
+
+#[test]
+fn test_decode_partial_quote_ticks_with_null_bid_errors() {
+    let schema = PartialQuoteTick::encode_schema(quote_tick_metadata());
+    let chunk = Chunk::new(vec![
+        Int64Array::from(vec![None]).boxed(),
+        Int64Array::from_slice([100_001]).boxed(),
+        UInt64Array::from(vec![None]).boxed(),
+        UInt64Array::from(vec![None]).boxed(),
+        UInt64Array::from_slice([1]).boxed(),
+        UInt64Array::from_slice([1]).boxed(),
+    ]);
+
+    let err = PartialQuoteTick::decode(&schema, chunk).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("required column `bid` has 1 nulls"),
+        "{err}"
+    );
+}
+
+/// A quote without an ask size, for writing onto a schema with optional fields.
+struct BidSizeQuote {
+    bid: i64,
+    ask: i64,
+    bid_size: u64,
+    ts_init: u64,
+}
+
+impl GetTsInit for BidSizeQuote {
+    fn get_ts_init(&self) -> u64 {
+        self.ts_init
+    }
+}
+
+impl EncodeToChunk for BidSizeQuote {
+    fn assert_metadata(_metadata: &BTreeMap<String, String>) {}
+
+    /// Delta encodes the timestamps, so writing onto a target schema can be
+    /// checked to keep the encodings of the data type.
+    fn encodings(metadata: BTreeMap<String, String>) -> Vec<Vec<Encoding>> {
+        Self::encode_schema(metadata)
+            .fields
+            .iter()
+            .map(|field| match field.name.as_str() {
+                "ts_event" | "ts_init" => vec![Encoding::DeltaBinaryPacked],
+                _ => vec![Encoding::Plain],
+            })
+            .collect()
+    }
+
+    fn encode_schema(metadata: BTreeMap<String, String>) -> Schema {
+        Schema::from(vec![
+            Field::new("bid", DataType::Int64, false),
+            Field::new("ask", DataType::Int64, false),
+            Field::new("bid_size", DataType::UInt64, false),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ])
+        .with_metadata(metadata)
+    }
+
+    fn check_precisions(
+        _price_precision: u8,
+        _size_precision: u8,
+        _data: &[Self],
+    ) -> nautilus_persistence::parquet::Result<()> {
+        Ok(())
+    }
+
+    fn encode<'a, I>(data: I) -> Chunk<Box<dyn Array>>
+    where
+        I: Iterator<Item = &'a Self>,
+        Self: 'a,
+    {
+        let data: Vec<&Self> = data.collect();
+        let ts: Vec<u64> = data.iter().map(|quote| quote.ts_init).collect();
+        Chunk::new(vec![
+            Int64Array::from_vec(data.iter().map(|quote| quote.bid).collect()).boxed(),
+            Int64Array::from_vec(data.iter().map(|quote| quote.ask).collect()).boxed(),
+            UInt64Array::from_vec(data.iter().map(|quote| quote.bid_size).collect()).boxed(),
+            UInt64Array::from_vec(ts.clone()).boxed(),
+            UInt64Array::from_vec(ts).boxed(),
+        ])
+    }
+}
+
+#[test]
+fn test_parquet_writer_pads_missing_optional_columns_with_nulls() {
+    let data: Vec<BidSizeQuote> = (0..3)
+        .map(|i| BidSizeQuote {
+            bid: 100_000,
+            ask: 100_001,
+            bid_size: 100,
+            ts_init: i,
+        })
+        .collect();
+    let target = PartialQuoteTick::encode_schema(quote_tick_metadata());
+    let mut writer: ParquetWriter<BidSizeQuote, Vec<u8>> =
+        ParquetWriter::new_with_target_schema(Vec::new(), target.clone()).unwrap();
+    assert_eq!(
+        writer.encodings,
+        [
+            Encoding::Plain,
+            Encoding::Plain,
+            Encoding::Plain,
+            Encoding::Plain,
+            Encoding::DeltaBinaryPacked,
+            Encoding::DeltaBinaryPacked,
+        ]
+        .map(|encoding| vec![encoding])
+    );
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<PartialQuoteTick, _> =
+        ParquetReader::from_bytes(&buffer, 10).unwrap();
+    assert_eq!(reader.schema().fields, target.fields);
+    let result: Vec<PartialQuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result.len(), 3);
+    for (i, quote) in result.iter().enumerate() {
+        assert_eq!(quote.bid_size, Some(Quantity::from_raw(100, 0)));
+        assert_eq!(quote.ask_size, None);
+        assert_eq!(quote.ts_init, i as u64);
+    }
+}
+
+#[test]
+fn test_parquet_writer_with_non_nullable_missing_column_errors() {
+    let target = QuoteTick::encode_schema(quote_tick_metadata());
+    let result: Result<ParquetWriter<BidSizeQuote, Vec<u8>>, _> =
+        ParquetWriter::new_with_target_schema(Vec::new(), target);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parquet_writer_with_mixed_precisions_errors() {
+    let mut data = quote_ticks(&[1, 2, 3]);
+    data[1].bid = Price::new(1.0, 3);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+
+    let err = writer.write(&data).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("`bid` precision of 3 at index 1 differs from 5"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parquet_writer_with_uniform_mismatched_precision_errors() {
+    let data: Vec<QuoteTick> = quote_ticks(&[1, 2, 3])
+        .into_iter()
+        .map(|tick| QuoteTick {
+            bid: Price::new(1.0, 3),
+            ask: Price::new(1.001, 3),
+            ..tick
+        })
+        .collect();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+
+    let err = writer.write(&data).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("`bid` precision of 3 at index 0 differs from 5 of the schema metadata"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_partitioned_quote_writer_splits_by_day() {
+    const DAY: u64 = 86_400_000_000_000;
+    let dir = tempfile::tempdir().unwrap();
+    let mut writer = PartitionedQuoteWriter::new(dir.path(), quote_tick_metadata());
+
+    writer
+        .write(&quote_ticks(&[1, 2, DAY + 1, 2 * DAY + 1]))
+        .unwrap();
+    writer.write(&quote_ticks(&[DAY + 2, 2 * DAY - 1])).unwrap();
+    let paths = writer.finalize().unwrap();
+
+    let names: Vec<String> = paths
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "1970-01-01.parquet",
+            "1970-01-02.parquet",
+            "1970-01-03.parquet"
+        ]
+    );
+    let expected: [&[u64]; 3] = [&[1, 2], &[DAY + 1, DAY + 2, 2 * DAY - 1], &[2 * DAY + 1]];
+    for (path, ts_inits) in paths.iter().zip(expected) {
+        let reader: ParquetReader<QuoteTick, File> =
+            ParquetReader::new(File::open(path).unwrap(), 10, GroupFilterArg::None).unwrap();
+        let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+        assert_eq!(result, quote_ticks(ts_inits));
+    }
+}
+
+#[test]
+fn test_partitioned_quote_writer_dropped_without_finalize_writes_no_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut writer = PartitionedQuoteWriter::new(dir.path(), quote_tick_metadata());
+    writer.write(&quote_ticks(&[1, 2, 3])).unwrap();
+    drop(writer);
+
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_parquet_writer_ends_file_on_drop() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quotes.parquet");
+    let data = quote_ticks(&[1, 2, 3]);
+    let mut writer: ParquetWriter<QuoteTick, File> = ParquetWriter::new(
+        File::create(&path).unwrap(),
+        QuoteTick::encode_schema(quote_tick_metadata()),
+    );
+    writer.write(&data).unwrap();
+    drop(writer);
+
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(&path).unwrap(), 10, GroupFilterArg::None).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(result, data);
+}
+
+#[test]
+fn test_parquet_writer_with_max_rows_per_group() {
+    let ts_inits: Vec<u64> = (0..10_000).collect();
+    let data = quote_ticks(&ts_inits);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.max_rows_per_group = Some(2500);
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 1000).unwrap();
+
+    assert_eq!(reader.num_row_groups(), 4);
+    assert_eq!(reader.flat_iter().map(Result::unwrap).count(), 10_000);
+}
+
+#[test]
+fn test_write_empty_batch_round_trip() {
+    let buffer = write_quote_ticks_to_bytes(&[], quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    assert_eq!(
+        reader.schema().metadata,
+        QuoteTick::encode_schema(quote_tick_metadata()).metadata
+    );
+    assert_eq!(reader.schema().fields.len(), 6);
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert!(result.is_empty());
+}
+
+#[test]
+fn test_parquet_writer_empty_batches_write_no_row_groups() {
+    let schema = QuoteTick::encode_schema(quote_tick_metadata());
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    writer.write(&[]).unwrap();
+    writer
+        .write_streaming(vec![Vec::new(), quote_ticks(&[1, 2]), Vec::new()].into_iter())
+        .unwrap();
+    writer.write(&[]).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    assert_eq!(reader.num_row_groups(), 1);
+    assert_eq!(reader.total_rows(), 2);
+}
+
+#[test]
+fn test_parquet_writer_with_delta_encoded_timestamps() {
+    let ts_inits: Vec<u64> = (0..10_000)
+        .map(|i| 1_577_898_000_000_000_000 + i * 1_000)
+        .collect();
+    let data = quote_ticks(&ts_inits);
+    let schema = QuoteTick::encode_schema(quote_tick_metadata());
+
+    let mut plain_writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), schema.clone());
+    plain_writer.write(&data).unwrap();
+    let plain_buffer = plain_writer.flush();
+
+    let mut delta_writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), schema);
+    delta_writer
+        .set_column_encodings(&BTreeMap::from([
+            ("ts_event".to_string(), Encoding::DeltaBinaryPacked),
+            ("ts_init".to_string(), Encoding::DeltaBinaryPacked),
+        ]))
+        .unwrap();
+    delta_writer.write(&data).unwrap();
+    let delta_buffer = delta_writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::from_bytes(&delta_buffer, 1000).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result, data);
+    assert!(
+        delta_buffer.len() < plain_buffer.len(),
+        "delta {} was not smaller than plain {}",
+        delta_buffer.len(),
+        plain_buffer.len()
+    );
+}
+
+#[test]
+fn test_parquet_writer_with_unsupported_column_encoding_errors() {
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+
+    let result = writer.set_column_encodings(&BTreeMap::from([(
+        "bid".to_string(),
+        Encoding::DeltaLengthByteArray,
+    )]));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_quote_tick_schema() {
+    let schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+
+    let names: Vec<&str> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["bid", "ask", "bid_size", "ask_size", "ts_event", "ts_init"]
+    );
+    for (key, value) in quote_tick_metadata() {
+        assert_eq!(schema.metadata.get(&key), Some(&value));
+    }
+}
+
+#[test]
+fn test_quote_tick_schema_json() {
+    let json = quote_tick_schema_json(&"EUR/USD.SIM".into(), 5, 0);
+
+    let expected = concat!(
+        r#"{"fields":["#,
+        r#"{"data_type":"Int64","name":"bid","nullable":false},"#,
+        r#"{"data_type":"Int64","name":"ask","nullable":false},"#,
+        r#"{"data_type":"UInt64","name":"bid_size","nullable":false},"#,
+        r#"{"data_type":"UInt64","name":"ask_size","nullable":false},"#,
+        r#"{"data_type":"UInt64","name":"ts_event","nullable":false},"#,
+        r#"{"data_type":"UInt64","name":"ts_init","nullable":false}],"#,
+        r#""metadata":{"instrument_id":"EUR/USD.SIM","#,
+        r#""logical_types":"bid=Price,ask=Price,bid_size=Quantity,ask_size=Quantity,ts_event=Timestamp,ts_init=Timestamp","#,
+        r#""price_precision":"5","size_precision":"0"}}"#,
+    );
+    assert_eq!(json, expected);
+    assert_eq!(json, quote_tick_schema_json(&"EUR/USD.SIM".into(), 5, 0));
+}
+
+#[test]
+fn test_quote_tick_schema_logical_types() {
+    let schema = quote_tick_schema(&"EUR/USD.SIM".into(), 5, 0);
+
+    let logical_types: BTreeMap<&str, &str> = schema.metadata["logical_types"]
+        .split(',')
+        .map(|pair| pair.split_once('=').unwrap())
+        .collect();
+    assert_eq!(
+        logical_types,
+        BTreeMap::from([
+            ("bid", "Price"),
+            ("ask", "Price"),
+            ("bid_size", "Quantity"),
+            ("ask_size", "Quantity"),
+            ("ts_event", "Timestamp"),
+            ("ts_init", "Timestamp"),
+        ])
+    );
+}
+
+#[test]
+fn test_decode_with_mismatched_logical_types_errors() {
+    let data = quote_ticks(&[1, 2]);
+    let mut schema = QuoteTick::encode_schema(quote_tick_metadata());
+    schema.metadata.insert(
+        "logical_types".to_string(),
+        "bid=Price,ask=Price,bid_size=Price,ask_size=Price".to_string(),
+    );
+
+    let result = QuoteTick::decode(&schema, QuoteTick::encode(data.iter()));
+
+    assert!(matches!(
+        result,
+        Err(ParquetError::LogicalTypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_parquet_writer_with_duplicate_column_names_errors() {
+    let mut schema = QuoteTick::encode_schema(quote_tick_metadata());
+    schema.fields[1].name = "bid".to_string();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+
+    let err = writer.write(&quote_ticks(&[1, 2])).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("schema has duplicate column name `bid`"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parquet_writer_with_mismatched_column_count_errors() {
+    let mut schema = QuoteTick::encode_schema(quote_tick_metadata());
+    schema.fields.pop();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    writer.set_column_encodings(&BTreeMap::new()).unwrap();
+
+    let err = writer.write(&quote_ticks(&[1, 2])).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("chunk has 6 columns but schema has 5 fields"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parquet_reader_from_mmap() {
+    let file_path = "../../tests/test_data/quote_tick_data.parquet";
+    let file = File::open(file_path).expect("Unable to open given file");
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(file, 1000, GroupFilterArg::None).unwrap();
+    let expected: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    let mmap_reader: ParquetReader<QuoteTick, _> =
+        unsafe { ParquetReader::from_mmap(file_path, 1000) }.unwrap();
+    let result: Vec<QuoteTick> = mmap_reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(result.len(), 9500);
+    assert_eq!(result, expected);
+}
 
-use nautilus_model::{
-    data::tick::{QuoteTick, TradeTick},
-    identifiers::trade_id::TradeId,
-    types::{price::Price, quantity::Quantity},
-};
-use nautilus_persistence::parquet::{EncodeToChunk, GroupFilterArg, ParquetReader, ParquetWriter};
+#[test]
+fn test_parquet_writer_with_registered_instrument() {
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.instrument_registry = Some(HashSet::from(["EUR/USD.SIM".into(), "GBP/USD.SIM".into()]));
 
-mod test_util;
+    assert!(writer.write(&quote_ticks(&[1, 2])).is_ok());
+}
 
 #[test]
-fn test_parquet_reader_native_quote_ticks() {
+fn test_parquet_writer_with_unknown_instrument_errors() {
+    let mut metadata = quote_tick_metadata();
+    metadata.insert("instrument_id".to_string(), "EUR/USD.SMI".to_string());
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(metadata));
+    writer.instrument_registry = Some(HashSet::from(["EUR/USD.SIM".into()]));
+
+    let err = writer.write(&quote_ticks(&[1, 2])).unwrap_err();
+
+    assert!(err.to_string().contains("'EUR/USD.SMI'"), "{err}");
+}
+
+#[test]
+fn test_parquet_reader_flat_iter() {
+    let ts_inits: Vec<u64> = (0..25).collect();
+    let buffer =
+        write_quote_ticks_to_bytes(&quote_ticks(&ts_inits), quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+    assert_eq!(reader.flat_iter().map(Result::unwrap).count(), 25);
+}
+
+#[test]
+fn test_parquet_reader_with_stride() {
+    let data = quote_ticks(&(0..100).collect::<Vec<u64>>());
+    let buffer = write_quote_ticks_to_bytes(&data, quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 7)
+        .unwrap()
+        .with_stride(10)
+        .unwrap();
+    let result: Vec<u64> = reader
+        .flat_iter()
+        .map(|tick| tick.unwrap().ts_init)
+        .collect();
+
+    assert_eq!(result, (0..100).step_by(10).collect::<Vec<u64>>());
+}
+
+#[test]
+fn test_parquet_reader_with_stride_and_ts_init_filter() {
+    let data = quote_ticks(&(0..100).collect::<Vec<u64>>());
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.max_rows_per_group = Some(10);
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::new(
+        Cursor::new(buffer.as_slice()),
+        10,
+        GroupFilterArg::TsInitGt(49),
+    )
+    .unwrap()
+    .with_stride(10)
+    .unwrap();
+    let result: Vec<u64> = reader
+        .flat_iter()
+        .map(|tick| tick.unwrap().ts_init)
+        .collect();
+
+    assert_eq!(result, [50, 60, 70, 80, 90]);
+}
+
+#[test]
+fn test_parquet_reader_with_zero_stride_errors() {
+    let buffer = write_quote_ticks_to_bytes(&quote_ticks(&[1]), quote_tick_metadata()).unwrap();
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+    assert!(reader.with_stride(0).is_err());
+}
+
+#[test]
+fn test_parquet_reader_into_channel_matches_direct_read() {
     let file_path = "../../tests/test_data/quote_tick_data.parquet";
-    let file = File::open(file_path).expect("Unable to open given file");
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(file_path).unwrap(), 100, GroupFilterArg::None).unwrap();
+    let expected: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
 
     let reader: ParquetReader<QuoteTick, File> =
-        ParquetReader::new(file, 100, GroupFilterArg::None);
-    let data: Vec<QuoteTick> = reader.flatten().collect();
+        ParquetReader::new(File::open(file_path).unwrap(), 100, GroupFilterArg::None).unwrap();
+    let result: Vec<QuoteTick> = reader
+        .into_channel(2)
+        .collect::<Result<_, ParquetError>>()
+        .unwrap();
 
-    assert_eq!("EUR/USD.SIM", data[0].instrument_id.to_string());
-    assert_eq!(data.len(), 9500);
+    assert_eq!(result.len(), 9500);
+    assert_eq!(result, expected);
 }
 
 #[test]
-fn test_parquet_trade_ticks_round_trip() {
-    let file_path = "../../tests/test_data/trade_tick_data.parquet";
-    let file = File::open(file_path).expect("Unable to open given file");
+fn test_parquet_reader_into_channel_ends_with_decode_error() {
+    let mut schema = QuoteTick::encode_schema(quote_tick_metadata());
+    schema.metadata.insert(
+        "logical_types".to_string(),
+        "bid=Price,ask=Price,bid_size=Price,ask_size=Price".to_string(),
+    );
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    writer.write(&quote_ticks(&[1, 2, 3])).unwrap();
+    let buffer = writer.flush();
 
-    let reader: ParquetReader<TradeTick, File> =
-        ParquetReader::new(file, 1000, GroupFilterArg::None);
-    let data: Vec<TradeTick> = reader.flatten().collect();
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::new(Cursor::new(buffer), 1, GroupFilterArg::None).unwrap();
+    let mut channel = reader.into_channel(1);
 
-    let metadata: BTreeMap<String, String> = BTreeMap::from([
-        ("instrument_id".to_string(), "EUR/USD.SIM".to_string()),
-        ("price_precision".to_string(), "5".to_string()),
-        ("size_precision".to_string(), "0".to_string()),
-    ]);
-    let schema = TradeTick::encode_schema(metadata);
-    let mut writer: ParquetWriter<TradeTick, Vec<u8>> = ParquetWriter::new(Vec::new(), schema);
+    assert!(matches!(
+        channel.next(),
+        Some(Err(ParquetError::LogicalTypeMismatch { .. }))
+    ));
+    assert!(channel.next().is_none());
+}
+
+#[test]
+fn test_parquet_reader_slice() {
+    let ts_inits: Vec<u64> = (0..10_000).collect();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.max_rows_per_group = Some(2700);
+    writer.write(&quote_ticks(&ts_inits)).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::slice(Cursor::new(buffer.as_slice()), 100, 2500, 500).unwrap();
+
+    // only the groups of rows 0..2700 and 2700..5400 overlap the slice
+    assert_eq!(reader.num_row_groups(), 2);
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(result, quote_ticks(&ts_inits[2500..3000]));
+}
+
+#[test]
+fn test_parquet_reader_slice_with_unbounded_limit_reads_to_end() {
+    let ts_inits: Vec<u64> = (0..1_000).collect();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.max_rows_per_group = Some(300);
+    writer.write(&quote_ticks(&ts_inits)).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::slice(Cursor::new(buffer.as_slice()), 100, 450, usize::MAX).unwrap();
+
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(result, quote_ticks(&ts_inits[450..]));
+}
+
+#[test]
+fn test_parquet_reader_slice_past_end_is_empty() {
+    let ts_inits: Vec<u64> = (0..100).collect();
+    let buffer =
+        write_quote_ticks_to_bytes(&quote_ticks(&ts_inits), quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::slice(Cursor::new(buffer.as_slice()), 10, 100, 10).unwrap();
+
+    assert_eq!(reader.num_row_groups(), 0);
+    assert_eq!(reader.flat_iter().map(Result::unwrap).count(), 0);
+}
+
+#[test]
+fn test_parquet_reader_summary() {
+    let ts_inits: Vec<u64> = (0..10_000).map(|i| 1_000 + i * 3).collect();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.max_rows_per_group = Some(2500);
+    writer.write(&quote_ticks(&ts_inits)).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 100).unwrap();
+    let summary = reader.summary().unwrap();
+
+    assert_eq!(
+        summary,
+        FileSummary {
+            num_rows: 10_000,
+            ts_init_min: ts_inits.iter().min().copied(),
+            ts_init_max: ts_inits.iter().max().copied(),
+            instrument_ids: vec!["EUR/USD.SIM".into()],
+            path: None,
+        }
+    );
+}
+
+#[test]
+fn test_parquet_reader_summary_of_empty_file() {
+    let buffer = write_quote_ticks_to_bytes(&[], quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let summary = reader.summary().unwrap();
+
+    assert_eq!(summary.num_rows, 0);
+    assert_eq!(summary.ts_init_min, None);
+    assert_eq!(summary.ts_init_max, None);
+}
+
+#[test]
+fn test_parquet_writer_without_statistics() {
+    let ts_inits: Vec<u64> = (0..1_000).collect();
+    let data = quote_ticks(&ts_inits);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new_with_options(
+        Vec::new(),
+        QuoteTick::encode_schema(quote_tick_metadata()),
+        WriteOptions {
+            write_statistics: false,
+            ..DEFAULT_WRITE_OPTIONS
+        },
+    );
+    writer.max_rows_per_group = Some(250);
     writer.write(&data).unwrap();
     let buffer = writer.flush();
 
-    let buf_reader: ParquetReader<TradeTick, Cursor<&[u8]>> =
-        ParquetReader::new(Cursor::new(&buffer), 1000, GroupFilterArg::None);
-    let buf_data: Vec<TradeTick> = buf_reader.flatten().collect();
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 100).unwrap();
+    let summary = reader.summary().unwrap();
+    assert_eq!(summary.num_rows, 1_000);
+    assert_eq!(summary.ts_init_min, None);
+    assert_eq!(
+        reader.flat_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+        data
+    );
 
-    assert_eq!(buf_data, data);
+    // without statistics no row groups can be skipped
+    for filter_arg in [GroupFilterArg::TsInitLt(10), GroupFilterArg::TsInitGt(990)] {
+        let reader: ParquetReader<QuoteTick, _> =
+            ParquetReader::new(Cursor::new(buffer.as_slice()), 100, filter_arg).unwrap();
+        assert_eq!(reader.num_row_groups(), 4);
+        assert_eq!(reader.flat_iter().map(Result::unwrap).count(), 1_000);
+    }
 }
 
 #[test]
-fn test_parquet_reader_native_trade_ticks() {
-    let file_path = "../../tests/test_data/trade_tick_data.parquet";
-    let file = File::open(file_path).expect("Unable to open given file");
+fn test_atomic_parquet_writer_renames_on_end() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quotes.parquet");
+    let data = quote_ticks(&[1, 2, 3]);
+    let mut writer: AtomicParquetWriter<QuoteTick> =
+        AtomicParquetWriter::create(&path, QuoteTick::encode_schema(quote_tick_metadata()))
+            .unwrap();
+    writer.write(&data).unwrap();
 
-    let reader: ParquetReader<TradeTick, File> =
-        ParquetReader::new(file, 100, GroupFilterArg::None);
-    let data: Vec<TradeTick> = reader.flatten().collect();
+    assert!(!path.exists());
+    assert_eq!(writer.end().unwrap(), path);
+    assert!(!dir.path().join("quotes.parquet.tmp").exists());
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(&path).unwrap(), 10, GroupFilterArg::None).unwrap();
+    assert_eq!(
+        reader.flat_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+        data
+    );
+}
 
-    assert_eq!("EUR/USD.SIM", data[0].instrument_id.to_string());
-    assert_eq!(data.len(), 100);
+#[test]
+fn test_atomic_parquet_writer_with_write_error_leaves_no_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quotes.parquet");
+    let mut writer: AtomicParquetWriter<QuoteTick> =
+        AtomicParquetWriter::create(&path, QuoteTick::encode_schema(quote_tick_metadata()))
+            .unwrap();
+    writer.writer_mut().validate_sorted = true;
+    writer.write(&quote_ticks(&[1, 2])).unwrap();
+
+    assert!(writer.write(&quote_ticks(&[4, 3])).is_err());
+    drop(writer);
+
+    assert!(!path.exists());
+    assert!(!dir.path().join("quotes.parquet.tmp").exists());
+}
+
+#[test]
+fn test_parquet_reader_raw_columns() {
+    let mut data = quote_ticks(&[1, 2, 3, 4, 5]);
+    for (i, tick) in data.iter_mut().enumerate() {
+        tick.bid = Price::from_raw(1_000_000_000 + i as i64 * 10_000, 5);
+    }
+    let buffer = write_quote_ticks_to_bytes(&data, quote_tick_metadata()).unwrap();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 2).unwrap();
+    let chunks: Vec<RawQuoteColumns> = reader.raw_columns().map(Result::unwrap).collect();
+
+    assert_eq!(chunks.len(), 3);
+    let bids: Vec<i64> = chunks.iter().flat_map(|c| c.bid().to_vec()).collect();
+    assert_eq!(
+        bids,
+        [
+            1_000_000_000,
+            1_000_010_000,
+            1_000_020_000,
+            1_000_030_000,
+            1_000_040_000
+        ]
+    );
+    assert_eq!(chunks[2].ts_init(), [5]);
+    assert_eq!(chunks[0].ask_size(), [100_000_000_000, 100_000_000_000]);
+}
+
+#[test]
+fn test_parquet_reader_raw_columns_match_decoded_ticks() {
+    let file_path = "../../tests/test_data/quote_tick_data.parquet";
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(file_path).unwrap(), 1000, GroupFilterArg::None).unwrap();
+    let expected: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(file_path).unwrap(), 1000, GroupFilterArg::None).unwrap();
+    let mut bids = Vec::new();
+    let mut ts_inits = Vec::new();
+    for chunk in reader.raw_columns() {
+        let chunk = chunk.unwrap();
+        bids.extend_from_slice(chunk.bid());
+        ts_inits.extend_from_slice(chunk.ts_init());
+    }
+
+    assert_eq!(bids, expected.iter().map(|t| t.bid.raw).collect::<Vec<_>>());
+    assert_eq!(
+        ts_inits,
+        expected.iter().map(|t| t.ts_init).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_parquet_writer_with_options_version_round_trip() {
+    let data = quote_ticks(&[1, 2, 3, 4]);
+    for version in [Version::V1, Version::V2] {
+        let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new_with_options(
+            Vec::new(),
+            QuoteTick::encode_schema(quote_tick_metadata()),
+            WriteOptions {
+                version,
+                ..DEFAULT_WRITE_OPTIONS
+            },
+        );
+        writer.write(&data).unwrap();
+        let buffer = writer.flush();
+
+        let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+        assert_eq!(
+            reader.flat_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+            data
+        );
+    }
+}
+
+#[test]
+fn test_parquet_reader_read_row_group() {
+    let ts_inits: Vec<u64> = (0..1_000).collect();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.max_rows_per_group = Some(250);
+    writer.write(&quote_ticks(&ts_inits)).unwrap();
+    let buffer = writer.flush();
+
+    let mut reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 100).unwrap();
+    let result = reader.read_row_group(2).unwrap();
+
+    assert_eq!(result, quote_ticks(&ts_inits[500..750]));
+    // reading a group does not advance the iteration of the reader
+    let ticks: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(ticks, quote_ticks(&ts_inits));
+}
+
+#[test]
+fn test_parquet_reader_read_row_group_out_of_range_errors() {
+    let buffer = write_quote_ticks_to_bytes(&quote_ticks(&[1, 2]), quote_tick_metadata()).unwrap();
+
+    let mut reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+    assert!(reader.read_row_group(1).is_err());
+}
+
+#[test]
+fn test_parquet_writer_reject_crossed() {
+    let mut data = quote_ticks(&[1, 2, 3]);
+    data[1].bid = Price::new(1.00002, 5);
+    assert!(data[1].is_crossed());
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.reject_crossed = true;
+
+    let err = writer.write(&data).unwrap_err();
+
+    assert!(
+        err.to_string().contains("crossed quote at index 1"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_parquet_writer_reject_crossed_allows_locked_quotes() {
+    let mut data = quote_ticks(&[1, 2, 3]);
+    data[1].bid = data[1].ask.clone();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.reject_crossed = true;
+    writer.write(&data).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+    assert_eq!(
+        reader.flat_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+        data
+    );
+}
+
+#[test]
+fn test_multi_instrument_quote_ticks_round_trip() {
+    let eur_usd = QuoteTick {
+        instrument_id: "EUR/USD.SIM".into(),
+        bid: Price::new(1.00001, 5),
+        ask: Price::new(1.00002, 5),
+        bid_size: Quantity::new(100.0, 0),
+        ask_size: Quantity::new(200.0, 0),
+        ts_event: 1,
+        ts_init: 1,
+    };
+    let btc_usdt = QuoteTick {
+        instrument_id: "BTC/USDT.BINANCE".into(),
+        bid: Price::new(25000.1, 1),
+        ask: Price::new(25000.2, 1),
+        bid_size: Quantity::new(0.005, 3),
+        ask_size: Quantity::new(1.25, 3),
+        ts_event: 2,
+        ts_init: 2,
+    };
+    let ticks = vec![eur_usd.clone(), btc_usdt.clone(), eur_usd];
+    let schema = multi_instrument_quote_tick_schema(&[
+        ("EUR/USD.SIM".into(), 5, 0),
+        ("BTC/USDT.BINANCE".into(), 1, 3),
+    ]);
+    assert_eq!(schema.metadata["instrument_1"], "BTC/USDT.BINANCE");
+    assert_eq!(schema.metadata["instrument_1_size_precision"], "3");
+
+    let mut writer = ParquetWriter::<QuoteTick, _>::new(Cursor::new(vec![]), schema.clone());
+    writer.write(&ticks).unwrap();
+    let buffer = writer.end().unwrap().into_inner();
+
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::new(Cursor::new(buffer.as_slice()), 100, GroupFilterArg::None).unwrap();
+    let summary = reader.summary().unwrap();
+    assert_eq!(
+        summary.instrument_ids,
+        vec!["EUR/USD.SIM".into(), "BTC/USDT.BINANCE".into()]
+    );
+    let decoded: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(decoded, ticks);
+    assert_eq!(decoded[1].bid.precision, 1);
+    assert_eq!(decoded[1].bid_size.precision, 3);
+    assert_eq!(decoded[2].bid.precision, 5);
+
+    let mut unknown = btc_usdt.clone();
+    unknown.instrument_id = "ETH/USDT.BINANCE".into();
+    let mut wrong_precision = btc_usdt;
+    wrong_precision.ask_size = Quantity::new(1.25, 2);
+    for tick in [unknown, wrong_precision] {
+        let mut writer = ParquetWriter::<QuoteTick, _>::new(Cursor::new(vec![]), schema.clone());
+        assert!(writer.write(&[tick]).is_err());
+    }
+}
+
+#[test]
+fn test_parquet_reader_null_counts() {
+    let sizes = [
+        (Some(100.0), Some(200.0)),
+        (None, Some(0.0)),
+        (Some(0.0), None),
+        (None, None),
+        (None, Some(1.0)),
+    ];
+    let data: Vec<PartialQuoteTick> = sizes
+        .iter()
+        .enumerate()
+        .map(|(i, (bid_size, ask_size))| PartialQuoteTick {
+            instrument_id: "EUR/USD.SIM".into(),
+            bid: Price::new(1.0, 5),
+            ask: Price::new(1.00001, 5),
+            bid_size: bid_size.map(|size| Quantity::new(size, 0)),
+            ask_size: ask_size.map(|size| Quantity::new(size, 0)),
+            ts_event: i as u64,
+            ts_init: i as u64,
+        })
+        .collect();
+
+    let schema = PartialQuoteTick::encode_schema(quote_tick_metadata());
+    let mut writer: ParquetWriter<PartialQuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), schema);
+    writer.write(&data[..2]).unwrap();
+    writer.write(&data[2..]).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<PartialQuoteTick, _> =
+        ParquetReader::from_bytes(&buffer, 10).unwrap();
+    assert_eq!(reader.num_row_groups(), 2);
+    let null_counts = reader.null_counts();
+
+    assert_eq!(null_counts["bid_size"], 3);
+    assert_eq!(null_counts["ask_size"], 2);
+    assert_eq!(null_counts["bid"], 0);
+    assert_eq!(null_counts["ts_init"], 0);
+}
+
+#[test]
+fn test_parquet_reader_total_rows() {
+    let ticks = quote_ticks(&(0..250).collect::<Vec<u64>>());
+    let schema = QuoteTick::encode_schema(quote_tick_metadata());
+    let mut writer = ParquetWriter::<QuoteTick, _>::new(Vec::new(), schema);
+    writer.max_rows_per_group = Some(100);
+    writer.write(&ticks).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+    assert_eq!(reader.num_row_groups(), 3);
+    assert_eq!(reader.total_rows(), ticks.len());
+}
+
+#[test]
+fn test_multi_instrument_decode_interns_instrument_ids() {
+    let instruments = ["EUR/USD.SIM", "BTC/USDT.BINANCE"];
+    let ticks: Vec<QuoteTick> = (0..6)
+        .map(|i| QuoteTick {
+            instrument_id: instruments[i % 2].into(),
+            ts_event: i as u64,
+            ts_init: i as u64,
+            ..quote_ticks(&[0])[0].clone()
+        })
+        .collect();
+    let schema = multi_instrument_quote_tick_schema(&[
+        ("EUR/USD.SIM".into(), 5, 0),
+        ("BTC/USDT.BINANCE".into(), 5, 0),
+    ]);
+    let mut writer = ParquetWriter::<QuoteTick, _>::new(Vec::new(), schema);
+    writer.write(&ticks).unwrap();
+    let buffer = writer.flush();
+
+    // A chunk per row so each row is decoded with freshly read metadata
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 1).unwrap();
+    let decoded: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(decoded, ticks);
+    for (i, tick) in decoded.iter().enumerate().skip(2) {
+        let first = &decoded[i % 2].instrument_id;
+        assert!(Rc::ptr_eq(
+            &tick.instrument_id.symbol.value,
+            &first.symbol.value
+        ));
+        assert!(Rc::ptr_eq(
+            &tick.instrument_id.venue.value,
+            &first.venue.value
+        ));
+    }
+}
+
+/// Writes quote ticks as the Python pyarrow layer does, with the timestamps
+/// first as nullable `Int64` columns and no `logical_types` metadata.
+fn pyarrow_style_quote_ticks(ts_inits: &[i64]) -> Vec<u8> {
+    let schema = Schema::from(vec![
+        Field::new("ts_init", DataType::Int64, true),
+        Field::new("ts_event", DataType::Int64, true),
+        Field::new("ask", DataType::Int64, true),
+        Field::new("bid", DataType::Int64, true),
+        Field::new("ask_size", DataType::UInt64, true),
+        Field::new("bid_size", DataType::UInt64, true),
+    ])
+    .with_metadata(quote_tick_metadata());
+    let n = ts_inits.len();
+    let chunk = Chunk::new(vec![
+        Int64Array::from_slice(ts_inits).boxed(),
+        Int64Array::from_slice(ts_inits).boxed(),
+        Int64Array::from_vec(vec![1_000_010_000; n]).boxed(),
+        Int64Array::from_vec(vec![1_000_000_000; n]).boxed(),
+        UInt64Array::from_vec(vec![200_000_000_000; n]).boxed(),
+        UInt64Array::from_vec(vec![100_000_000_000; n]).boxed(),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+    let encodings = vec![vec![Encoding::Plain]; schema.fields.len()];
+    let row_groups =
+        RowGroupIterator::try_new(vec![Ok(chunk)].into_iter(), &schema, options, encodings)
+            .unwrap();
+    let mut writer = FileWriter::try_new(Vec::new(), schema, options).unwrap();
+    for group in row_groups {
+        writer.write(group.unwrap()).unwrap();
+    }
+    writer.end(None).unwrap();
+    writer.into_inner()
+}
+
+#[test]
+fn test_decode_pyarrow_style_quote_ticks() {
+    let buffer = pyarrow_style_quote_ticks(&[1, 2, 3]);
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let ticks: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(ticks.len(), 3);
+    assert_eq!(ticks[0].bid, Price::new(1.0, 5));
+    assert_eq!(ticks[0].ask, Price::new(1.00001, 5));
+    assert_eq!(ticks[2].bid_size, Quantity::new(100.0, 0));
+    assert_eq!(ticks[2].ask_size, Quantity::new(200.0, 0));
+    assert_eq!(ticks[2].ts_event, 3);
+    assert_eq!(ticks[2].ts_init, 3);
+}
+
+#[test]
+fn test_decode_pyarrow_style_quote_ticks_with_negative_timestamp_errors() {
+    let buffer = pyarrow_style_quote_ticks(&[1, -2]);
+
+    let mut reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+
+    assert!(reader.read_row_group(0).is_err());
+}
+
+#[test]
+fn test_decimal_price_encoding_round_trip() {
+    let prices = [
+        ("0.00001", "0.00002"),
+        ("1.23456", "1.23457"),
+        ("98765.43210", "98765.43211"),
+        ("-0.50000", "0.50000"),
+    ];
+    let ticks: Vec<QuoteTick> = prices
+        .iter()
+        .zip(quote_ticks(&[1, 2, 3, 4]))
+        .map(|((bid, ask), tick)| QuoteTick {
+            bid: Price::from(*bid),
+            ask: Price::from(*ask),
+            ..tick
+        })
+        .collect();
+    let mut metadata = quote_tick_metadata();
+    metadata.insert(
+        PRICE_ENCODING_KEY.to_string(),
+        DECIMAL_PRICE_ENCODING.to_string(),
+    );
+    let buffer = write_quote_ticks_to_bytes(&ticks, metadata).unwrap();
+
+    let mut cursor = Cursor::new(&buffer);
+    let file_metadata = read::read_metadata(&mut cursor).unwrap();
+    let schema = read::infer_schema(&file_metadata).unwrap();
+    assert_eq!(schema.fields[0].data_type, DataType::Utf8);
+    let mut file_reader =
+        read::FileReader::new(cursor, file_metadata.row_groups, schema, None, None, None);
+    let chunk = file_reader.next().unwrap().unwrap();
+    let bids: Vec<&str> = chunk.arrays()[0]
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap()
+        .values_iter()
+        .collect();
+    assert_eq!(bids, prices.map(|(bid, _)| bid));
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(result, ticks);
+    for (decoded, tick) in result.iter().zip(&ticks) {
+        assert_eq!(decoded.bid.raw, tick.bid.raw);
+        assert_eq!(decoded.ask.raw, tick.ask.raw);
+    }
+}
+
+#[test]
+fn test_write_rejects_out_of_range_raw_values() {
+    let mut ticks = quote_ticks(&[1, 2]);
+    ticks[1].ask = Price::from_raw(i64::MAX, 5);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+
+    let err = writer.write(&ticks).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "arrow error: Invalid argument error: `ask` raw value {} at index 1 was not in the `Price` range [-9223372036000000000, 9223372036000000000]",
+            i64::MAX
+        )
+    );
+}
+
+#[test]
+fn test_write_timestamps_above_int64_maximum_round_trip() {
+    let ticks = quote_ticks(&[1, u64::MAX]);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.write(&ticks).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(result, ticks);
+}
+
+#[test]
+fn test_quote_rows_match_full_decode() {
+    let ticks: Vec<QuoteTick> = quote_ticks(&(0..25).collect::<Vec<u64>>())
+        .into_iter()
+        .enumerate()
+        .map(|(i, tick)| QuoteTick {
+            bid: Price::from_raw(1_000_000_000 + i as i64 * 10_000, 5),
+            bid_size: Quantity::from_raw(i as u64 * 1_000_000_000, 0),
+            ..tick
+        })
+        .collect();
+    let buffer = write_quote_ticks_to_bytes(&ticks, quote_tick_metadata()).unwrap();
+    let decoded: Vec<QuoteTick> = ParquetReader::<QuoteTick, _>::from_bytes(&buffer, 10)
+        .unwrap()
+        .flat_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    let mut rows = ParquetReader::<QuoteTick, _>::from_bytes(&buffer, 10)
+        .unwrap()
+        .rows()
+        .unwrap();
+    let mut count = 0;
+    while let Some(row) = rows.next_row() {
+        let row = row.unwrap();
+        let tick = &decoded[count];
+        assert_eq!(row.instrument_id(), &tick.instrument_id);
+        assert_eq!(row.bid(), tick.bid);
+        assert_eq!(row.ask(), tick.ask);
+        assert_eq!(row.bid_size(), tick.bid_size);
+        assert_eq!(row.ask_size(), tick.ask_size);
+        assert_eq!(row.ts_event(), tick.ts_event);
+        assert_eq!(row.ts_init(), tick.ts_init);
+        count += 1;
+    }
+    assert_eq!(count, ticks.len());
+    assert!(rows.next_row().is_none());
+}
+
+#[test]
+fn test_custom_metadata_keys_round_trip() {
+    let keys = MetadataKeys {
+        instrument_id: "symbol".to_string(),
+        price_precision: "px_precision".to_string(),
+        size_precision: "qty_precision".to_string(),
+    };
+    let ticks = quote_ticks(&[1, 2, 3]);
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> = ParquetWriter::new_with_metadata_keys(
+        Vec::new(),
+        QuoteTick::encode_schema(quote_tick_metadata()),
+        keys.clone(),
+    );
+    writer.write(&ticks).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let metadata = &reader.schema().metadata;
+    assert_eq!(metadata.get("symbol").unwrap(), "EUR/USD.SIM");
+    assert_eq!(metadata.get("px_precision").unwrap(), "5");
+    assert_eq!(metadata.get("qty_precision").unwrap(), "0");
+    assert!(!metadata.contains_key("instrument_id"));
+    let mut reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    assert!(reader.read_row_group(0).is_err());
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::new_with_metadata_keys(
+        Cursor::new(buffer.as_slice()),
+        10,
+        GroupFilterArg::None,
+        &keys,
+    )
+    .unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(result, ticks);
+}
+
+#[test]
+fn test_sort_on_write_stable_sorts_batch() {
+    let ts_inits = [5, 3, 9, 3, 1, 7, 5, 2];
+    let ticks: Vec<QuoteTick> = quote_ticks(&ts_inits)
+        .into_iter()
+        .enumerate()
+        .map(|(i, tick)| QuoteTick {
+            bid: Price::from_raw(1_000_000_000 - i as i64 * 10_000, 5),
+            ..tick
+        })
+        .collect();
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.sort_on_write = true;
+    writer.validate_sorted = true;
+    writer.max_rows_per_group = Some(3);
+    writer.write(&ticks).unwrap();
+    let buffer = writer.flush();
+
+    let reader: ParquetReader<QuoteTick, _> = ParquetReader::from_bytes(&buffer, 10).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+    let mut expected = ticks;
+    expected.sort_by_key(|tick| tick.ts_init);
+    assert_eq!(result, expected);
+    // equal timestamps keep their input order
+    assert_eq!(result[2].bid, Price::from_raw(999_990_000, 5));
+    assert_eq!(result[3].bid, Price::from_raw(999_970_000, 5));
+}
+
+/// Counts the reads and seeks made of the wrapped reader.
+struct CountingReader<R> {
+    inner: R,
+    calls: Rc<Cell<usize>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.calls.set(self.calls.get() + 1);
+        self.inner.read(buf)
+    }
+}
+
+impl<R: Seek> Seek for CountingReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.calls.set(self.calls.get() + 1);
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn test_metadata_methods_do_not_reread_footer() {
+    let mut writer: ParquetWriter<QuoteTick, Vec<u8>> =
+        ParquetWriter::new(Vec::new(), QuoteTick::encode_schema(quote_tick_metadata()));
+    writer.write(&quote_ticks(&[1, 2])).unwrap();
+    writer.write(&quote_ticks(&[3, 4])).unwrap();
+    let buffer = writer.flush();
+    let calls = Rc::new(Cell::new(0));
+    let counting_reader = CountingReader {
+        inner: Cursor::new(buffer),
+        calls: calls.clone(),
+    };
+
+    let reader: ParquetReader<QuoteTick, _> =
+        ParquetReader::new(counting_reader, 10, GroupFilterArg::None).unwrap();
+    let footer_calls = calls.get();
+    assert!(footer_calls > 0);
+
+    assert_eq!(reader.num_row_groups(), 2);
+    assert_eq!(reader.total_rows(), 4);
+    assert_eq!(reader.summary().unwrap().num_rows, 4);
+    assert_eq!(reader.null_counts().get("bid"), Some(&0));
+    assert_eq!(reader.schema().fields.len(), 6);
+    assert_eq!(calls.get(), footer_calls);
+
+    assert_eq!(reader.flat_iter().map(Result::unwrap).count(), 4);
+    assert!(calls.get() > footer_calls);
+}
+
+#[test]
+fn test_index_directory_sorts_by_instrument_then_time() {
+    let dir = tempfile::tempdir().unwrap();
+    let write = |name: &str, instrument_id: &str, ts_inits: &[u64]| {
+        let ticks: Vec<QuoteTick> = quote_ticks(ts_inits)
+            .into_iter()
+            .map(|tick| QuoteTick {
+                instrument_id: instrument_id.into(),
+                ..tick
+            })
+            .collect();
+        let mut metadata = quote_tick_metadata();
+        metadata.insert("instrument_id".to_string(), instrument_id.to_string());
+        let buffer = write_quote_ticks_to_bytes(&ticks, metadata).unwrap();
+        std::fs::write(dir.path().join(name), buffer).unwrap();
+    };
+    write("eurusd-2.parquet", "EUR/USD.SIM", &[300, 400]);
+    write("eurusd-1.parquet", "EUR/USD.SIM", &[100, 150, 200]);
+    write("audusd.parquet", "AUD/USD.SIM", &[500]);
+    std::fs::write(dir.path().join("notes.txt"), "not parquet").unwrap();
+
+    let index = index_directory(dir.path()).unwrap();
+
+    let names: Vec<String> = index
+        .iter()
+        .map(|summary| {
+            let path = summary.path.as_ref().unwrap();
+            path.file_name().unwrap().to_string_lossy().to_string()
+        })
+        .collect();
+    assert_eq!(
+        names,
+        ["audusd.parquet", "eurusd-1.parquet", "eurusd-2.parquet"]
+    );
+    let summaries: Vec<(usize, Option<u64>, Option<u64>)> = index
+        .iter()
+        .map(|summary| (summary.num_rows, summary.ts_init_min, summary.ts_init_max))
+        .collect();
+    assert_eq!(
+        summaries,
+        [
+            (1, Some(500), Some(500)),
+            (3, Some(100), Some(200)),
+            (2, Some(300), Some(400))
+        ]
+    );
+    assert_eq!(index[0].instrument_ids, [InstrumentId::from("AUD/USD.SIM")]);
+    assert_eq!(index[2].instrument_ids, [InstrumentId::from("EUR/USD.SIM")]);
 }
 
 #[test]
@@ -121,17 +1840,18 @@ fn test_parquet_filter() {
 
     let buffer = writer.flush();
     let filtered_reader: ParquetReader<TradeTick, Cursor<&[u8]>> = ParquetReader::new(
-        Cursor::new(&buffer),
+        Cursor::new(buffer.as_slice()),
         1000,
         GroupFilterArg::TsInitGt(ts_init_cutoff),
-    );
+    )
+    .unwrap();
     let data_filtered: Vec<TradeTick> = filtered_reader
-        .flat_map(|ticks| ticks.into_iter())
+        .flat_map(|ticks| ticks.unwrap().into_iter())
         .collect();
     let unfiltered_reader: ParquetReader<TradeTick, Cursor<&[u8]>> =
-        ParquetReader::new(Cursor::new(&buffer), 1000, GroupFilterArg::None);
+        ParquetReader::new(Cursor::new(buffer.as_slice()), 1000, GroupFilterArg::None).unwrap();
     let data_unfiltered: Vec<TradeTick> = unfiltered_reader
-        .flat_map(|ticks| ticks.into_iter())
+        .flat_map(|ticks| ticks.unwrap().into_iter())
         .collect();
 
     assert_eq!(data_filtered.len(), len);
@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::process::Command;
+
+use nautilus_model::{
+    data::tick::QuoteTick,
+    types::{price::Price, quantity::Quantity},
+};
+use nautilus_persistence::csv::{read_quote_ticks_csv, CsvQuoteConfig};
+use nautilus_persistence::parquet::{EncodeToChunk, GroupFilterArg, ParquetReader, ParquetWriter};
+
+#[test]
+fn test_inspect_prints_file_summary() {
+    let data: Vec<QuoteTick> = (0..10)
+        .map(|ts_init| QuoteTick {
+            instrument_id: "EUR/USD.SIM".into(),
+            bid: Price::new(1.0, 5),
+            ask: Price::new(1.00001, 5),
+            bid_size: Quantity::new(100.0, 0),
+            ask_size: Quantity::new(100.0, 0),
+            ts_event: ts_init,
+            ts_init,
+        })
+        .collect();
+    let metadata = BTreeMap::from([
+        ("instrument_id".to_string(), "EUR/USD.SIM".to_string()),
+        ("price_precision".to_string(), "5".to_string()),
+        ("size_precision".to_string(), "0".to_string()),
+    ]);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("quotes.parquet");
+    let mut writer: ParquetWriter<QuoteTick, File> = ParquetWriter::new(
+        File::create(&path).unwrap(),
+        QuoteTick::encode_schema(metadata),
+    );
+    writer.max_rows_per_group = Some(4);
+    writer.write(&data).unwrap();
+    writer.flush();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_parquet_tool"))
+        .arg("inspect")
+        .arg(&path)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.contains("  bid: Int64\n"), "{stdout}");
+    assert!(stdout.contains("  ts_init: UInt64\n"), "{stdout}");
+    assert!(
+        stdout.contains("  instrument_id: EUR/USD.SIM\n"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("  price_precision: 5\n"), "{stdout}");
+    assert!(stdout.contains("  size_precision: 0\n"), "{stdout}");
+    assert!(stdout.contains("row_groups: 3\n"), "{stdout}");
+    assert!(stdout.contains("rows: 10\n"), "{stdout}");
+}
+
+#[test]
+fn test_convert_csv_to_parquet() {
+    let input = "../../tests/test_data/quote_tick_data.csv";
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("quotes.parquet");
+
+    let status = Command::new(env!("CARGO_BIN_EXE_parquet_tool"))
+        .args(["convert", input])
+        .arg(&output)
+        .args(["--instrument-id", "EUR/USD.SIM"])
+        .args(["--price-precision", "5", "--size-precision", "0"])
+        .args(["--timestamp-format", "%Y%m%d %H%M%S%3f"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(&output).unwrap(), 1000, GroupFilterArg::None).unwrap();
+    let result: Vec<QuoteTick> = reader.flat_iter().collect::<Result<_, _>>().unwrap();
+
+    let mut config = CsvQuoteConfig::new("EUR/USD.SIM".into(), 5, 0);
+    config.timestamp_format = "%Y%m%d %H%M%S%3f".to_string();
+    let expected = read_quote_ticks_csv(File::open(input).unwrap(), &config).unwrap();
+    assert_eq!(result.len(), 9500);
+    assert_eq!(result, expected);
+    assert_eq!(result[0].bid, Price::new(1.1212, 5));
+    assert_eq!(result[0].ask, Price::new(1.12172, 5));
+    assert_eq!(result[0].ts_init, 1_577_898_000_065_000_000);
+}
+
+#[test]
+fn test_convert_with_unknown_flag_errors() {
+    let input = "../../tests/test_data/quote_tick_data.csv";
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("quotes.parquet");
+
+    let result = Command::new(env!("CARGO_BIN_EXE_parquet_tool"))
+        .args(["convert", input])
+        .arg(&output)
+        .args(["--instrument-id", "EUR/USD.SIM"])
+        .args(["--price-precision", "5", "--size-precision", "0"])
+        .args(["--bid-column", "1"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(result.stderr).unwrap();
+
+    assert_eq!(result.status.code(), Some(2));
+    assert!(stderr.contains("unknown flag --bid-column"), "{stderr}");
+    assert!(stderr.contains("usage:"), "{stderr}");
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_convert_with_invalid_instrument_id_errors() {
+    let input = "../../tests/test_data/quote_tick_data.csv";
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("quotes.parquet");
+
+    let result = Command::new(env!("CARGO_BIN_EXE_parquet_tool"))
+        .args(["convert", input])
+        .arg(&output)
+        .args(["--instrument-id", "EURUSD"])
+        .args(["--price-precision", "5", "--size-precision", "0"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(result.stderr).unwrap();
+
+    assert_eq!(result.status.code(), Some(2));
+    assert!(stderr.contains("invalid value 'EURUSD'"), "{stderr}");
+    assert!(stderr.contains("usage:"), "{stderr}");
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_convert_with_strict_flag() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("quotes.csv");
+    std::fs::write(
+        &input,
+        "2020-01-01 17:00:00.065,1.12120,1.12172\n2020-01-01 17:00:10.447,1.12120\n2020-01-01 17:00:10.498,1.12117,1.12161\n",
+    )
+    .unwrap();
+    let output = dir.path().join("quotes.parquet");
+    let convert = |strict: &str| {
+        Command::new(env!("CARGO_BIN_EXE_parquet_tool"))
+            .arg("convert")
+            .arg(&input)
+            .arg(&output)
+            .args(["--instrument-id", "EUR/USD.SIM"])
+            .args(["--price-precision", "5", "--size-precision", "0"])
+            .args(["--strict", strict])
+            .status()
+            .unwrap()
+    };
+
+    assert_eq!(convert("true").code(), Some(1));
+    assert!(convert("false").success());
+    let reader: ParquetReader<QuoteTick, File> =
+        ParquetReader::new(File::open(&output).unwrap(), 10, GroupFilterArg::None).unwrap();
+    assert_eq!(reader.total_rows(), 2);
+}
@@ -0,0 +1,40 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_persistence::synthetic::{generate_quotes, PRICE_PRECISION};
+
+#[test]
+fn test_generate_quotes_is_deterministic() {
+    let quotes = generate_quotes("EUR/USD.SIM".into(), 1_000, 42);
+
+    assert_eq!(quotes, generate_quotes("EUR/USD.SIM".into(), 1_000, 42));
+    assert_ne!(quotes, generate_quotes("EUR/USD.SIM".into(), 1_000, 43));
+}
+
+#[test]
+fn test_generate_quotes_are_realistic() {
+    let quotes = generate_quotes("EUR/USD.SIM".into(), 1_000, 42);
+
+    assert_eq!(quotes.len(), 1_000);
+    for quote in &quotes {
+        assert_eq!(quote.bid.precision, PRICE_PRECISION);
+        assert!(quote.bid.is_positive());
+        assert!(quote.bid < quote.ask);
+        assert_eq!(quote.ts_event, quote.ts_init);
+    }
+    assert!(quotes
+        .windows(2)
+        .all(|pair| pair[0].ts_init < pair[1].ts_init));
+}
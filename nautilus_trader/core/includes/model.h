@@ -250,6 +250,10 @@ typedef struct Price_t {
     int64_t raw;
     uint8_t precision;
 } Price_t;
+/**
+ * A zero price with a precision of 0.
+ */
+#define Price_t_ZERO (Price_t){ .raw = 0, .precision = 0 }
 
 typedef struct Quantity_t {
     uint64_t raw;
@@ -317,6 +321,19 @@ typedef struct ExecAlgorithmId_t {
     struct Rc_String *value;
 } ExecAlgorithmId_t;
 
+typedef struct VenueOrderId_t {
+    struct Rc_String *value;
+} VenueOrderId_t;
+
+/**
+ * Represents a composite key identifying an order by both its client and
+ * venue assigned identifiers.
+ */
+typedef struct OrderKey_t {
+    struct ClientOrderId_t client_order_id;
+    struct VenueOrderId_t venue_order_id;
+} OrderKey_t;
+
 typedef struct OrderListId_t {
     struct Rc_String *value;
 } OrderListId_t;
@@ -333,10 +350,6 @@ typedef struct TraderId_t {
     struct Rc_String *value;
 } TraderId_t;
 
-typedef struct VenueOrderId_t {
-    struct Rc_String *value;
-} VenueOrderId_t;
-
 typedef struct Ladder {
     enum OrderSide side;
     struct BTreeMap_BookPrice__Level *levels;
@@ -527,6 +540,20 @@ const char *aggressor_side_to_cstr(enum AggressorSide value);
  */
 enum AggressorSide aggressor_side_from_cstr(const char *ptr);
 
+/**
+ * Returns the integer representation of the enum, as used by the parquet
+ * encoders.
+ */
+uint8_t aggressor_side_to_u8(enum AggressorSide value);
+
+/**
+ * Returns an enum from its integer representation.
+ *
+ * # Panics
+ * - If `value` is not a valid `AggressorSide`.
+ */
+enum AggressorSide aggressor_side_from_u8(uint8_t value);
+
 const char *asset_class_to_cstr(enum AssetClass value);
 
 /**
@@ -667,6 +694,20 @@ const char *order_side_to_cstr(enum OrderSide value);
  */
 enum OrderSide order_side_from_cstr(const char *ptr);
 
+/**
+ * Returns the integer representation of the enum, as used by the parquet
+ * encoders.
+ */
+uint8_t order_side_to_u8(enum OrderSide value);
+
+/**
+ * Returns an enum from its integer representation.
+ *
+ * # Panics
+ * - If `value` is not a valid `OrderSide`.
+ */
+enum OrderSide order_side_from_u8(uint8_t value);
+
 const char *order_status_to_cstr(enum OrderStatus value);
 
 /**
@@ -755,6 +796,13 @@ enum TriggerType trigger_type_from_cstr(const char *ptr);
  */
 struct AccountId_t account_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `account_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `account_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct AccountId_t account_id_clone(const struct AccountId_t *account_id);
 
 /**
@@ -767,6 +815,11 @@ void account_id_free(struct AccountId_t account_id);
  */
 const char *account_id_to_cstr(const struct AccountId_t *account_id);
 
+/**
+ * Returns the UTF-8 byte length of an [`AccountId`], without copying the string.
+ */
+uintptr_t account_id_len(const struct AccountId_t *account_id);
+
 uint8_t account_id_eq(const struct AccountId_t *lhs, const struct AccountId_t *rhs);
 
 uint64_t account_id_hash(const struct AccountId_t *account_id);
@@ -779,6 +832,27 @@ uint64_t account_id_hash(const struct AccountId_t *account_id);
  */
 struct ClientId_t client_id_new(const char *ptr);
 
+/**
+ * Writes a [`ClientId`] from a C string pointer to `out`, returning zero on
+ * success or an [`IdentifierFfiError`] code, in which case `out` is left
+ * untouched.
+ *
+ * Unlike `client_id_new` this never panics on the input.
+ *
+ * # Safety
+ * - Assumes `ptr` is null or a valid C string pointer.
+ * - Assumes `out` is null or valid for writes, any value it holds is
+ *   overwritten without being freed.
+ */
+int32_t client_id_new_checked(const char *ptr, struct ClientId_t *out);
+
+/**
+ * Returns an owned clone of the given `client_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `client_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct ClientId_t client_id_clone(const struct ClientId_t *client_id);
 
 /**
@@ -791,8 +865,15 @@ void client_id_free(struct ClientId_t client_id);
  */
 const char *client_id_to_cstr(const struct ClientId_t *client_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`ClientId`], without copying the string.
+ */
+uintptr_t client_id_len(const struct ClientId_t *client_id);
+
 uint8_t client_id_eq(const struct ClientId_t *lhs, const struct ClientId_t *rhs);
 
+uint8_t client_id_eq_ignore_case(const struct ClientId_t *lhs, const struct ClientId_t *rhs);
+
 uint64_t client_id_hash(const struct ClientId_t *client_id);
 
 /**
@@ -803,6 +884,31 @@ uint64_t client_id_hash(const struct ClientId_t *client_id);
  */
 struct ClientOrderId_t client_order_id_new(const char *ptr);
 
+/**
+ * Fills the caller allocated `out` array with a [`ClientOrderId`] for each
+ * string in the given Python `list`, in order, in a single call.
+ *
+ * The caller owns each identifier written to `out` and is responsible for
+ * freeing each one with `client_order_id_free`. The array itself remains
+ * owned by the caller.
+ *
+ * # Safety
+ * - Assumes `ptr` is borrowed from a valid Python `list` of `str`.
+ * - Assumes `out` points to an array with space for at least `len` identifiers.
+ * # Panics
+ * - If `ptr` or `out` is null.
+ * - If the length of the list is not equal to `len`.
+ * - If any list item is not a valid identifier string.
+ */
+void client_order_ids_from_pystr_list(PyObject *ptr, struct ClientOrderId_t *out, uintptr_t len);
+
+/**
+ * Returns an owned clone of the given `client_order_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `client_order_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct ClientOrderId_t client_order_id_clone(const struct ClientOrderId_t *client_order_id);
 
 /**
@@ -815,6 +921,11 @@ void client_order_id_free(struct ClientOrderId_t client_order_id);
  */
 const char *client_order_id_to_cstr(const struct ClientOrderId_t *client_order_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`ClientOrderId`], without copying the string.
+ */
+uintptr_t client_order_id_len(const struct ClientOrderId_t *client_order_id);
+
 uint8_t client_order_id_eq(const struct ClientOrderId_t *lhs, const struct ClientOrderId_t *rhs);
 
 uint64_t client_order_id_hash(const struct ClientOrderId_t *client_order_id);
@@ -827,6 +938,13 @@ uint64_t client_order_id_hash(const struct ClientOrderId_t *client_order_id);
  */
 struct ComponentId_t component_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `component_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `component_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct ComponentId_t component_id_clone(const struct ComponentId_t *component_id);
 
 /**
@@ -839,6 +957,11 @@ void component_id_free(struct ComponentId_t component_id);
  */
 const char *component_id_to_cstr(const struct ComponentId_t *component_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`ComponentId`], without copying the string.
+ */
+uintptr_t component_id_len(const struct ComponentId_t *component_id);
+
 uint8_t component_id_eq(const struct ComponentId_t *lhs, const struct ComponentId_t *rhs);
 
 uint64_t component_id_hash(const struct ComponentId_t *component_id);
@@ -851,6 +974,13 @@ uint64_t component_id_hash(const struct ComponentId_t *component_id);
  */
 struct ExecAlgorithmId_t exec_algorithm_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `exec_algorithm_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `exec_algorithm_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct ExecAlgorithmId_t exec_algorithm_id_clone(const struct ExecAlgorithmId_t *exec_algorithm_id);
 
 /**
@@ -863,6 +993,11 @@ void exec_algorithm_id_free(struct ExecAlgorithmId_t exec_algorithm_id);
  */
 const char *exec_algorithm_id_to_cstr(const struct ExecAlgorithmId_t *exec_algorithm_id);
 
+/**
+ * Returns the UTF-8 byte length of an [`ExecAlgorithmId`], without copying the string.
+ */
+uintptr_t exec_algorithm_id_len(const struct ExecAlgorithmId_t *exec_algorithm_id);
+
 uint8_t exec_algorithm_id_eq(const struct ExecAlgorithmId_t *lhs,
                              const struct ExecAlgorithmId_t *rhs);
 
@@ -878,6 +1013,25 @@ struct InstrumentId_t instrument_id_new(const struct Symbol_t *symbol, const str
  */
 struct InstrumentId_t instrument_id_new_from_cstr(const char *ptr);
 
+/**
+ * Returns an [`InstrumentId`] from C string pointers to its symbol and venue,
+ * joined as `{symbol}.{venue}`.
+ *
+ * # Safety
+ * - Assumes `symbol_ptr` and `venue_ptr` are valid C string pointers.
+ *
+ * # Panics
+ * - If the symbol or venue is not a valid value.
+ */
+struct InstrumentId_t instrument_id_from_parts(const char *symbol_ptr, const char *venue_ptr);
+
+/**
+ * Returns an owned clone of the given `instrument_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `instrument_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct InstrumentId_t instrument_id_clone(const struct InstrumentId_t *instrument_id);
 
 /**
@@ -890,10 +1044,36 @@ void instrument_id_free(struct InstrumentId_t instrument_id);
  */
 const char *instrument_id_to_cstr(const struct InstrumentId_t *instrument_id);
 
+/**
+ * Returns the UTF-8 byte length of an [`InstrumentId`], without copying the string.
+ */
+uintptr_t instrument_id_len(const struct InstrumentId_t *instrument_id);
+
 uint8_t instrument_id_eq(const struct InstrumentId_t *lhs, const struct InstrumentId_t *rhs);
 
 uint64_t instrument_id_hash(const struct InstrumentId_t *instrument_id);
 
+struct OrderKey_t order_key_new(const struct ClientOrderId_t *client_order_id,
+                                const struct VenueOrderId_t *venue_order_id);
+
+/**
+ * Returns an owned clone of the given `order_key`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `order_key_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
+struct OrderKey_t order_key_clone(const struct OrderKey_t *order_key);
+
+/**
+ * Frees the memory for the given `order_key` by dropping.
+ */
+void order_key_free(struct OrderKey_t order_key);
+
+uint8_t order_key_eq(const struct OrderKey_t *lhs, const struct OrderKey_t *rhs);
+
+uint64_t order_key_hash(const struct OrderKey_t *order_key);
+
 /**
  * Returns a Nautilus identifier from a C string pointer.
  *
@@ -902,6 +1082,13 @@ uint64_t instrument_id_hash(const struct InstrumentId_t *instrument_id);
  */
 struct OrderListId_t order_list_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `order_list_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `order_list_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct OrderListId_t order_list_id_clone(const struct OrderListId_t *order_list_id);
 
 /**
@@ -914,6 +1101,11 @@ void order_list_id_free(struct OrderListId_t order_list_id);
  */
 const char *order_list_id_to_cstr(const struct OrderListId_t *order_list_id);
 
+/**
+ * Returns the UTF-8 byte length of an [`OrderListId`], without copying the string.
+ */
+uintptr_t order_list_id_len(const struct OrderListId_t *order_list_id);
+
 uint8_t order_list_id_eq(const struct OrderListId_t *lhs, const struct OrderListId_t *rhs);
 
 uint64_t order_list_id_hash(const struct OrderListId_t *order_list_id);
@@ -926,6 +1118,13 @@ uint64_t order_list_id_hash(const struct OrderListId_t *order_list_id);
  */
 struct PositionId_t position_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `position_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `position_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct PositionId_t position_id_clone(const struct PositionId_t *position_id);
 
 /**
@@ -938,6 +1137,13 @@ void position_id_free(struct PositionId_t position_id);
  */
 const char *position_id_to_cstr(const struct PositionId_t *position_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`PositionId`], without copying the string.
+ */
+uintptr_t position_id_len(const struct PositionId_t *position_id);
+
+uint8_t position_id_is_virtual(const struct PositionId_t *position_id);
+
 uint8_t position_id_eq(const struct PositionId_t *lhs, const struct PositionId_t *rhs);
 
 uint64_t position_id_hash(const struct PositionId_t *position_id);
@@ -950,6 +1156,13 @@ uint64_t position_id_hash(const struct PositionId_t *position_id);
  */
 struct StrategyId_t strategy_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `strategy_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `strategy_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct StrategyId_t strategy_id_clone(const struct StrategyId_t *strategy_id);
 
 /**
@@ -962,6 +1175,11 @@ void strategy_id_free(struct StrategyId_t strategy_id);
  */
 const char *strategy_id_to_cstr(const struct StrategyId_t *strategy_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`StrategyId`], without copying the string.
+ */
+uintptr_t strategy_id_len(const struct StrategyId_t *strategy_id);
+
 /**
  * Returns a Nautilus identifier from a C string pointer.
  *
@@ -970,6 +1188,13 @@ const char *strategy_id_to_cstr(const struct StrategyId_t *strategy_id);
  */
 struct Symbol_t symbol_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `symbol`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `symbol_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct Symbol_t symbol_clone(const struct Symbol_t *symbol);
 
 /**
@@ -982,6 +1207,11 @@ void symbol_free(struct Symbol_t symbol);
  */
 const char *symbol_to_cstr(const struct Symbol_t *symbol);
 
+/**
+ * Returns the UTF-8 byte length of a [`Symbol`], without copying the string.
+ */
+uintptr_t symbol_len(const struct Symbol_t *symbol);
+
 uint8_t symbol_eq(const struct Symbol_t *lhs, const struct Symbol_t *rhs);
 
 uint64_t symbol_hash(const struct Symbol_t *symbol);
@@ -994,6 +1224,13 @@ uint64_t symbol_hash(const struct Symbol_t *symbol);
  */
 struct TradeId_t trade_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `trade_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `trade_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct TradeId_t trade_id_clone(const struct TradeId_t *trade_id);
 
 /**
@@ -1002,10 +1239,15 @@ struct TradeId_t trade_id_clone(const struct TradeId_t *trade_id);
 void trade_id_free(struct TradeId_t trade_id);
 
 /**
- * Returns [TradeId] as a C string pointer.
+ * Returns a [`TradeId`] as a C string pointer.
  */
 const char *trade_id_to_cstr(const struct TradeId_t *trade_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`TradeId`], without copying the string.
+ */
+uintptr_t trade_id_len(const struct TradeId_t *trade_id);
+
 uint8_t trade_id_eq(const struct TradeId_t *lhs, const struct TradeId_t *rhs);
 
 uint64_t trade_id_hash(const struct TradeId_t *trade_id);
@@ -1018,6 +1260,13 @@ uint64_t trade_id_hash(const struct TradeId_t *trade_id);
  */
 struct TraderId_t trader_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `trader_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `trader_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct TraderId_t trader_id_clone(const struct TraderId_t *trader_id);
 
 /**
@@ -1030,6 +1279,11 @@ void trader_id_free(struct TraderId_t trader_id);
  */
 const char *trader_id_to_cstr(const struct TraderId_t *trader_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`TraderId`], without copying the string.
+ */
+uintptr_t trader_id_len(const struct TraderId_t *trader_id);
+
 /**
  * Returns a Nautilus identifier from a C string pointer.
  *
@@ -1038,6 +1292,13 @@ const char *trader_id_to_cstr(const struct TraderId_t *trader_id);
  */
 struct Venue_t venue_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `venue`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `venue_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct Venue_t venue_clone(const struct Venue_t *venue);
 
 /**
@@ -1050,6 +1311,11 @@ void venue_free(struct Venue_t venue);
  */
 const char *venue_to_cstr(const struct Venue_t *venue);
 
+/**
+ * Returns the UTF-8 byte length of a [`Venue`], without copying the string.
+ */
+uintptr_t venue_len(const struct Venue_t *venue);
+
 uint8_t venue_eq(const struct Venue_t *lhs, const struct Venue_t *rhs);
 
 uint64_t venue_hash(const struct Venue_t *venue);
@@ -1062,6 +1328,13 @@ uint64_t venue_hash(const struct Venue_t *venue);
  */
 struct VenueOrderId_t venue_order_id_new(const char *ptr);
 
+/**
+ * Returns an owned clone of the given `venue_order_id`, sharing its immutable string
+ * data.
+ *
+ * The caller owns the result and must free it with `venue_order_id_free`,
+ * freeing either the clone or the original does not affect the other.
+ */
 struct VenueOrderId_t venue_order_id_clone(const struct VenueOrderId_t *venue_order_id);
 
 /**
@@ -1071,6 +1344,11 @@ void venue_order_id_free(struct VenueOrderId_t venue_order_id);
 
 const char *venue_order_id_to_cstr(const struct VenueOrderId_t *venue_order_id);
 
+/**
+ * Returns the UTF-8 byte length of a [`VenueOrderId`], without copying the string.
+ */
+uintptr_t venue_order_id_len(const struct VenueOrderId_t *venue_order_id);
+
 uint8_t venue_order_id_eq(const struct VenueOrderId_t *lhs, const struct VenueOrderId_t *rhs);
 
 uint64_t venue_order_id_hash(const struct VenueOrderId_t *venue_order_id);
@@ -1122,9 +1400,19 @@ struct Price_t price_from_raw(int64_t raw, uint8_t precision);
 
 double price_as_f64(const struct Price_t *price);
 
-void price_add_assign(struct Price_t a, struct Price_t b);
+/**
+ * Adds `b` to `a` in place, returning 1 on success, or 0 leaving `a`
+ * unchanged if the precisions differ or the sum overflows, see
+ * [`Price::checked_add`].
+ */
+uint8_t price_add_assign(struct Price_t *a, struct Price_t b);
 
-void price_sub_assign(struct Price_t a, struct Price_t b);
+/**
+ * Subtracts `b` from `a` in place, returning 1 on success, or 0 leaving `a`
+ * unchanged if the precisions differ or the difference overflows, see
+ * [`Price::checked_sub`].
+ */
+uint8_t price_sub_assign(struct Price_t *a, struct Price_t b);
 
 struct Quantity_t quantity_new(double value, uint8_t precision);
 
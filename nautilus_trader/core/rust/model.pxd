@@ -217,6 +217,8 @@ cdef extern from "../includes/model.h":
     cdef struct Price_t:
         int64_t raw;
         uint8_t precision;
+    # A zero price with a precision of 0.
+    const Price_t Price_t_ZERO # = <Price_t>{ 0, 0 }
 
     cdef struct Quantity_t:
         uint64_t raw;
@@ -270,6 +272,15 @@ cdef extern from "../includes/model.h":
     cdef struct ExecAlgorithmId_t:
         Rc_String *value;
 
+    cdef struct VenueOrderId_t:
+        Rc_String *value;
+
+    # Represents a composite key identifying an order by both its client and
+    # venue assigned identifiers.
+    cdef struct OrderKey_t:
+        ClientOrderId_t client_order_id;
+        VenueOrderId_t venue_order_id;
+
     cdef struct OrderListId_t:
         Rc_String *value;
 
@@ -282,9 +293,6 @@ cdef extern from "../includes/model.h":
     cdef struct TraderId_t:
         Rc_String *value;
 
-    cdef struct VenueOrderId_t:
-        Rc_String *value;
-
     cdef struct Ladder:
         OrderSide side;
         BTreeMap_BookPrice__Level *levels;
@@ -450,6 +458,16 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     AggressorSide aggressor_side_from_cstr(const char *ptr);
 
+    # Returns the integer representation of the enum, as used by the parquet
+    # encoders.
+    uint8_t aggressor_side_to_u8(AggressorSide value);
+
+    # Returns an enum from its integer representation.
+    #
+    # # Panics
+    # - If `value` is not a valid `AggressorSide`.
+    AggressorSide aggressor_side_from_u8(uint8_t value);
+
     const char *asset_class_to_cstr(AssetClass value);
 
     # Returns an enum from a Python string.
@@ -562,6 +580,16 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     OrderSide order_side_from_cstr(const char *ptr);
 
+    # Returns the integer representation of the enum, as used by the parquet
+    # encoders.
+    uint8_t order_side_to_u8(OrderSide value);
+
+    # Returns an enum from its integer representation.
+    #
+    # # Panics
+    # - If `value` is not a valid `OrderSide`.
+    OrderSide order_side_from_u8(uint8_t value);
+
     const char *order_status_to_cstr(OrderStatus value);
 
     # Returns an enum from a Python string.
@@ -632,6 +660,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     AccountId_t account_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `account_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `account_id_free`,
+    # freeing either the clone or the original does not affect the other.
     AccountId_t account_id_clone(const AccountId_t *account_id);
 
     # Frees the memory for the given `account_id` by dropping.
@@ -640,6 +673,9 @@ cdef extern from "../includes/model.h":
     # Returns an [`AccountId`] as a C string pointer.
     const char *account_id_to_cstr(const AccountId_t *account_id);
 
+    # Returns the UTF-8 byte length of an [`AccountId`], without copying the string.
+    uintptr_t account_id_len(const AccountId_t *account_id);
+
     uint8_t account_id_eq(const AccountId_t *lhs, const AccountId_t *rhs);
 
     uint64_t account_id_hash(const AccountId_t *account_id);
@@ -650,6 +686,23 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     ClientId_t client_id_new(const char *ptr);
 
+    # Writes a [`ClientId`] from a C string pointer to `out`, returning zero on
+    # success or an [`IdentifierFfiError`] code, in which case `out` is left
+    # untouched.
+    #
+    # Unlike `client_id_new` this never panics on the input.
+    #
+    # # Safety
+    # - Assumes `ptr` is null or a valid C string pointer.
+    # - Assumes `out` is null or valid for writes, any value it holds is
+    #   overwritten without being freed.
+    int32_t client_id_new_checked(const char *ptr, ClientId_t *out);
+
+    # Returns an owned clone of the given `client_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `client_id_free`,
+    # freeing either the clone or the original does not affect the other.
     ClientId_t client_id_clone(const ClientId_t *client_id);
 
     # Frees the memory for the given `client_id` by dropping.
@@ -658,8 +711,13 @@ cdef extern from "../includes/model.h":
     # Returns a [`ClientId`] identifier as a C string pointer.
     const char *client_id_to_cstr(const ClientId_t *client_id);
 
+    # Returns the UTF-8 byte length of a [`ClientId`], without copying the string.
+    uintptr_t client_id_len(const ClientId_t *client_id);
+
     uint8_t client_id_eq(const ClientId_t *lhs, const ClientId_t *rhs);
 
+    uint8_t client_id_eq_ignore_case(const ClientId_t *lhs, const ClientId_t *rhs);
+
     uint64_t client_id_hash(const ClientId_t *client_id);
 
     # Returns a Nautilus identifier from a C string pointer.
@@ -668,6 +726,27 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     ClientOrderId_t client_order_id_new(const char *ptr);
 
+    # Fills the caller allocated `out` array with a [`ClientOrderId`] for each
+    # string in the given Python `list`, in order, in a single call.
+    #
+    # The caller owns each identifier written to `out` and is responsible for
+    # freeing each one with `client_order_id_free`. The array itself remains
+    # owned by the caller.
+    #
+    # # Safety
+    # - Assumes `ptr` is borrowed from a valid Python `list` of `str`.
+    # - Assumes `out` points to an array with space for at least `len` identifiers.
+    # # Panics
+    # - If `ptr` or `out` is null.
+    # - If the length of the list is not equal to `len`.
+    # - If any list item is not a valid identifier string.
+    void client_order_ids_from_pystr_list(PyObject *ptr, ClientOrderId_t *out, uintptr_t len);
+
+    # Returns an owned clone of the given `client_order_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `client_order_id_free`,
+    # freeing either the clone or the original does not affect the other.
     ClientOrderId_t client_order_id_clone(const ClientOrderId_t *client_order_id);
 
     # Frees the memory for the given `client_order_id` by dropping.
@@ -676,6 +755,9 @@ cdef extern from "../includes/model.h":
     # Returns a [`ClientOrderId`] as a C string pointer.
     const char *client_order_id_to_cstr(const ClientOrderId_t *client_order_id);
 
+    # Returns the UTF-8 byte length of a [`ClientOrderId`], without copying the string.
+    uintptr_t client_order_id_len(const ClientOrderId_t *client_order_id);
+
     uint8_t client_order_id_eq(const ClientOrderId_t *lhs, const ClientOrderId_t *rhs);
 
     uint64_t client_order_id_hash(const ClientOrderId_t *client_order_id);
@@ -686,6 +768,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     ComponentId_t component_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `component_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `component_id_free`,
+    # freeing either the clone or the original does not affect the other.
     ComponentId_t component_id_clone(const ComponentId_t *component_id);
 
     # Frees the memory for the given `component_id` by dropping.
@@ -694,6 +781,9 @@ cdef extern from "../includes/model.h":
     # Returns a [`ComponentId`] identifier as a C string pointer.
     const char *component_id_to_cstr(const ComponentId_t *component_id);
 
+    # Returns the UTF-8 byte length of a [`ComponentId`], without copying the string.
+    uintptr_t component_id_len(const ComponentId_t *component_id);
+
     uint8_t component_id_eq(const ComponentId_t *lhs, const ComponentId_t *rhs);
 
     uint64_t component_id_hash(const ComponentId_t *component_id);
@@ -704,6 +794,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     ExecAlgorithmId_t exec_algorithm_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `exec_algorithm_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `exec_algorithm_id_free`,
+    # freeing either the clone or the original does not affect the other.
     ExecAlgorithmId_t exec_algorithm_id_clone(const ExecAlgorithmId_t *exec_algorithm_id);
 
     # Frees the memory for the given `exec_algorithm_id` by dropping.
@@ -712,6 +807,9 @@ cdef extern from "../includes/model.h":
     # Returns an [`ExecAlgorithmId`] identifier as a C string pointer.
     const char *exec_algorithm_id_to_cstr(const ExecAlgorithmId_t *exec_algorithm_id);
 
+    # Returns the UTF-8 byte length of an [`ExecAlgorithmId`], without copying the string.
+    uintptr_t exec_algorithm_id_len(const ExecAlgorithmId_t *exec_algorithm_id);
+
     uint8_t exec_algorithm_id_eq(const ExecAlgorithmId_t *lhs, const ExecAlgorithmId_t *rhs);
 
     uint64_t exec_algorithm_id_hash(const ExecAlgorithmId_t *exec_algorithm_id);
@@ -724,6 +822,21 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     InstrumentId_t instrument_id_new_from_cstr(const char *ptr);
 
+    # Returns an [`InstrumentId`] from C string pointers to its symbol and venue,
+    # joined as `{symbol}.{venue}`.
+    #
+    # # Safety
+    # - Assumes `symbol_ptr` and `venue_ptr` are valid C string pointers.
+    #
+    # # Panics
+    # - If the symbol or venue is not a valid value.
+    InstrumentId_t instrument_id_from_parts(const char *symbol_ptr, const char *venue_ptr);
+
+    # Returns an owned clone of the given `instrument_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `instrument_id_free`,
+    # freeing either the clone or the original does not affect the other.
     InstrumentId_t instrument_id_clone(const InstrumentId_t *instrument_id);
 
     # Frees the memory for the given `instrument_id` by dropping.
@@ -732,16 +845,41 @@ cdef extern from "../includes/model.h":
     # Returns an [`InstrumentId`] as a C string pointer.
     const char *instrument_id_to_cstr(const InstrumentId_t *instrument_id);
 
+    # Returns the UTF-8 byte length of an [`InstrumentId`], without copying the string.
+    uintptr_t instrument_id_len(const InstrumentId_t *instrument_id);
+
     uint8_t instrument_id_eq(const InstrumentId_t *lhs, const InstrumentId_t *rhs);
 
     uint64_t instrument_id_hash(const InstrumentId_t *instrument_id);
 
+    OrderKey_t order_key_new(const ClientOrderId_t *client_order_id,
+                             const VenueOrderId_t *venue_order_id);
+
+    # Returns an owned clone of the given `order_key`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `order_key_free`,
+    # freeing either the clone or the original does not affect the other.
+    OrderKey_t order_key_clone(const OrderKey_t *order_key);
+
+    # Frees the memory for the given `order_key` by dropping.
+    void order_key_free(OrderKey_t order_key);
+
+    uint8_t order_key_eq(const OrderKey_t *lhs, const OrderKey_t *rhs);
+
+    uint64_t order_key_hash(const OrderKey_t *order_key);
+
     # Returns a Nautilus identifier from a C string pointer.
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
     OrderListId_t order_list_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `order_list_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `order_list_id_free`,
+    # freeing either the clone or the original does not affect the other.
     OrderListId_t order_list_id_clone(const OrderListId_t *order_list_id);
 
     # Frees the memory for the given `order_list_id` by dropping.
@@ -750,6 +888,9 @@ cdef extern from "../includes/model.h":
     # Returns an [`OrderListId`] as a C string pointer.
     const char *order_list_id_to_cstr(const OrderListId_t *order_list_id);
 
+    # Returns the UTF-8 byte length of an [`OrderListId`], without copying the string.
+    uintptr_t order_list_id_len(const OrderListId_t *order_list_id);
+
     uint8_t order_list_id_eq(const OrderListId_t *lhs, const OrderListId_t *rhs);
 
     uint64_t order_list_id_hash(const OrderListId_t *order_list_id);
@@ -760,6 +901,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     PositionId_t position_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `position_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `position_id_free`,
+    # freeing either the clone or the original does not affect the other.
     PositionId_t position_id_clone(const PositionId_t *position_id);
 
     # Frees the memory for the given `position_id` by dropping.
@@ -768,6 +914,11 @@ cdef extern from "../includes/model.h":
     # Returns a [`PositionId`] identifier as a C string pointer.
     const char *position_id_to_cstr(const PositionId_t *position_id);
 
+    # Returns the UTF-8 byte length of a [`PositionId`], without copying the string.
+    uintptr_t position_id_len(const PositionId_t *position_id);
+
+    uint8_t position_id_is_virtual(const PositionId_t *position_id);
+
     uint8_t position_id_eq(const PositionId_t *lhs, const PositionId_t *rhs);
 
     uint64_t position_id_hash(const PositionId_t *position_id);
@@ -778,6 +929,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     StrategyId_t strategy_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `strategy_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `strategy_id_free`,
+    # freeing either the clone or the original does not affect the other.
     StrategyId_t strategy_id_clone(const StrategyId_t *strategy_id);
 
     # Frees the memory for the given `strategy_id` by dropping.
@@ -786,12 +942,20 @@ cdef extern from "../includes/model.h":
     # Returns a [`StrategyId`] as a C string pointer.
     const char *strategy_id_to_cstr(const StrategyId_t *strategy_id);
 
+    # Returns the UTF-8 byte length of a [`StrategyId`], without copying the string.
+    uintptr_t strategy_id_len(const StrategyId_t *strategy_id);
+
     # Returns a Nautilus identifier from a C string pointer.
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
     Symbol_t symbol_new(const char *ptr);
 
+    # Returns an owned clone of the given `symbol`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `symbol_free`,
+    # freeing either the clone or the original does not affect the other.
     Symbol_t symbol_clone(const Symbol_t *symbol);
 
     # Frees the memory for the given [Symbol] by dropping.
@@ -800,6 +964,9 @@ cdef extern from "../includes/model.h":
     # Returns a [`Symbol`] as a C string pointer.
     const char *symbol_to_cstr(const Symbol_t *symbol);
 
+    # Returns the UTF-8 byte length of a [`Symbol`], without copying the string.
+    uintptr_t symbol_len(const Symbol_t *symbol);
+
     uint8_t symbol_eq(const Symbol_t *lhs, const Symbol_t *rhs);
 
     uint64_t symbol_hash(const Symbol_t *symbol);
@@ -810,14 +977,22 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     TradeId_t trade_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `trade_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `trade_id_free`,
+    # freeing either the clone or the original does not affect the other.
     TradeId_t trade_id_clone(const TradeId_t *trade_id);
 
     # Frees the memory for the given `trade_id` by dropping.
     void trade_id_free(TradeId_t trade_id);
 
-    # Returns [TradeId] as a C string pointer.
+    # Returns a [`TradeId`] as a C string pointer.
     const char *trade_id_to_cstr(const TradeId_t *trade_id);
 
+    # Returns the UTF-8 byte length of a [`TradeId`], without copying the string.
+    uintptr_t trade_id_len(const TradeId_t *trade_id);
+
     uint8_t trade_id_eq(const TradeId_t *lhs, const TradeId_t *rhs);
 
     uint64_t trade_id_hash(const TradeId_t *trade_id);
@@ -828,6 +1003,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     TraderId_t trader_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `trader_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `trader_id_free`,
+    # freeing either the clone or the original does not affect the other.
     TraderId_t trader_id_clone(const TraderId_t *trader_id);
 
     # Frees the memory for the given `trader_id` by dropping.
@@ -836,12 +1016,20 @@ cdef extern from "../includes/model.h":
     # Returns a [`TraderId`] as a C string pointer.
     const char *trader_id_to_cstr(const TraderId_t *trader_id);
 
+    # Returns the UTF-8 byte length of a [`TraderId`], without copying the string.
+    uintptr_t trader_id_len(const TraderId_t *trader_id);
+
     # Returns a Nautilus identifier from a C string pointer.
     #
     # # Safety
     # - Assumes `ptr` is a valid C string pointer.
     Venue_t venue_new(const char *ptr);
 
+    # Returns an owned clone of the given `venue`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `venue_free`,
+    # freeing either the clone or the original does not affect the other.
     Venue_t venue_clone(const Venue_t *venue);
 
     # Frees the memory for the given `venue` by dropping.
@@ -850,6 +1038,9 @@ cdef extern from "../includes/model.h":
     # Returns a [`Venue`] identifier as a C string pointer.
     const char *venue_to_cstr(const Venue_t *venue);
 
+    # Returns the UTF-8 byte length of a [`Venue`], without copying the string.
+    uintptr_t venue_len(const Venue_t *venue);
+
     uint8_t venue_eq(const Venue_t *lhs, const Venue_t *rhs);
 
     uint64_t venue_hash(const Venue_t *venue);
@@ -860,6 +1051,11 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     VenueOrderId_t venue_order_id_new(const char *ptr);
 
+    # Returns an owned clone of the given `venue_order_id`, sharing its immutable string
+    # data.
+    #
+    # The caller owns the result and must free it with `venue_order_id_free`,
+    # freeing either the clone or the original does not affect the other.
     VenueOrderId_t venue_order_id_clone(const VenueOrderId_t *venue_order_id);
 
     # Frees the memory for the given `venue_order_id` by dropping.
@@ -867,6 +1063,9 @@ cdef extern from "../includes/model.h":
 
     const char *venue_order_id_to_cstr(const VenueOrderId_t *venue_order_id);
 
+    # Returns the UTF-8 byte length of a [`VenueOrderId`], without copying the string.
+    uintptr_t venue_order_id_len(const VenueOrderId_t *venue_order_id);
+
     uint8_t venue_order_id_eq(const VenueOrderId_t *lhs, const VenueOrderId_t *rhs);
 
     uint64_t venue_order_id_hash(const VenueOrderId_t *venue_order_id);
@@ -916,9 +1115,15 @@ cdef extern from "../includes/model.h":
 
     double price_as_f64(const Price_t *price);
 
-    void price_add_assign(Price_t a, Price_t b);
+    # Adds `b` to `a` in place, returning 1 on success, or 0 leaving `a`
+    # unchanged if the precisions differ or the sum overflows, see
+    # [`Price::checked_add`].
+    uint8_t price_add_assign(Price_t *a, Price_t b);
 
-    void price_sub_assign(Price_t a, Price_t b);
+    # Subtracts `b` from `a` in place, returning 1 on success, or 0 leaving `a`
+    # unchanged if the precisions differ or the difference overflows, see
+    # [`Price::checked_sub`].
+    uint8_t price_sub_assign(Price_t *a, Price_t b);
 
     Quantity_t quantity_new(double value, uint8_t precision);
 
@@ -43,6 +43,7 @@ from nautilus_trader.core.rust.model cimport order_list_id_to_cstr
 from nautilus_trader.core.rust.model cimport position_id_eq
 from nautilus_trader.core.rust.model cimport position_id_free
 from nautilus_trader.core.rust.model cimport position_id_hash
+from nautilus_trader.core.rust.model cimport position_id_is_virtual
 from nautilus_trader.core.rust.model cimport position_id_new
 from nautilus_trader.core.rust.model cimport position_id_to_cstr
 from nautilus_trader.core.rust.model cimport symbol_clone
@@ -708,7 +709,7 @@ cdef class PositionId(Identifier):
         return cstr_to_pystr(position_id_to_cstr(&self._mem))
 
     cdef bint is_virtual_c(self):
-        return self.to_str().startswith("P-")
+        return position_id_is_virtual(&self._mem)
 
     @staticmethod
     cdef PositionId from_mem_c(PositionId_t mem):